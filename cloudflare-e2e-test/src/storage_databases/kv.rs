@@ -166,6 +166,7 @@ async fn write_bulk(
                 expiration: None,
                 expiration_ttl: None,
                 base64: None,
+                metadata: None,
             })
             .collect(),
     };
@@ -0,0 +1,369 @@
+use crate::framework::response::ApiResult;
+use crate::framework::OrderDirection;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::collections::HashMap;
+
+/// A Custom Hostname (Cloudflare for SaaS)
+/// <https://developers.cloudflare.com/api/resources/custom_hostnames/>
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CustomHostname {
+    /// Custom hostname identifier
+    pub id: String,
+    /// The custom hostname that will point to your hostname via CNAME
+    pub hostname: String,
+    /// SSL properties for the custom hostname
+    pub ssl: Option<CustomHostnameSsl>,
+    /// Unique key/value metadata for this hostname. These are per-hostname (customer) settings.
+    pub custom_metadata: Option<HashMap<String, String>>,
+    /// A valid hostname that's been added to your DNS zone as an A, AAAA, or CNAME record
+    pub custom_origin_server: Option<String>,
+    /// A hostname that will be sent to your custom origin server as SNI for TLS handshake
+    pub custom_origin_sni: Option<String>,
+    /// Status of the hostname's activation
+    pub status: Option<CustomHostnameStatus>,
+    /// These are errors that were encountered while trying to activate a hostname
+    pub verification_errors: Option<Vec<String>>,
+    /// This is a record which can be placed to activate a hostname
+    pub ownership_verification: Option<OwnershipVerification>,
+    /// This presents the token to be served by the given http url to activate a hostname
+    pub ownership_verification_http: Option<OwnershipVerificationHttp>,
+    /// This is the time the hostname was created
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+/// Status of the hostname's activation
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomHostnameStatus {
+    Active,
+    Pending,
+    ActiveRedeploying,
+    Moved,
+    PendingDeletion,
+    Deleted,
+    PendingBlocked,
+    PendingMigration,
+    PendingProvisioned,
+    TestPending,
+    TestActive,
+    TestActiveApex,
+    TestBlocked,
+    TestFailed,
+    Provisioned,
+    Blocked,
+}
+
+/// DNS record that proves ownership of a custom hostname
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct OwnershipVerification {
+    /// DNS name for the ownership verification record
+    pub name: Option<String>,
+    /// DNS record type
+    #[serde(rename = "type")]
+    pub record_type: Option<String>,
+    /// Content for the record
+    pub value: Option<String>,
+}
+
+/// HTTP token that proves ownership of a custom hostname
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct OwnershipVerificationHttp {
+    /// Token to be served
+    pub http_body: Option<String>,
+    /// The HTTP URL that will be checked during custom hostname verification and where the
+    /// customer should host the token
+    pub http_url: Option<String>,
+}
+
+/// Domain control validation (DCV) method used for the certificate
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DcvMethod {
+    Http,
+    Txt,
+    Email,
+}
+
+/// A hostname's certificate authority
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CertificateAuthority {
+    Digicert,
+    Google,
+    LetsEncrypt,
+    SslCom,
+}
+
+/// A ubiquitous bundle has the highest probability of being verified everywhere, even by clients
+/// using outdated or unusual trust stores. An optimal bundle uses the shortest chain and newest
+/// intermediates. And the force bundle verifies the chain, but does not otherwise modify it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BundleMethod {
+    Ubiquitous,
+    Optimal,
+    Force,
+}
+
+/// Status of the hostname's SSL certificate
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomHostnameSslStatus {
+    Initializing,
+    PendingValidation,
+    Deleted,
+    PendingIssuance,
+    PendingDeployment,
+    PendingDeletion,
+    PendingExpiration,
+    Expired,
+    Active,
+    InitializingTimedOut,
+    ValidationTimedOut,
+    IssuanceTimedOut,
+    DeploymentTimedOut,
+    DeletionTimedOut,
+    PendingCleanup,
+    StagingDeployment,
+    StagingActive,
+    Deactivating,
+    Inactive,
+    BackupIssued,
+    HoldingDeployment,
+}
+
+/// SSL properties of a custom hostname, including its validation state
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CustomHostnameSsl {
+    /// Custom hostname SSL identifier tag
+    pub id: Option<String>,
+    /// A ubiquitous bundle has the highest probability of being verified everywhere
+    pub bundle_method: Option<BundleMethod>,
+    /// The Certificate Authority that will issue the certificate
+    pub certificate_authority: Option<CertificateAuthority>,
+    /// If a custom uploaded certificate is used
+    pub custom_certificate: Option<String>,
+    /// The identifier for the Custom CSR that was used
+    pub custom_csr_id: Option<String>,
+    /// The time the custom certificate expires on
+    pub expires_on: Option<DateTime<Utc>>,
+    /// A list of Hostnames on a custom uploaded certificate
+    pub hosts: Option<Vec<String>>,
+    /// The issuer on a custom uploaded certificate
+    pub issuer: Option<String>,
+    /// Domain control validation (DCV) method used for this hostname
+    pub method: Option<DcvMethod>,
+    /// The serial number on a custom uploaded certificate
+    pub serial_number: Option<String>,
+    /// SSL specific settings
+    pub settings: Option<CustomHostnameSslSettings>,
+    /// The signature on a custom uploaded certificate
+    pub signature: Option<String>,
+    /// Status of the hostname's SSL certificates
+    pub status: Option<CustomHostnameSslStatus>,
+    /// Level of validation to be used for this hostname. Domain validation (dv) must be used.
+    #[serde(rename = "type")]
+    pub ssl_type: Option<String>,
+    /// The time the custom certificate was uploaded
+    pub uploaded_on: Option<DateTime<Utc>>,
+    /// Domain validation errors that have been received by the certificate authority (CA)
+    pub validation_errors: Option<Vec<ValidationError>>,
+    /// Certificate's required validation records
+    pub validation_records: Option<Vec<ValidationRecord>>,
+    /// Indicates whether the certificate covers a wildcard
+    pub wildcard: Option<bool>,
+}
+
+/// SSL specific settings of a custom hostname
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct CustomHostnameSslSettings {
+    /// An allowlist of ciphers for TLS termination, in BoringSSL format
+    pub ciphers: Option<Vec<String>>,
+    /// Whether or not Early Hints is enabled ("on" or "off")
+    pub early_hints: Option<String>,
+    /// Whether or not HTTP2 is enabled ("on" or "off")
+    pub http2: Option<String>,
+    /// The minimum TLS version supported
+    pub min_tls_version: Option<String>,
+    /// Whether or not TLS 1.3 is enabled ("on" or "off")
+    pub tls_1_3: Option<String>,
+}
+
+/// A domain validation error received from the certificate authority
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    /// A domain validation error
+    pub message: Option<String>,
+}
+
+/// A record that has to be published for the certificate to be issued
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ValidationRecord {
+    /// The set of email addresses that the certificate authority (CA) will use to complete
+    /// domain validation
+    pub emails: Option<Vec<String>>,
+    /// The content that the certificate authority (CA) will expect to find at the `http_url`
+    /// during the domain validation
+    pub http_body: Option<String>,
+    /// The url that will be checked during domain validation
+    pub http_url: Option<String>,
+    /// Status of the validation record
+    pub status: Option<String>,
+    /// The hostname that the certificate authority (CA) will check for a TXT record during domain
+    /// validation
+    pub txt_name: Option<String>,
+    /// The TXT record that the certificate authority (CA) will check during domain validation
+    pub txt_value: Option<String>,
+}
+
+/// SSL properties used when creating or editing a custom hostname
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct CustomHostnameSslParams {
+    /// A ubiquitous bundle has the highest probability of being verified everywhere
+    pub bundle_method: Option<BundleMethod>,
+    /// The Certificate Authority that will issue the certificate
+    pub certificate_authority: Option<CertificateAuthority>,
+    /// Whether or not to add Cloudflare Branding for the order
+    pub cloudflare_branding: Option<bool>,
+    /// If a custom uploaded certificate is used
+    pub custom_certificate: Option<String>,
+    /// The key for a custom uploaded certificate
+    pub custom_key: Option<String>,
+    /// Domain control validation (DCV) method used for this hostname
+    pub method: Option<DcvMethod>,
+    /// SSL specific settings
+    pub settings: Option<CustomHostnameSslSettings>,
+    /// Level of validation to be used for this hostname. Domain validation (dv) must be used.
+    #[serde(rename = "type")]
+    pub ssl_type: Option<String>,
+    /// Indicates whether the certificate covers a wildcard
+    pub wildcard: Option<bool>,
+}
+
+impl CustomHostnameSslParams {
+    /// Domain validated certificate using the given DCV method
+    pub fn dv(method: DcvMethod) -> Self {
+        Self {
+            method: Some(method),
+            ssl_type: Some("dv".to_string()),
+            ..Default::default()
+        }
+    }
+}
+
+/// Parameters for creating a custom hostname
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CreateCustomHostnameParams {
+    /// The custom hostname that will point to your hostname via CNAME
+    pub hostname: String,
+    /// SSL properties used when creating the custom hostname
+    pub ssl: CustomHostnameSslParams,
+    /// Unique key/value metadata for this hostname
+    pub custom_metadata: Option<HashMap<String, String>>,
+}
+
+/// Parameters for editing a custom hostname
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct EditCustomHostnameParams {
+    /// Unique key/value metadata for this hostname
+    pub custom_metadata: Option<HashMap<String, String>>,
+    /// A valid hostname that's been added to your DNS zone as an A, AAAA, or CNAME record
+    pub custom_origin_server: Option<String>,
+    /// A hostname that will be sent to your custom origin server as SNI for TLS handshake
+    pub custom_origin_sni: Option<String>,
+    /// SSL properties used when editing the custom hostname
+    pub ssl: Option<CustomHostnameSslParams>,
+}
+
+/// Field to order custom hostnames by
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ListCustomHostnamesOrder {
+    Ssl,
+    SslStatus,
+}
+
+/// Filters and pagination for listing custom hostnames
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ListCustomHostnamesParams {
+    /// Fully qualified domain name to match against
+    pub hostname: Option<String>,
+    /// Hostname ID to match against
+    pub id: Option<String>,
+    /// Page number of paginated results
+    pub page: Option<u32>,
+    /// Number of hostnames per page
+    pub per_page: Option<u32>,
+    /// Field to order hostnames by
+    pub order: Option<ListCustomHostnamesOrder>,
+    /// Direction to order hostnames
+    pub direction: Option<OrderDirection>,
+    /// Whether to filter hostnames based on if they have SSL enabled (`1` for yes, `0` for no)
+    pub ssl: Option<u8>,
+    /// Filter by the hostname's activation status
+    pub hostname_status: Option<CustomHostnameStatus>,
+    /// Filter by the hostname's SSL status
+    pub ssl_status: Option<CustomHostnameSslStatus>,
+}
+
+/// Response for custom hostname deletion
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct DeleteCustomHostnameResponse {
+    /// Identifier of the deleted custom hostname
+    pub id: String,
+}
+
+/// Fallback origin for custom hostnames
+/// <https://developers.cloudflare.com/api/resources/custom_hostnames/subresources/fallback_origin/>
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct FallbackOrigin {
+    /// Your origin hostname that requests to your custom hostnames will be sent to
+    pub origin: Option<String>,
+    /// Status of the fallback origin's activation
+    pub status: Option<FallbackOriginStatus>,
+    /// These are errors that were encountered while trying to activate a fallback origin
+    pub errors: Option<Vec<String>>,
+    /// This is the time the fallback origin was created
+    pub created_at: Option<DateTime<Utc>>,
+    /// This is the time the fallback origin was updated
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Status of the fallback origin's activation
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FallbackOriginStatus {
+    Initializing,
+    PendingDeployment,
+    PendingDeletion,
+    Active,
+    DeploymentTimedOut,
+    DeletionTimedOut,
+}
+
+/// Parameters for updating the fallback origin
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct UpdateFallbackOriginParams {
+    /// Your origin hostname that requests to your custom hostnames will be sent to
+    pub origin: String,
+}
+
+// ApiResult trait implementations
+impl ApiResult for CustomHostname {}
+impl ApiResult for Vec<CustomHostname> {}
+impl ApiResult for DeleteCustomHostnameResponse {}
+impl ApiResult for FallbackOrigin {}
@@ -0,0 +1,149 @@
+use crate::framework::endpoint::{serialize_query, EndpointSpec, Method, RequestBody};
+use crate::framework::response::ApiSuccess;
+
+use super::custom_hostname_data_structures::{
+    CreateCustomHostnameParams, CustomHostname, DeleteCustomHostnameResponse,
+    EditCustomHostnameParams, ListCustomHostnamesParams,
+};
+
+/// List custom hostnames for a zone
+/// <https://developers.cloudflare.com/api/resources/custom_hostnames/methods/list/>
+#[derive(Debug)]
+pub struct ListCustomHostnames<'a> {
+    /// Zone identifier
+    pub zone_id: &'a str,
+    /// Filters and pagination
+    pub params: ListCustomHostnamesParams,
+}
+
+impl EndpointSpec for ListCustomHostnames<'_> {
+    type JsonResponse = Vec<CustomHostname>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!("zones/{}/custom_hostnames", self.zone_id)
+    }
+
+    fn query(&self) -> Option<String> {
+        serialize_query(&self.params)
+    }
+}
+
+/// Create a custom hostname
+/// <https://developers.cloudflare.com/api/resources/custom_hostnames/methods/create/>
+#[derive(Debug)]
+pub struct CreateCustomHostname<'a> {
+    /// Zone identifier
+    pub zone_id: &'a str,
+    /// Custom hostname creation parameters
+    pub params: CreateCustomHostnameParams,
+}
+
+impl EndpointSpec for CreateCustomHostname<'_> {
+    type JsonResponse = CustomHostname;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> String {
+        format!("zones/{}/custom_hostnames", self.zone_id)
+    }
+
+    fn body(&self) -> Option<RequestBody<'_>> {
+        Some(RequestBody::Json(
+            serde_json::to_string(&self.params).unwrap(),
+        ))
+    }
+}
+
+/// Get a custom hostname
+/// <https://developers.cloudflare.com/api/resources/custom_hostnames/methods/get/>
+#[derive(Debug)]
+pub struct GetCustomHostname<'a> {
+    /// Zone identifier
+    pub zone_id: &'a str,
+    /// Custom hostname identifier
+    pub custom_hostname_id: &'a str,
+}
+
+impl EndpointSpec for GetCustomHostname<'_> {
+    type JsonResponse = CustomHostname;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "zones/{}/custom_hostnames/{}",
+            self.zone_id, self.custom_hostname_id
+        )
+    }
+}
+
+/// Edit a custom hostname. Sending `ssl` again triggers a fresh certificate validation.
+/// <https://developers.cloudflare.com/api/resources/custom_hostnames/methods/edit/>
+#[derive(Debug)]
+pub struct EditCustomHostname<'a> {
+    /// Zone identifier
+    pub zone_id: &'a str,
+    /// Custom hostname identifier
+    pub custom_hostname_id: &'a str,
+    /// Custom hostname edit parameters
+    pub params: EditCustomHostnameParams,
+}
+
+impl EndpointSpec for EditCustomHostname<'_> {
+    type JsonResponse = CustomHostname;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "zones/{}/custom_hostnames/{}",
+            self.zone_id, self.custom_hostname_id
+        )
+    }
+
+    fn body(&self) -> Option<RequestBody<'_>> {
+        Some(RequestBody::Json(
+            serde_json::to_string(&self.params).unwrap(),
+        ))
+    }
+}
+
+/// Delete a custom hostname and any issued SSL certificates
+/// <https://developers.cloudflare.com/api/resources/custom_hostnames/methods/delete/>
+#[derive(Debug)]
+pub struct DeleteCustomHostname<'a> {
+    /// Zone identifier
+    pub zone_id: &'a str,
+    /// Custom hostname identifier
+    pub custom_hostname_id: &'a str,
+}
+
+impl EndpointSpec for DeleteCustomHostname<'_> {
+    type JsonResponse = DeleteCustomHostnameResponse;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "zones/{}/custom_hostnames/{}",
+            self.zone_id, self.custom_hostname_id
+        )
+    }
+}
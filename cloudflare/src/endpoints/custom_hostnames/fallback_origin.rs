@@ -0,0 +1,75 @@
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::ApiSuccess;
+
+use super::custom_hostname_data_structures::{FallbackOrigin, UpdateFallbackOriginParams};
+
+/// Get the fallback origin for custom hostnames
+/// <https://developers.cloudflare.com/api/resources/custom_hostnames/subresources/fallback_origin/methods/get/>
+#[derive(Debug)]
+pub struct GetFallbackOrigin<'a> {
+    /// Zone identifier
+    pub zone_id: &'a str,
+}
+
+impl EndpointSpec for GetFallbackOrigin<'_> {
+    type JsonResponse = FallbackOrigin;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!("zones/{}/custom_hostnames/fallback_origin", self.zone_id)
+    }
+}
+
+/// Update the fallback origin for custom hostnames
+/// <https://developers.cloudflare.com/api/resources/custom_hostnames/subresources/fallback_origin/methods/update/>
+#[derive(Debug)]
+pub struct UpdateFallbackOrigin<'a> {
+    /// Zone identifier
+    pub zone_id: &'a str,
+    /// Fallback origin update parameters
+    pub params: UpdateFallbackOriginParams,
+}
+
+impl EndpointSpec for UpdateFallbackOrigin<'_> {
+    type JsonResponse = FallbackOrigin;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn path(&self) -> String {
+        format!("zones/{}/custom_hostnames/fallback_origin", self.zone_id)
+    }
+
+    fn body(&self) -> Option<RequestBody<'_>> {
+        Some(RequestBody::Json(
+            serde_json::to_string(&self.params).unwrap(),
+        ))
+    }
+}
+
+/// Delete the fallback origin for custom hostnames
+/// <https://developers.cloudflare.com/api/resources/custom_hostnames/subresources/fallback_origin/methods/delete/>
+#[derive(Debug)]
+pub struct DeleteFallbackOrigin<'a> {
+    /// Zone identifier
+    pub zone_id: &'a str,
+}
+
+impl EndpointSpec for DeleteFallbackOrigin<'_> {
+    type JsonResponse = FallbackOrigin;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn path(&self) -> String {
+        format!("zones/{}/custom_hostnames/fallback_origin", self.zone_id)
+    }
+}
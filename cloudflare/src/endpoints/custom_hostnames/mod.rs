@@ -0,0 +1,8 @@
+mod custom_hostname_data_structures;
+mod custom_hostname_management;
+mod fallback_origin;
+mod tests;
+
+pub use custom_hostname_data_structures::*;
+pub use custom_hostname_management::*;
+pub use fallback_origin::*;
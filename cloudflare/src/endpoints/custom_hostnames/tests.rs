@@ -0,0 +1,201 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{
+        CreateCustomHostname, CreateCustomHostnameParams, CustomHostname, CustomHostnameSslParams,
+        CustomHostnameSslStatus, CustomHostnameStatus, DcvMethod, DeleteCustomHostname,
+        DeleteFallbackOrigin, EditCustomHostname, EditCustomHostnameParams, FallbackOrigin,
+        FallbackOriginStatus, GetCustomHostname, GetFallbackOrigin, ListCustomHostnames,
+        ListCustomHostnamesOrder, ListCustomHostnamesParams, UpdateFallbackOrigin,
+        UpdateFallbackOriginParams,
+    };
+    use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+    use crate::framework::OrderDirection;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_custom_hostname_deserialization() {
+        let json = r#"
+        {
+            "id": "023e105f4ecef8ad9ca31a8372d0c353",
+            "hostname": "app.example.com",
+            "ssl": {
+                "id": "0d89c70d-ad9f-4843-b99f-6cc0252067e9",
+                "bundle_method": "ubiquitous",
+                "certificate_authority": "google",
+                "method": "txt",
+                "settings": {
+                    "ciphers": ["ECDHE-RSA-AES128-GCM-SHA256", "AES128-SHA"],
+                    "http2": "on",
+                    "min_tls_version": "1.2",
+                    "tls_1_3": "on"
+                },
+                "status": "pending_validation",
+                "type": "dv",
+                "validation_errors": [{"message": "SERVFAIL looking up CAA for app.example.com"}],
+                "validation_records": [
+                    {
+                        "status": "pending",
+                        "txt_name": "_acme-challenge.app.example.com",
+                        "txt_value": "ca3-574923932a82475cb8592200f1a2a23d"
+                    }
+                ],
+                "wildcard": false
+            },
+            "custom_metadata": {"customer": "acme"},
+            "status": "pending",
+            "verification_errors": ["None of the A or AAAA records are owned by this account"],
+            "ownership_verification": {
+                "name": "_cf-custom-hostname.app.example.com",
+                "type": "txt",
+                "value": "5cc07c04-ea62-4a5a-95f0-419334a875a4"
+            },
+            "ownership_verification_http": {
+                "http_body": "5cc07c04-ea62-4a5a-95f0-419334a875a4",
+                "http_url": "http://custom.test.com/.well-known/cf-custom-hostname-challenge/0d89c70d-ad9f-4843-b99f-6cc0252067e9"
+            },
+            "created_at": "2020-02-06T18:11:23.531995Z"
+        }
+        "#;
+
+        let hostname: CustomHostname = serde_json::from_str(json).unwrap();
+        assert_eq!(hostname.hostname, "app.example.com");
+        assert_eq!(hostname.status, Some(CustomHostnameStatus::Pending));
+        assert_eq!(
+            hostname.custom_metadata.as_ref().unwrap()["customer"],
+            "acme"
+        );
+
+        let ssl = hostname.ssl.unwrap();
+        assert_eq!(ssl.method, Some(DcvMethod::Txt));
+        assert_eq!(ssl.status, Some(CustomHostnameSslStatus::PendingValidation));
+        let records = ssl.validation_records.unwrap();
+        assert_eq!(
+            records[0].txt_name.as_deref(),
+            Some("_acme-challenge.app.example.com")
+        );
+        assert_eq!(
+            hostname
+                .ownership_verification
+                .unwrap()
+                .record_type
+                .as_deref(),
+            Some("txt")
+        );
+    }
+
+    #[test]
+    fn test_list_custom_hostnames_query() {
+        let endpoint = ListCustomHostnames {
+            zone_id: "zone-123",
+            params: ListCustomHostnamesParams {
+                hostname: Some("app.example.com".to_string()),
+                page: Some(2),
+                per_page: Some(50),
+                order: Some(ListCustomHostnamesOrder::SslStatus),
+                direction: Some(OrderDirection::Descending),
+                ssl: Some(1),
+                ..Default::default()
+            },
+        };
+
+        assert_eq!(endpoint.method(), Method::GET);
+        assert_eq!(endpoint.path(), "zones/zone-123/custom_hostnames");
+        assert_eq!(
+            endpoint.query(),
+            Some(
+                "hostname=app.example.com&page=2&per_page=50&order=ssl_status&direction=desc&ssl=1"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_create_custom_hostname_body() {
+        let endpoint = CreateCustomHostname {
+            zone_id: "zone-123",
+            params: CreateCustomHostnameParams {
+                hostname: "app.example.com".to_string(),
+                ssl: CustomHostnameSslParams::dv(DcvMethod::Http),
+                custom_metadata: Some(HashMap::from([(
+                    "customer".to_string(),
+                    "acme".to_string(),
+                )])),
+            },
+        };
+
+        assert_eq!(endpoint.method(), Method::POST);
+        assert_eq!(endpoint.path(), "zones/zone-123/custom_hostnames");
+        match endpoint.body() {
+            Some(RequestBody::Json(json)) => assert_eq!(
+                json,
+                r#"{"hostname":"app.example.com","ssl":{"method":"http","type":"dv"},"custom_metadata":{"customer":"acme"}}"#
+            ),
+            _ => panic!("Expected JSON body"),
+        }
+    }
+
+    #[test]
+    fn test_custom_hostname_endpoints() {
+        let get = GetCustomHostname {
+            zone_id: "zone-123",
+            custom_hostname_id: "ch-456",
+        };
+        assert_eq!(get.method(), Method::GET);
+        assert_eq!(get.path(), "zones/zone-123/custom_hostnames/ch-456");
+
+        let edit = EditCustomHostname {
+            zone_id: "zone-123",
+            custom_hostname_id: "ch-456",
+            params: EditCustomHostnameParams {
+                custom_origin_server: Some("origin.example.com".to_string()),
+                ..Default::default()
+            },
+        };
+        assert_eq!(edit.method(), Method::PATCH);
+        match edit.body() {
+            Some(RequestBody::Json(json)) => {
+                assert_eq!(json, r#"{"custom_origin_server":"origin.example.com"}"#)
+            }
+            _ => panic!("Expected JSON body"),
+        }
+
+        let delete = DeleteCustomHostname {
+            zone_id: "zone-123",
+            custom_hostname_id: "ch-456",
+        };
+        assert_eq!(delete.method(), Method::DELETE);
+        assert_eq!(delete.path(), "zones/zone-123/custom_hostnames/ch-456");
+    }
+
+    #[test]
+    fn test_fallback_origin_endpoints() {
+        let path = "zones/zone-123/custom_hostnames/fallback_origin";
+
+        let get = GetFallbackOrigin {
+            zone_id: "zone-123",
+        };
+        assert_eq!(get.method(), Method::GET);
+        assert_eq!(get.path(), path);
+
+        let update = UpdateFallbackOrigin {
+            zone_id: "zone-123",
+            params: UpdateFallbackOriginParams {
+                origin: "fallback.example.com".to_string(),
+            },
+        };
+        assert_eq!(update.method(), Method::PUT);
+        assert_eq!(update.path(), path);
+
+        let delete = DeleteFallbackOrigin {
+            zone_id: "zone-123",
+        };
+        assert_eq!(delete.method(), Method::DELETE);
+        assert_eq!(delete.path(), path);
+
+        let origin: FallbackOrigin = serde_json::from_str(
+            r#"{"origin": "fallback.example.com", "status": "pending_deployment", "errors": []}"#,
+        )
+        .unwrap();
+        assert_eq!(origin.status, Some(FallbackOriginStatus::PendingDeployment));
+    }
+}
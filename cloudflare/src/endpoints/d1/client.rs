@@ -0,0 +1,198 @@
+use serde::de::DeserializeOwned;
+
+use super::data_structures::D1QueryResult;
+use super::query_database::{BatchQueryDatabase, QueryDatabase, QueryDatabaseParams};
+use crate::framework::client::async_api::Client;
+use crate::framework::response::ApiFailure;
+
+#[derive(thiserror::Error, Debug)]
+/// Errors encountered by a [`D1Client`]
+pub enum D1Error {
+    #[error("Cloudflare API request failed: {0}")]
+    Api(#[from] ApiFailure),
+    #[error("Row does not match the expected type: {0}")]
+    Row(#[from] serde_json::Error),
+}
+
+/// Runs queries against one D1 database and maps result rows to types
+///
+/// ```no_run
+/// # async fn example(client: &cloudflare::framework::client::async_api::Client) -> Result<(), cloudflare::endpoints::d1::D1Error> {
+/// use cloudflare::endpoints::d1::{D1Client, QueryDatabaseParams};
+///
+/// #[derive(serde::Deserialize)]
+/// struct User {
+///     id: i64,
+///     name: String,
+/// }
+///
+/// let db = D1Client::new(client, "account-id", "database-id");
+/// let users: Vec<User> = db
+///     .query_as(QueryDatabaseParams::new("SELECT * FROM users WHERE team = ?".into()).bind("core"))
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct D1Client<'a> {
+    client: &'a Client,
+    account_id: &'a str,
+    database_id: &'a str,
+}
+
+impl<'a> D1Client<'a> {
+    pub fn new(client: &'a Client, account_id: &'a str, database_id: &'a str) -> Self {
+        D1Client {
+            client,
+            account_id,
+            database_id,
+        }
+    }
+
+    /// Runs a query. SQL with several statements has one result per statement.
+    pub async fn query(&self, params: QueryDatabaseParams) -> Result<Vec<D1QueryResult>, D1Error> {
+        let query = QueryDatabase::new(self.account_id, self.database_id, params);
+        Ok(self.client.request(&query).await?.result)
+    }
+
+    /// Runs a query and deserializes the rows of its last statement into `T`
+    pub async fn query_as<T: DeserializeOwned>(
+        &self,
+        params: QueryDatabaseParams,
+    ) -> Result<Vec<T>, D1Error> {
+        match self.query(params).await?.last() {
+            Some(result) => Ok(result.rows()?),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Runs a query and deserializes the first row of its last statement into `T`,
+    /// or returns `None` if there are no rows
+    pub async fn query_first_as<T: DeserializeOwned>(
+        &self,
+        params: QueryDatabaseParams,
+    ) -> Result<Option<T>, D1Error> {
+        match self.query(params).await?.last() {
+            Some(result) => Ok(result.first_row()?),
+            None => Ok(None),
+        }
+    }
+
+    /// Runs statements in order in one request and returns one result per statement.
+    /// Use [`D1QueryResult::rows`] to map the rows of each to its own type.
+    pub async fn batch(
+        &self,
+        statements: Vec<QueryDatabaseParams>,
+    ) -> Result<Vec<D1QueryResult>, D1Error> {
+        let batch = BatchQueryDatabase::new(self.account_id, self.database_id, statements);
+        Ok(self.client.request(&batch).await?.result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::{Matcher, Server};
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use super::*;
+    use crate::framework::auth::Credentials;
+    use crate::framework::client::ClientConfig;
+    use crate::framework::Environment;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct User {
+        id: i64,
+        name: String,
+    }
+
+    fn client(server: &Server) -> Client {
+        Client::new(
+            Credentials::UserAuthToken {
+                token: "api-token".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap()
+    }
+
+    fn success(result: serde_json::Value) -> String {
+        json!({"result": result, "success": true, "errors": [], "messages": []}).to_string()
+    }
+
+    fn query_result(rows: serde_json::Value) -> serde_json::Value {
+        json!({"results": rows, "meta": {"changes": 0}, "success": true})
+    }
+
+    #[tokio::test]
+    async fn test_query_as() {
+        let mut server = Server::new_async().await;
+        let query = server
+            .mock("POST", "/accounts/acct/d1/database/db/query")
+            .match_body(Matcher::Json(json!({
+                "sql": "SELECT id, name FROM users WHERE team = ?",
+                "params": ["core"]
+            })))
+            .with_body(success(json!([query_result(
+                json!([{"id": 1, "name": "Ada"}, {"id": 2, "name": "Grace"}])
+            )])))
+            .expect(2)
+            .create();
+
+        let client = client(&server);
+        let db = D1Client::new(&client, "acct", "db");
+        let params = QueryDatabaseParams::new("SELECT id, name FROM users WHERE team = ?".into())
+            .bind("core");
+        let users: Vec<User> = db.query_as(params.clone()).await.unwrap();
+        assert_eq!(
+            users,
+            [
+                User {
+                    id: 1,
+                    name: "Ada".to_string()
+                },
+                User {
+                    id: 2,
+                    name: "Grace".to_string()
+                }
+            ]
+        );
+
+        let error = db.query_as::<(String,)>(params).await.unwrap_err();
+        assert!(matches!(error, D1Error::Row(_)));
+        query.assert();
+    }
+
+    #[tokio::test]
+    async fn test_batch() {
+        let mut server = Server::new_async().await;
+        let batch = server
+            .mock("POST", "/accounts/acct/d1/database/db/query")
+            .match_body(Matcher::Json(json!({"batch": [
+                {"sql": "INSERT INTO users (name) VALUES (?)", "params": ["Ada"]},
+                {"sql": "SELECT COUNT(*) AS count FROM users", "params": []}
+            ]})))
+            .with_body(success(json!([
+                query_result(json!([])),
+                query_result(json!([{"count": 1}]))
+            ])))
+            .create();
+
+        #[derive(Deserialize)]
+        struct Count {
+            count: u64,
+        }
+
+        let client = client(&server);
+        let results = D1Client::new(&client, "acct", "db")
+            .batch(vec![
+                QueryDatabaseParams::new("INSERT INTO users (name) VALUES (?)".into()).bind("Ada"),
+                QueryDatabaseParams::new("SELECT COUNT(*) AS count FROM users".into()),
+            ])
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[1].first_row::<Count>().unwrap().unwrap().count, 1);
+        batch.assert();
+    }
+}
@@ -1,4 +1,5 @@
 use crate::framework::response::ApiResult;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -142,6 +143,27 @@ pub struct D1QueryResult {
     pub success: bool,
 }
 
+impl D1QueryResult {
+    /// Deserializes each row into `T`, matching columns to fields by name
+    pub fn rows<T: DeserializeOwned>(&self) -> Result<Vec<T>, serde_json::Error> {
+        self.results.iter().map(row_as).collect()
+    }
+
+    /// Deserializes the first row into `T`, or returns `None` if there are no rows
+    pub fn first_row<T: DeserializeOwned>(&self) -> Result<Option<T>, serde_json::Error> {
+        self.results.first().map(row_as).transpose()
+    }
+}
+
+fn row_as<T: DeserializeOwned>(
+    row: &HashMap<String, serde_json::Value>,
+) -> Result<T, serde_json::Error> {
+    let row = row
+        .iter()
+        .map(|(column, value)| (column.clone(), value.clone()));
+    serde_json::from_value(serde_json::Value::Object(row.collect()))
+}
+
 impl ApiResult for D1QueryResult {}
 impl ApiResult for Vec<D1QueryResult> {}
 
@@ -279,6 +301,46 @@ mod tests {
         assert_eq!(result.meta.rows_read, Some(2.0));
     }
 
+    #[test]
+    fn test_d1_query_result_rows() {
+        #[derive(Deserialize, Debug, PartialEq)]
+        struct User {
+            id: i64,
+            name: String,
+            email: Option<String>,
+        }
+
+        let result: D1QueryResult = serde_json::from_value(serde_json::json!({
+            "results": [
+                {"id": 1, "name": "Alice", "email": "alice@example.com"},
+                {"id": 2, "name": "Bob", "email": null}
+            ],
+            "meta": {},
+            "success": true
+        }))
+        .unwrap();
+
+        let users: Vec<User> = result.rows().unwrap();
+        assert_eq!(
+            users[1],
+            User {
+                id: 2,
+                name: "Bob".to_string(),
+                email: None
+            }
+        );
+        assert_eq!(
+            result
+                .first_row::<User>()
+                .unwrap()
+                .unwrap()
+                .email
+                .as_deref(),
+            Some("alice@example.com")
+        );
+        assert!(result.rows::<(i64,)>().is_err());
+    }
+
     #[test]
     fn test_d1_raw_query_result_deserialization() {
         let json = r#"
@@ -3,13 +3,15 @@ D1 database endpoints for Cloudflare's serverless SQL database service.
 
 This module provides comprehensive D1 database management capabilities including:
 - Database CRUD operations (create, read, update, delete)
-- SQL query execution (parameterized and raw queries)
+- SQL query execution (parameterized, batched and raw queries)
+- Typed row mapping through [`D1Client`]
 - Data import/export operations
 - Read replication configuration
 
 All endpoints are fully compliant with the official Cloudflare API specification.
 */
 
+pub mod client;
 pub mod create_database;
 pub mod data_structures;
 pub mod delete_database;
@@ -22,6 +24,7 @@ pub mod raw_query;
 pub mod update_database;
 pub mod update_partial_database;
 
+pub use client::{D1Client, D1Error};
 pub use create_database::{CreateDatabase, CreateDatabaseParams};
 pub use data_structures::{
     D1Database, D1ExportResult, D1ImportResult, D1PrimaryLocationHint, D1QueryMeta, D1QueryResult,
@@ -33,7 +36,9 @@ pub use export_database::{ExportDatabase, ExportDatabaseParams};
 pub use get_database::GetDatabase;
 pub use import_database::{ImportDatabase, ImportDatabaseParams};
 pub use list_databases::ListDatabases;
-pub use query_database::{QueryDatabase, QueryDatabaseParams};
+pub use query_database::{
+    BatchQueryDatabase, D1Real, D1Value, NonFiniteFloat, QueryDatabase, QueryDatabaseParams,
+};
 pub use raw_query::{RawQuery, RawQueryParams};
 pub use update_database::{UpdateDatabase, UpdateDatabaseParams};
 pub use update_partial_database::{UpdatePartialDatabase, UpdatePartialDatabaseParams};
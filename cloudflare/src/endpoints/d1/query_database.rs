@@ -73,6 +73,183 @@ impl QueryDatabaseParams {
     pub fn with_params(sql: String, params: Vec<serde_json::Value>) -> Self {
         Self { sql, params }
     }
+
+    /// Binds the next `?` parameter of the statement
+    ///
+    /// Accepts integers, booleans, strings, byte blobs, [`D1Real`]s and `Option`s of them,
+    /// where `None` binds `NULL`. Use [`Self::bind_f64`] for plain floats.
+    pub fn bind(mut self, value: impl Into<D1Value>) -> Self {
+        self.params.push(value.into().into());
+        self
+    }
+
+    /// Binds the next `?` parameter to a float, failing on NaN and infinities
+    pub fn bind_f64(self, value: f64) -> Result<Self, NonFiniteFloat> {
+        Ok(self.bind(D1Real::new(value)?))
+    }
+}
+
+/// A value bound to a statement parameter
+#[derive(Clone, Debug, PartialEq)]
+pub enum D1Value {
+    Null,
+    Integer(i64),
+    Real(D1Real),
+    Text(String),
+    /// Sent as an array of byte values, the same shape D1 returns `BLOB` columns in
+    Blob(Vec<u8>),
+}
+
+/// A finite float, since JSON has no NaN or infinities
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct D1Real(f64);
+
+impl D1Real {
+    pub fn new(value: f64) -> Result<Self, NonFiniteFloat> {
+        if value.is_finite() {
+            Ok(D1Real(value))
+        } else {
+            Err(NonFiniteFloat(value))
+        }
+    }
+
+    pub fn get(self) -> f64 {
+        self.0
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq)]
+/// A float that cannot be bound, as JSON cannot represent it
+#[error("Cannot bind {0}, only finite floats can be sent to D1")]
+pub struct NonFiniteFloat(pub f64);
+
+impl From<D1Value> for serde_json::Value {
+    fn from(value: D1Value) -> Self {
+        match value {
+            D1Value::Null => serde_json::Value::Null,
+            D1Value::Integer(value) => value.into(),
+            D1Value::Real(value) => value.get().into(),
+            D1Value::Text(value) => value.into(),
+            D1Value::Blob(value) => value.into(),
+        }
+    }
+}
+
+impl From<i64> for D1Value {
+    fn from(value: i64) -> Self {
+        D1Value::Integer(value)
+    }
+}
+
+impl From<i32> for D1Value {
+    fn from(value: i32) -> Self {
+        D1Value::Integer(value.into())
+    }
+}
+
+impl From<u32> for D1Value {
+    fn from(value: u32) -> Self {
+        D1Value::Integer(value.into())
+    }
+}
+
+impl TryFrom<f64> for D1Value {
+    type Error = NonFiniteFloat;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        D1Real::new(value).map(Into::into)
+    }
+}
+
+impl From<bool> for D1Value {
+    /// SQLite stores booleans as `0` and `1`
+    fn from(value: bool) -> Self {
+        D1Value::Integer(value.into())
+    }
+}
+
+impl From<&str> for D1Value {
+    fn from(value: &str) -> Self {
+        D1Value::Text(value.to_string())
+    }
+}
+
+impl From<String> for D1Value {
+    fn from(value: String) -> Self {
+        D1Value::Text(value)
+    }
+}
+
+impl From<D1Real> for D1Value {
+    fn from(value: D1Real) -> Self {
+        D1Value::Real(value)
+    }
+}
+
+impl From<&[u8]> for D1Value {
+    fn from(value: &[u8]) -> Self {
+        D1Value::Blob(value.to_vec())
+    }
+}
+
+impl From<Vec<u8>> for D1Value {
+    fn from(value: Vec<u8>) -> Self {
+        D1Value::Blob(value)
+    }
+}
+
+impl<T: Into<D1Value>> From<Option<T>> for D1Value {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(D1Value::Null, Into::into)
+    }
+}
+
+/// Execute several parameterized SQL statements in one request
+///
+/// The statements run in order, in a single transaction, and there is one result
+/// per statement.
+///
+/// <https://developers.cloudflare.com/api/resources/d1/subresources/database/methods/query/>
+#[derive(Debug)]
+pub struct BatchQueryDatabase<'a> {
+    pub account_identifier: &'a str,
+    pub database_identifier: &'a str,
+    pub statements: Vec<QueryDatabaseParams>,
+}
+
+impl<'a> BatchQueryDatabase<'a> {
+    pub fn new(
+        account_identifier: &'a str,
+        database_identifier: &'a str,
+        statements: Vec<QueryDatabaseParams>,
+    ) -> Self {
+        Self {
+            account_identifier,
+            database_identifier,
+            statements,
+        }
+    }
+}
+
+impl EndpointSpec for BatchQueryDatabase<'_> {
+    type JsonResponse = Vec<D1QueryResult>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/d1/database/{}/query",
+            self.account_identifier, self.database_identifier
+        )
+    }
+
+    fn body(&self) -> Option<RequestBody<'_>> {
+        let body = serde_json::json!({ "batch": self.statements });
+        Some(RequestBody::Json(body.to_string()))
+    }
 }
 
 #[cfg(test)]
@@ -92,4 +269,61 @@ mod tests {
         assert_eq!(params_with_bindings.sql, "SELECT * FROM users WHERE id = ?");
         assert_eq!(params_with_bindings.params.len(), 1);
     }
+
+    #[test]
+    fn test_bind() {
+        let params =
+            QueryDatabaseParams::new("INSERT INTO files VALUES (?, ?, ?, ?, ?, ?, ?)".into())
+                .bind(42i64)
+                .bind("notes.txt")
+                .bind_f64(1.5)
+                .unwrap()
+                .bind(D1Real::new(0.25).ok())
+                .bind(None::<&str>)
+                .bind(true)
+                .bind(&b"\x00\xff"[..]);
+        assert_eq!(
+            serde_json::to_value(&params).unwrap()["params"],
+            serde_json::json!([42, "notes.txt", 1.5, 0.25, null, 1, [0, 255]])
+        );
+        assert_eq!(D1Value::from(vec![7u8]), D1Value::Blob(vec![7]));
+
+        assert_eq!(
+            D1Value::try_from(f64::NAN).unwrap_err().to_string(),
+            "Cannot bind NaN, only finite floats can be sent to D1"
+        );
+        assert!(D1Value::try_from(f64::INFINITY).is_err());
+        assert_eq!(
+            QueryDatabaseParams::new("SELECT ?".into())
+                .bind_f64(f64::NAN)
+                .unwrap_err()
+                .to_string(),
+            "Cannot bind NaN, only finite floats can be sent to D1"
+        );
+        assert!(D1Value::try_from(f64::NEG_INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_batch_query_database() {
+        let batch = BatchQueryDatabase::new(
+            "account",
+            "database",
+            vec![
+                QueryDatabaseParams::new("INSERT INTO users (name) VALUES (?)".into()).bind("Ada"),
+                QueryDatabaseParams::new("SELECT * FROM users".into()),
+            ],
+        );
+        assert_eq!(batch.method(), Method::POST);
+        assert_eq!(batch.path(), "accounts/account/d1/database/database/query");
+        let Some(RequestBody::Json(body)) = batch.body() else {
+            panic!("expected a JSON body");
+        };
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&body).unwrap(),
+            serde_json::json!({"batch": [
+                {"sql": "INSERT INTO users (name) VALUES (?)", "params": ["Ada"]},
+                {"sql": "SELECT * FROM users", "params": []}
+            ]})
+        );
+    }
 }
@@ -8,6 +8,7 @@ pub mod account;
 pub mod ai;
 pub mod argo_tunnel;
 pub mod cfd_tunnel;
+pub mod custom_hostnames;
 pub mod d1;
 pub mod dns;
 pub mod load_balancing;
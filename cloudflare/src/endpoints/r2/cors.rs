@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::r2::EmptyMap;
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::{ApiResult, ApiSuccess};
+
+/// Cross-origin requests a bucket allows, e.g. browser uploads through presigned URLs
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct BucketCorsPolicy {
+    #[serde(default)]
+    pub rules: Vec<CorsRule>,
+}
+
+impl ApiResult for BucketCorsPolicy {}
+
+/// One CORS rule. A request is allowed if any rule matches it.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CorsRule {
+    /// Identifier of the rule
+    pub id: Option<String>,
+    pub allowed: CorsAllowed,
+    /// Response headers browsers may read
+    pub expose_headers: Option<Vec<String>>,
+    /// How long browsers may cache the result of a preflight request
+    pub max_age_seconds: Option<u32>,
+}
+
+/// What a [`CorsRule`] matches
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct CorsAllowed {
+    /// HTTP methods, e.g. `GET` and `PUT`
+    pub methods: Vec<String>,
+    /// Origins, e.g. `https://example.com`, or `*` for any
+    pub origins: Vec<String>,
+    /// Request headers browsers may send
+    pub headers: Option<Vec<String>>,
+}
+
+/// Gets the CORS rules of a bucket.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/cors/methods/get/>
+#[derive(Debug)]
+pub struct GetBucketCors<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+}
+
+impl EndpointSpec for GetBucketCors<'_> {
+    type JsonResponse = BucketCorsPolicy;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/cors",
+            self.account_identifier, self.bucket_name
+        )
+    }
+}
+
+/// Replaces the CORS rules of a bucket.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/cors/methods/update/>
+#[derive(Debug)]
+pub struct PutBucketCors<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    pub policy: BucketCorsPolicy,
+}
+
+impl EndpointSpec for PutBucketCors<'_> {
+    type JsonResponse = EmptyMap;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/cors",
+            self.account_identifier, self.bucket_name
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody<'_>> {
+        Some(RequestBody::Json(
+            serde_json::to_string(&self.policy).unwrap(),
+        ))
+    }
+}
+
+/// Removes every CORS rule of a bucket.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/cors/methods/delete/>
+#[derive(Debug)]
+pub struct DeleteBucketCors<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+}
+
+impl EndpointSpec for DeleteBucketCors<'_> {
+    type JsonResponse = EmptyMap;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/cors",
+            self.account_identifier, self.bucket_name
+        )
+    }
+}
@@ -0,0 +1,296 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::{ApiResult, ApiSuccess};
+
+/// Minimum TLS version a custom domain accepts
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MinTlsVersion {
+    #[serde(rename = "1.0")]
+    Tls1_0,
+    #[serde(rename = "1.1")]
+    Tls1_1,
+    #[serde(rename = "1.2")]
+    Tls1_2,
+    #[serde(rename = "1.3")]
+    Tls1_3,
+}
+
+/// A domain of a Cloudflare zone serving the objects of a bucket
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomDomain {
+    pub domain: String,
+    pub enabled: bool,
+    pub status: CustomDomainStatus,
+    #[serde(rename = "minTLS")]
+    pub min_tls: Option<MinTlsVersion>,
+    pub zone_id: Option<String>,
+    pub zone_name: Option<String>,
+}
+
+impl ApiResult for CustomDomain {}
+
+/// Progress of setting up a custom domain
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct CustomDomainStatus {
+    pub ownership: CustomDomainState,
+    pub ssl: CustomDomainState,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CustomDomainState {
+    Initializing,
+    Pending,
+    Active,
+    Deactivated,
+    Blocked,
+    Error,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct ListCustomDomainsResult {
+    pub domains: Vec<CustomDomain>,
+}
+
+impl ApiResult for ListCustomDomainsResult {}
+
+/// A custom domain after it was attached, updated or removed
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct CustomDomainChange {
+    pub domain: String,
+    pub enabled: Option<bool>,
+    #[serde(rename = "minTLS")]
+    pub min_tls: Option<MinTlsVersion>,
+}
+
+impl ApiResult for CustomDomainChange {}
+
+/// Lists the custom domains of a bucket.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/domains/subresources/custom/methods/list/>
+#[derive(Debug)]
+pub struct ListBucketCustomDomains<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+}
+
+impl EndpointSpec for ListBucketCustomDomains<'_> {
+    type JsonResponse = ListCustomDomainsResult;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/domains/custom",
+            self.account_identifier, self.bucket_name
+        )
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachCustomDomainParams {
+    /// Domain name, which must belong to the zone
+    pub domain: String,
+    pub enabled: bool,
+    /// Zone the domain belongs to
+    pub zone_id: String,
+    #[serde(rename = "minTLS")]
+    pub min_tls: Option<MinTlsVersion>,
+}
+
+/// Serves a bucket from a domain of one of the account's zones.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/domains/subresources/custom/methods/create/>
+#[derive(Debug)]
+pub struct AttachBucketCustomDomain<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    pub params: AttachCustomDomainParams,
+}
+
+impl EndpointSpec for AttachBucketCustomDomain<'_> {
+    type JsonResponse = CustomDomainChange;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/domains/custom",
+            self.account_identifier, self.bucket_name
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody<'_>> {
+        Some(RequestBody::Json(
+            serde_json::to_string(&self.params).unwrap(),
+        ))
+    }
+}
+
+/// Gets one custom domain of a bucket.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/domains/subresources/custom/methods/get/>
+#[derive(Debug)]
+pub struct GetBucketCustomDomain<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    pub domain: &'a str,
+}
+
+impl EndpointSpec for GetBucketCustomDomain<'_> {
+    type JsonResponse = CustomDomain;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/domains/custom/{}",
+            self.account_identifier, self.bucket_name, self.domain
+        )
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct UpdateCustomDomainParams {
+    pub enabled: Option<bool>,
+    #[serde(rename = "minTLS")]
+    pub min_tls: Option<MinTlsVersion>,
+}
+
+/// Enables, disables or changes the minimum TLS version of a custom domain.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/domains/subresources/custom/methods/update/>
+#[derive(Debug)]
+pub struct UpdateBucketCustomDomain<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    pub domain: &'a str,
+    pub params: UpdateCustomDomainParams,
+}
+
+impl EndpointSpec for UpdateBucketCustomDomain<'_> {
+    type JsonResponse = CustomDomainChange;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/domains/custom/{}",
+            self.account_identifier, self.bucket_name, self.domain
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody<'_>> {
+        Some(RequestBody::Json(
+            serde_json::to_string(&self.params).unwrap(),
+        ))
+    }
+}
+
+/// Stops serving a bucket from a custom domain.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/domains/subresources/custom/methods/delete/>
+#[derive(Debug)]
+pub struct RemoveBucketCustomDomain<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    pub domain: &'a str,
+}
+
+impl EndpointSpec for RemoveBucketCustomDomain<'_> {
+    type JsonResponse = CustomDomainChange;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/domains/custom/{}",
+            self.account_identifier, self.bucket_name, self.domain
+        )
+    }
+}
+
+/// The public `r2.dev` domain of a bucket
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ManagedDomain {
+    pub bucket_id: String,
+    /// e.g. `pub-<hash>.r2.dev`
+    pub domain: String,
+    pub enabled: bool,
+}
+
+impl ApiResult for ManagedDomain {}
+
+/// Gets whether a bucket is public on its `r2.dev` domain.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/domains/subresources/managed/methods/list/>
+#[derive(Debug)]
+pub struct GetBucketManagedDomain<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+}
+
+impl EndpointSpec for GetBucketManagedDomain<'_> {
+    type JsonResponse = ManagedDomain;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/domains/managed",
+            self.account_identifier, self.bucket_name
+        )
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct UpdateManagedDomainParams {
+    pub enabled: bool,
+}
+
+/// Makes a bucket public on its `r2.dev` domain, or private again.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/domains/subresources/managed/methods/update/>
+#[derive(Debug)]
+pub struct UpdateBucketManagedDomain<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    pub params: UpdateManagedDomainParams,
+}
+
+impl EndpointSpec for UpdateBucketManagedDomain<'_> {
+    type JsonResponse = ManagedDomain;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/domains/managed",
+            self.account_identifier, self.bucket_name
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody<'_>> {
+        Some(RequestBody::Json(
+            serde_json::to_string(&self.params).unwrap(),
+        ))
+    }
+}
@@ -0,0 +1,138 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::r2::EmptyMap;
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::{ApiResult, ApiSuccess};
+
+/// Object changes that send a message to a Queue
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventNotificationAction {
+    PutObject,
+    CopyObject,
+    DeleteObject,
+    CompleteMultipartUpload,
+    LifecycleDeletion,
+}
+
+/// Which object changes a Queue is notified of
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct EventNotificationRule {
+    pub actions: Vec<EventNotificationAction>,
+    /// Only notify of keys starting with this
+    pub prefix: Option<String>,
+    /// Only notify of keys ending with this
+    pub suffix: Option<String>,
+    pub description: Option<String>,
+    /// Set by the API
+    #[serde(skip_serializing)]
+    pub rule_id: Option<String>,
+    /// Set by the API
+    #[serde(skip_serializing)]
+    pub created_at: Option<String>,
+}
+
+/// The rules of a bucket for one Queue
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct QueueEventNotifications {
+    pub queue_id: String,
+    pub queue_name: Option<String>,
+    #[serde(default)]
+    pub rules: Vec<EventNotificationRule>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BucketEventNotifications {
+    pub bucket_name: Option<String>,
+    #[serde(default)]
+    pub queues: Vec<QueueEventNotifications>,
+}
+
+impl ApiResult for BucketEventNotifications {}
+
+/// Lists the event notification rules of a bucket, for every Queue.
+/// <https://developers.cloudflare.com/api/resources/event_notifications/subresources/r2/subresources/configuration/methods/get/>
+#[derive(Debug)]
+pub struct GetBucketEventNotifications<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+}
+
+impl EndpointSpec for GetBucketEventNotifications<'_> {
+    type JsonResponse = BucketEventNotifications;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/event_notifications/r2/{}/configuration",
+            self.account_identifier, self.bucket_name
+        )
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct PutEventNotificationRulesParams {
+    pub rules: Vec<EventNotificationRule>,
+}
+
+/// Adds rules that notify a Queue of changes to a bucket.
+/// <https://developers.cloudflare.com/api/resources/event_notifications/subresources/r2/subresources/configuration/subresources/queues/methods/update/>
+#[derive(Debug)]
+pub struct PutBucketEventNotificationRules<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    pub queue_id: &'a str,
+    pub params: PutEventNotificationRulesParams,
+}
+
+impl EndpointSpec for PutBucketEventNotificationRules<'_> {
+    type JsonResponse = EmptyMap;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/event_notifications/r2/{}/configuration/queues/{}",
+            self.account_identifier, self.bucket_name, self.queue_id
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody<'_>> {
+        Some(RequestBody::Json(
+            serde_json::to_string(&self.params).unwrap(),
+        ))
+    }
+}
+
+/// Removes every rule that notifies a Queue of changes to a bucket.
+/// <https://developers.cloudflare.com/api/resources/event_notifications/subresources/r2/subresources/configuration/subresources/queues/methods/delete/>
+#[derive(Debug)]
+pub struct DeleteBucketEventNotificationRules<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    pub queue_id: &'a str,
+}
+
+impl EndpointSpec for DeleteBucketEventNotificationRules<'_> {
+    type JsonResponse = EmptyMap;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/event_notifications/r2/{}/configuration/queues/{}",
+            self.account_identifier, self.bucket_name, self.queue_id
+        )
+    }
+}
@@ -0,0 +1,119 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::r2::EmptyMap;
+use super::R2StorageClass;
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::{ApiResult, ApiSuccess};
+
+/// Rules that delete or move objects as they age
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct BucketLifecyclePolicy {
+    #[serde(default)]
+    pub rules: Vec<LifecycleRule>,
+}
+
+impl ApiResult for BucketLifecyclePolicy {}
+
+/// One lifecycle rule, applying to the objects matching its conditions
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LifecycleRule {
+    /// Unique identifier of the rule
+    pub id: String,
+    pub enabled: bool,
+    pub conditions: LifecycleConditions,
+    /// When objects are deleted
+    pub delete_objects_transition: Option<LifecycleTransition>,
+    /// When unfinished multipart uploads are aborted. Only [`LifecycleCondition::Age`] applies.
+    pub abort_multipart_uploads_transition: Option<LifecycleTransition>,
+    /// When objects move to another storage class
+    pub storage_class_transitions: Option<Vec<StorageClassTransition>>,
+}
+
+/// Objects a [`LifecycleRule`] applies to
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct LifecycleConditions {
+    /// Key prefix, empty for every object
+    pub prefix: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct LifecycleTransition {
+    pub condition: LifecycleCondition,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageClassTransition {
+    pub condition: LifecycleCondition,
+    pub storage_class: R2StorageClass,
+}
+
+/// When a transition happens
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type")]
+pub enum LifecycleCondition {
+    /// Once objects are this many seconds old
+    Age {
+        #[serde(rename = "maxAge")]
+        max_age: u64,
+    },
+    /// From this time on
+    Date { date: DateTime<Utc> },
+}
+
+/// Gets the lifecycle rules of a bucket.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/lifecycle/methods/get/>
+#[derive(Debug)]
+pub struct GetBucketLifecycle<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+}
+
+impl EndpointSpec for GetBucketLifecycle<'_> {
+    type JsonResponse = BucketLifecyclePolicy;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/lifecycle",
+            self.account_identifier, self.bucket_name
+        )
+    }
+}
+
+/// Replaces the lifecycle rules of a bucket.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/lifecycle/methods/update/>
+#[derive(Debug)]
+pub struct PutBucketLifecycle<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    pub policy: BucketLifecyclePolicy,
+}
+
+impl EndpointSpec for PutBucketLifecycle<'_> {
+    type JsonResponse = EmptyMap;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/lifecycle",
+            self.account_identifier, self.bucket_name
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody<'_>> {
+        Some(RequestBody::Json(
+            serde_json::to_string(&self.policy).unwrap(),
+        ))
+    }
+}
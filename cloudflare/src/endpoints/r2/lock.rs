@@ -0,0 +1,96 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::r2::EmptyMap;
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::{ApiResult, ApiSuccess};
+
+/// Rules that keep objects from being deleted or overwritten
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct BucketLockPolicy {
+    #[serde(default)]
+    pub rules: Vec<BucketLockRule>,
+}
+
+impl ApiResult for BucketLockPolicy {}
+
+/// One lock rule, applying to the objects under its prefix
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct BucketLockRule {
+    /// Unique identifier of the rule
+    pub id: String,
+    pub enabled: bool,
+    /// Key prefix, `None` for every object
+    pub prefix: Option<String>,
+    pub condition: BucketLockCondition,
+}
+
+/// How long objects stay locked
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type")]
+pub enum BucketLockCondition {
+    /// Until they are this many seconds old
+    Age {
+        #[serde(rename = "maxAgeSeconds")]
+        max_age_seconds: u64,
+    },
+    /// Until this time
+    Date { date: DateTime<Utc> },
+    /// Until the rule is removed
+    Indefinite,
+}
+
+/// Gets the lock rules of a bucket.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/locks/methods/get/>
+#[derive(Debug)]
+pub struct GetBucketLock<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+}
+
+impl EndpointSpec for GetBucketLock<'_> {
+    type JsonResponse = BucketLockPolicy;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/lock",
+            self.account_identifier, self.bucket_name
+        )
+    }
+}
+
+/// Replaces the lock rules of a bucket.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/locks/methods/update/>
+#[derive(Debug)]
+pub struct PutBucketLock<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    pub policy: BucketLockPolicy,
+}
+
+impl EndpointSpec for PutBucketLock<'_> {
+    type JsonResponse = EmptyMap;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/lock",
+            self.account_identifier, self.bucket_name
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody<'_>> {
+        Some(RequestBody::Json(
+            serde_json::to_string(&self.policy).unwrap(),
+        ))
+    }
+}
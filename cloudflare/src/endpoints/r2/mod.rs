@@ -1,4 +1,19 @@
+pub mod cors;
+pub mod domains;
+pub mod event_notifications;
+pub mod lifecycle;
+pub mod lock;
+pub mod multipart;
+mod multipart_tests;
+pub mod presign;
+mod presign_tests;
 pub mod r2;
+pub mod s3;
+mod s3_tests;
+mod settings_tests;
+mod sigv4;
+pub mod sippy;
+pub mod temp_credentials;
 mod tests;
 
 pub use r2::{
@@ -0,0 +1,564 @@
+/*!
+Multipart uploads of large objects to R2.
+
+The operations on [`R2Bucket`] map one to one onto the S3 multipart API. [`MultipartUploader`]
+builds on them to upload a file or stream of any size: it splits the input into parts, uploads
+them concurrently, retries failed parts and records progress in a manifest file, so an
+interrupted upload continues where it stopped instead of starting over.
+
+```no_run
+# async fn example(bucket: cloudflare::endpoints::r2::s3::R2Bucket<'_>) -> Result<(), cloudflare::endpoints::r2::multipart::UploadError> {
+use cloudflare::endpoints::r2::multipart::MultipartUploader;
+
+MultipartUploader::new(bucket, "releases/app.tar.gz")
+    .manifest("app.tar.gz.upload.json")
+    .on_progress(|progress| println!("{} bytes uploaded", progress.uploaded_bytes))
+    .upload_file("app.tar.gz")
+    .await?;
+# Ok(())
+# }
+```
+ */
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use futures_channel::oneshot;
+use futures_util::future;
+use futures_util::io::{AsyncRead, AsyncReadExt};
+use futures_util::stream::{self, Stream, StreamExt, TryStreamExt};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use super::s3::{self, PutObjectOptions, R2Bucket, R2Error};
+
+/// Smallest size of every part but the last
+pub const MIN_PART_SIZE: u64 = 5 * 1024 * 1024;
+/// Most parts in one upload
+pub const MAX_PARTS: u32 = 10_000;
+
+impl R2Bucket<'_> {
+    /// Starts a multipart upload and returns its upload ID. `options` apply to the final object.
+    pub async fn create_multipart_upload(
+        &self,
+        key: &str,
+        options: &PutObjectOptions,
+    ) -> Result<String, R2Error> {
+        let url = self.client.url(self.name, Some(key), &[("uploads", "")]);
+        let response = self
+            .client
+            .send(Method::POST, url, options.headers(), Vec::new())
+            .await?;
+        let result: InitiateMultipartUploadResult =
+            quick_xml::de::from_str(&response.text().await?)?;
+        Ok(result.upload_id)
+    }
+
+    /// Uploads one part, numbered from 1, and returns it for completing the upload
+    pub async fn upload_part(
+        &self,
+        key: &str,
+        upload_id: &str,
+        part_number: u32,
+        body: Vec<u8>,
+    ) -> Result<CompletedPart, R2Error> {
+        let part = part_number.to_string();
+        let query = [("partNumber", part.as_str()), ("uploadId", upload_id)];
+        let url = self.client.url(self.name, Some(key), &query);
+        let response = self.client.send(Method::PUT, url, Vec::new(), body).await?;
+        Ok(CompletedPart {
+            part_number,
+            etag: s3::header(response.headers(), "etag").unwrap_or_default(),
+        })
+    }
+
+    /// Assembles the uploaded parts into the object. Parts must be in ascending order.
+    pub async fn complete_multipart_upload(
+        &self,
+        key: &str,
+        upload_id: &str,
+        parts: &[CompletedPart],
+    ) -> Result<CompleteMultipartUploadOutput, R2Error> {
+        let url = self
+            .client
+            .url(self.name, Some(key), &[("uploadId", upload_id)]);
+        // Serializing numbers and strings cannot fail
+        let body = quick_xml::se::to_string(&CompleteMultipartUpload { parts }).unwrap();
+        let response = self
+            .client
+            .send(Method::POST, url, Vec::new(), body.into_bytes())
+            .await?;
+        let status = response.status();
+        let body = response.text().await?;
+        // Completing can fail after the 200 status has been sent
+        if body.contains("<Error>") {
+            return Err(s3::xml_error(status, &body));
+        }
+        Ok(quick_xml::de::from_str(&body)?)
+    }
+
+    /// Cancels a multipart upload and deletes its uploaded parts
+    pub async fn abort_multipart_upload(&self, key: &str, upload_id: &str) -> Result<(), R2Error> {
+        let url = self
+            .client
+            .url(self.name, Some(key), &[("uploadId", upload_id)]);
+        self.client
+            .send(Method::DELETE, url, Vec::new(), Vec::new())
+            .await?;
+        Ok(())
+    }
+
+    /// Lists one page of the parts uploaded so far. Pass `next_part_number_marker` of the
+    /// output as `part_number_marker` to get the next page.
+    pub async fn list_parts(
+        &self,
+        key: &str,
+        upload_id: &str,
+        part_number_marker: Option<u32>,
+    ) -> Result<ListPartsOutput, R2Error> {
+        let marker = part_number_marker.map(|marker| marker.to_string());
+        let mut query = vec![("uploadId", upload_id)];
+        if let Some(marker) = &marker {
+            query.push(("part-number-marker", marker));
+        }
+        let url = self.client.url(self.name, Some(key), &query);
+        let response = self
+            .client
+            .send(Method::GET, url, Vec::new(), Vec::new())
+            .await?;
+        Ok(quick_xml::de::from_str(&response.text().await?)?)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct InitiateMultipartUploadResult {
+    upload_id: String,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename = "CompleteMultipartUpload")]
+struct CompleteMultipartUpload<'a> {
+    #[serde(rename = "Part")]
+    parts: &'a [CompletedPart],
+}
+
+/// An uploaded part, as needed to complete the upload
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CompletedPart {
+    pub part_number: u32,
+    #[serde(rename = "ETag")]
+    pub etag: String,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CompleteMultipartUploadOutput {
+    pub key: Option<String>,
+    /// Entity tag of the assembled object, quoted
+    #[serde(rename = "ETag")]
+    pub etag: String,
+}
+
+/// One page of the parts of a multipart upload
+#[derive(Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ListPartsOutput {
+    #[serde(rename = "Part")]
+    pub parts: Vec<UploadedPart>,
+    pub is_truncated: bool,
+    pub next_part_number_marker: Option<u32>,
+}
+
+/// A part in a [`ListPartsOutput`]
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct UploadedPart {
+    pub part_number: u32,
+    #[serde(rename = "ETag")]
+    pub etag: String,
+    pub size: u64,
+    pub last_modified: Option<DateTime<Utc>>,
+}
+
+#[derive(thiserror::Error, Debug)]
+/// Errors encountered by a [`MultipartUploader`]
+pub enum UploadError {
+    #[error(transparent)]
+    R2(#[from] R2Error),
+    #[error("Failed to read the upload or its manifest: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid upload manifest: {0}")]
+    Manifest(#[from] serde_json::Error),
+    #[error("Upload needs more than {MAX_PARTS} parts, use a larger part size")]
+    TooManyParts,
+}
+
+/// Progress of an upload, reported after every part
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UploadProgress {
+    /// Bytes uploaded so far, including parts uploaded before resuming
+    pub uploaded_bytes: u64,
+    pub uploaded_parts: usize,
+    /// Size of the upload, if known
+    pub total_bytes: Option<u64>,
+}
+
+/// The state of an unfinished upload, saved after every part
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct UploadManifest {
+    pub bucket: String,
+    pub key: String,
+    pub upload_id: String,
+    pub part_size: u64,
+    /// Uploaded parts with their sizes
+    pub parts: Vec<ManifestPart>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ManifestPart {
+    pub part_number: u32,
+    pub etag: String,
+    pub size: u64,
+}
+
+type ProgressCallback<'a> = Box<dyn Fn(UploadProgress) + Send + Sync + 'a>;
+
+/// Uploads a file or stream of any size as a multipart upload
+pub struct MultipartUploader<'a> {
+    bucket: R2Bucket<'a>,
+    key: String,
+    options: PutObjectOptions,
+    part_size: u64,
+    concurrency: usize,
+    retries: usize,
+    manifest: Option<PathBuf>,
+    on_progress: Option<ProgressCallback<'a>>,
+}
+
+impl<'a> MultipartUploader<'a> {
+    /// Part size unless changed with [`MultipartUploader::part_size`]
+    pub const DEFAULT_PART_SIZE: u64 = 16 * 1024 * 1024;
+    /// Parts uploaded at the same time unless changed with [`MultipartUploader::concurrency`]
+    pub const DEFAULT_CONCURRENCY: usize = 4;
+    /// Retries of a failed part unless changed with [`MultipartUploader::retries`]
+    pub const DEFAULT_RETRIES: usize = 3;
+
+    pub fn new(bucket: R2Bucket<'a>, key: impl Into<String>) -> Self {
+        MultipartUploader {
+            bucket,
+            key: key.into(),
+            options: PutObjectOptions::default(),
+            part_size: Self::DEFAULT_PART_SIZE,
+            concurrency: Self::DEFAULT_CONCURRENCY,
+            retries: Self::DEFAULT_RETRIES,
+            manifest: None,
+            on_progress: None,
+        }
+    }
+
+    /// Sets the content type, metadata and other headers of the object
+    pub fn options(mut self, options: PutObjectOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Sets the size of every part but the last, at least [`MIN_PART_SIZE`]. Parts are held in
+    /// memory while they upload.
+    pub fn part_size(mut self, part_size: u64) -> Self {
+        self.part_size = part_size.max(MIN_PART_SIZE);
+        self
+    }
+
+    /// Sets how many parts are uploaded at the same time
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Sets how many times a part is retried after a network or server error
+    pub fn retries(mut self, retries: usize) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Saves progress to this file. If it holds an unfinished upload of the same object, that
+    /// upload is continued with its original part size. The file is removed once the upload
+    /// completes.
+    pub fn manifest(mut self, path: impl Into<PathBuf>) -> Self {
+        self.manifest = Some(path.into());
+        self
+    }
+
+    /// Calls `callback` after every uploaded part
+    pub fn on_progress(mut self, callback: impl Fn(UploadProgress) + Send + Sync + 'a) -> Self {
+        self.on_progress = Some(Box::new(callback));
+        self
+    }
+
+    /// Uploads a file. The file is read on a separate thread, one part at a time, so the
+    /// executor is never blocked on disk reads.
+    pub async fn upload_file(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<CompleteMultipartUploadOutput, UploadError> {
+        let file = File::open(path)?;
+        let total_bytes = file.metadata()?.len();
+        // A resumed upload keeps the part size it was started with
+        let part_size = self
+            .load()?
+            .map_or(self.part_size, |manifest| manifest.part_size);
+        if total_bytes.div_ceil(part_size) > MAX_PARTS as u64 {
+            return Err(UploadError::TooManyParts);
+        }
+        let manifest = self.start().await?;
+        let part_size = manifest.part_size;
+        let parts = stream::try_unfold(file, move |file| async move {
+            let (file, body) = read_file_part(file, part_size).await?;
+            Ok(Some((body, file)))
+        });
+        self.upload_from(manifest, parts, Some(total_bytes)).await
+    }
+
+    /// Uploads everything `reader` returns. When resuming, parts that were already uploaded
+    /// are read and skipped, so the reader must return the same bytes as before.
+    ///
+    /// Tokio readers can be adapted with `tokio_util::compat`.
+    pub async fn upload(
+        &self,
+        reader: impl AsyncRead + Unpin + Send,
+    ) -> Result<CompleteMultipartUploadOutput, UploadError> {
+        let manifest = self.start().await?;
+        let part_size = manifest.part_size;
+        let parts = stream::try_unfold(reader, move |mut reader| async move {
+            let mut body = Vec::new();
+            (&mut reader).take(part_size).read_to_end(&mut body).await?;
+            Ok(Some((body, reader)))
+        });
+        self.upload_from(manifest, parts, None).await
+    }
+
+    /// Uploads `parts`, and aborts the upload if it fails and there is no manifest to resume
+    /// it from, so its parts are not left behind
+    async fn upload_from(
+        &self,
+        manifest: UploadManifest,
+        parts: impl Stream<Item = std::io::Result<Vec<u8>>> + Send,
+        total_bytes: Option<u64>,
+    ) -> Result<CompleteMultipartUploadOutput, UploadError> {
+        let upload_id = manifest.upload_id.clone();
+        let result = self.upload_parts(manifest, parts, total_bytes).await;
+        if result.is_err() && self.manifest.is_none() {
+            // The upload failed already, which is the error worth returning
+            let _ = self
+                .bucket
+                .abort_multipart_upload(&self.key, &upload_id)
+                .await;
+        }
+        result
+    }
+
+    /// Uploads the parts `parts` returns. Every part but the last must be `part_size` long.
+    async fn upload_parts(
+        &self,
+        mut manifest: UploadManifest,
+        parts: impl Stream<Item = std::io::Result<Vec<u8>>> + Send,
+        total_bytes: Option<u64>,
+    ) -> Result<CompleteMultipartUploadOutput, UploadError> {
+        let part_size = manifest.part_size;
+        if total_bytes.is_some_and(|total| total.div_ceil(part_size) > MAX_PARTS as u64) {
+            return Err(UploadError::TooManyParts);
+        }
+        let mut progress = UploadProgress {
+            uploaded_bytes: manifest.parts.iter().map(|part| part.size).sum(),
+            uploaded_parts: manifest.parts.len(),
+            total_bytes,
+        };
+        let uploaded: Vec<u32> = manifest.parts.iter().map(|p| p.part_number).collect();
+
+        let parts = parts
+            .enumerate()
+            .scan(false, move |finished, (index, body)| {
+                let part = if *finished {
+                    None
+                } else if index >= MAX_PARTS as usize {
+                    Some(Err(UploadError::TooManyParts))
+                } else {
+                    let part_number = index as u32 + 1;
+                    match body {
+                        Err(e) => Some(Err(e.into())),
+                        Ok(body) => {
+                            *finished = (body.len() as u64) < part_size;
+                            // An empty input still needs one part
+                            (part_number == 1 || !body.is_empty())
+                                .then_some(Ok((part_number, body)))
+                        }
+                    }
+                };
+                future::ready(part)
+            })
+            .filter(|part| {
+                let skip = matches!(part, Ok((number, _)) if uploaded.contains(number));
+                future::ready(!skip)
+            });
+
+        let upload_id = manifest.upload_id.clone();
+        parts
+            .map(|part| {
+                let upload_id = &upload_id;
+                async move {
+                    let (part_number, body) = part?;
+                    let size = body.len() as u64;
+                    let part = self.upload_part(upload_id, part_number, body).await?;
+                    Ok::<_, UploadError>(ManifestPart {
+                        part_number,
+                        etag: part.etag,
+                        size,
+                    })
+                }
+            })
+            .buffer_unordered(self.concurrency)
+            .try_for_each(|part| {
+                progress.uploaded_bytes += part.size;
+                progress.uploaded_parts += 1;
+                manifest.parts.push(part);
+                let saved = self.save(&manifest);
+                if let Some(callback) = &self.on_progress {
+                    callback(progress);
+                }
+                future::ready(saved)
+            })
+            .await?;
+
+        manifest.parts.sort_by_key(|part| part.part_number);
+        let parts: Vec<_> = manifest
+            .parts
+            .iter()
+            .map(|part| CompletedPart {
+                part_number: part.part_number,
+                etag: part.etag.clone(),
+            })
+            .collect();
+        let output = self
+            .bucket
+            .complete_multipart_upload(&self.key, &upload_id, &parts)
+            .await?;
+        if let Some(path) = &self.manifest {
+            std::fs::remove_file(path)?;
+        }
+        Ok(output)
+    }
+
+    /// Continues the upload in the manifest if it is still open, or starts a new one
+    async fn start(&self) -> Result<UploadManifest, UploadError> {
+        if let Some(manifest) = self.load()? {
+            match self.uploaded_parts(&manifest).await {
+                Ok(parts) => return Ok(UploadManifest { parts, ..manifest }),
+                // The upload was completed or aborted
+                Err(e) if e.is_not_found() => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+        let upload_id = self
+            .bucket
+            .create_multipart_upload(&self.key, &self.options)
+            .await?;
+        let manifest = UploadManifest {
+            bucket: self.bucket.name().to_string(),
+            key: self.key.clone(),
+            upload_id,
+            part_size: self.part_size,
+            parts: Vec::new(),
+        };
+        self.save(&manifest)?;
+        Ok(manifest)
+    }
+
+    /// The manifest of an unfinished upload of the same object, if there is one
+    fn load(&self) -> Result<Option<UploadManifest>, UploadError> {
+        let Some(path) = &self.manifest else {
+            return Ok(None);
+        };
+        let contents = match std::fs::read(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let manifest: UploadManifest = serde_json::from_slice(&contents)?;
+        let same_object = manifest.bucket == self.bucket.name() && manifest.key == self.key;
+        Ok(same_object.then_some(manifest))
+    }
+
+    /// Writes the manifest to a temporary file first, so an interrupted write keeps the
+    /// previous one
+    fn save(&self, manifest: &UploadManifest) -> Result<(), UploadError> {
+        let Some(path) = &self.manifest else {
+            return Ok(());
+        };
+        let mut temporary = path.clone().into_os_string();
+        temporary.push(".tmp");
+        std::fs::write(&temporary, serde_json::to_vec_pretty(manifest)?)?;
+        std::fs::rename(&temporary, path)?;
+        Ok(())
+    }
+
+    /// The parts R2 has for an upload. Only these are trusted when resuming.
+    async fn uploaded_parts(
+        &self,
+        manifest: &UploadManifest,
+    ) -> Result<Vec<ManifestPart>, R2Error> {
+        let mut parts = Vec::new();
+        let mut marker = None;
+        loop {
+            let page = self
+                .bucket
+                .list_parts(&self.key, &manifest.upload_id, marker)
+                .await?;
+            parts.extend(page.parts.into_iter().map(|part| ManifestPart {
+                part_number: part.part_number,
+                etag: part.etag,
+                size: part.size,
+            }));
+            match page.next_part_number_marker.filter(|_| page.is_truncated) {
+                Some(next) => marker = Some(next),
+                None => return Ok(parts),
+            }
+        }
+    }
+
+    async fn upload_part(
+        &self,
+        upload_id: &str,
+        part_number: u32,
+        body: Vec<u8>,
+    ) -> Result<CompletedPart, R2Error> {
+        let mut attempt = 0;
+        loop {
+            match self
+                .bucket
+                .upload_part(&self.key, upload_id, part_number, body.clone())
+                .await
+            {
+                Err(e) if e.is_retryable() && attempt < self.retries => attempt += 1,
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Reads the next part of a file on its own thread, like `spawn_blocking` but without tying the
+/// uploader to a runtime
+async fn read_file_part(mut file: File, part_size: u64) -> std::io::Result<(File, Vec<u8>)> {
+    let (sender, receiver) = oneshot::channel();
+    std::thread::spawn(move || {
+        let mut body = Vec::new();
+        let read = (&mut file).take(part_size).read_to_end(&mut body);
+        // The receiver is gone only if the upload was dropped
+        let _ = sender.send(read.map(|_| (file, body)));
+    });
+    receiver
+        .await
+        .map_err(|_| std::io::Error::other("the thread reading the file panicked"))?
+}
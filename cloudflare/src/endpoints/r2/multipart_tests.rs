@@ -0,0 +1,243 @@
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::sync::Mutex;
+
+    use mockito::{Matcher, Server};
+    use url::Url;
+
+    use super::super::multipart::{
+        CompletedPart, ManifestPart, MultipartUploader, UploadError, UploadManifest,
+        UploadProgress, MIN_PART_SIZE,
+    };
+    use super::super::s3::{R2Client, R2Credentials, R2Error};
+
+    fn client(server: &Server) -> R2Client {
+        R2Client::with_endpoint(
+            Url::parse(&server.url()).unwrap(),
+            R2Credentials::new("access-key", "secret-key"),
+        )
+    }
+
+    fn manifest_path(name: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("cloudflare-rs-{}-{name}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    fn part_query(part_number: u32, upload_id: &str) -> Matcher {
+        Matcher::AllOf(vec![
+            Matcher::UrlEncoded("partNumber".into(), part_number.to_string()),
+            Matcher::UrlEncoded("uploadId".into(), upload_id.into()),
+        ])
+    }
+
+    const COMPLETED: &str = r#"<CompleteMultipartUploadResult>
+  <Bucket>artifacts</Bucket>
+  <Key>build.tar</Key>
+  <ETag>"final-3"</ETag>
+</CompleteMultipartUploadResult>"#;
+
+    #[tokio::test]
+    async fn test_complete_multipart_upload_error_with_200() {
+        let mut server = Server::new_async().await;
+        let complete = server
+            .mock("POST", "/artifacts/build.tar")
+            .match_query(Matcher::UrlEncoded("uploadId".into(), "u1".into()))
+            .match_body(
+                "<CompleteMultipartUpload>\
+                 <Part><PartNumber>1</PartNumber><ETag>\"a\"</ETag></Part>\
+                 </CompleteMultipartUpload>",
+            )
+            .with_body("<Error><Code>InvalidPart</Code><Message>Part is missing</Message></Error>")
+            .create();
+
+        let client = client(&server);
+        let parts = [CompletedPart {
+            part_number: 1,
+            etag: "\"a\"".to_string(),
+        }];
+        let error = client
+            .bucket("artifacts")
+            .complete_multipart_upload("build.tar", "u1", &parts)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, R2Error::Api { ref code, .. } if code == "InvalidPart"));
+        complete.assert();
+    }
+
+    #[tokio::test]
+    async fn test_uploader_retries_failed_part() {
+        let mut server = Server::new_async().await;
+        let create = server
+            .mock("POST", "/artifacts/build.tar")
+            .match_query(Matcher::Exact("uploads".into()))
+            .match_header("content-type", "application/x-tar")
+            .with_body(
+                "<InitiateMultipartUploadResult><Bucket>artifacts</Bucket>\
+                 <Key>build.tar</Key><UploadId>u1</UploadId></InitiateMultipartUploadResult>",
+            )
+            .create();
+        let failed = server
+            .mock("PUT", "/artifacts/build.tar")
+            .match_query(part_query(1, "u1"))
+            .with_status(503)
+            .create();
+        let part = server
+            .mock("PUT", "/artifacts/build.tar")
+            .match_query(part_query(1, "u1"))
+            .match_body("small artifact")
+            .with_header("etag", "\"p1\"")
+            .create();
+        let complete = server
+            .mock("POST", "/artifacts/build.tar")
+            .match_query(Matcher::UrlEncoded("uploadId".into(), "u1".into()))
+            .match_body(Matcher::Regex(r"<PartNumber>1</PartNumber>".to_string()))
+            .with_body(COMPLETED)
+            .create();
+
+        let client = client(&server);
+        let output = MultipartUploader::new(client.bucket("artifacts"), "build.tar")
+            .options(super::super::s3::PutObjectOptions {
+                content_type: Some("application/x-tar".to_string()),
+                ..Default::default()
+            })
+            .upload(&b"small artifact"[..])
+            .await
+            .unwrap();
+
+        assert_eq!(output.etag, "\"final-3\"");
+        create.assert();
+        failed.assert();
+        part.assert();
+        complete.assert();
+    }
+
+    #[tokio::test]
+    async fn test_uploader_resumes_from_manifest() {
+        let mut server = Server::new_async().await;
+        let part_size = MIN_PART_SIZE as usize;
+        let data: Vec<u8> = (0..part_size * 2 + 10).map(|i| (i % 251) as u8).collect();
+        let manifest = manifest_path("resume");
+        let previous = UploadManifest {
+            bucket: "artifacts".to_string(),
+            key: "build.tar".to_string(),
+            upload_id: "u2".to_string(),
+            part_size: MIN_PART_SIZE,
+            parts: vec![],
+        };
+        std::fs::write(&manifest, serde_json::to_vec(&previous).unwrap()).unwrap();
+
+        // R2 already has the first part
+        let list = server
+            .mock("GET", "/artifacts/build.tar")
+            .match_query(Matcher::UrlEncoded("uploadId".into(), "u2".into()))
+            .with_body(format!(
+                "<ListPartsResult><IsTruncated>false</IsTruncated><Part>\
+                 <PartNumber>1</PartNumber><ETag>\"p1\"</ETag><Size>{part_size}</Size>\
+                 </Part></ListPartsResult>"
+            ))
+            .create();
+        let second = server
+            .mock("PUT", "/artifacts/build.tar")
+            .match_query(part_query(2, "u2"))
+            .match_body(data[part_size..part_size * 2].to_vec())
+            .with_header("etag", "\"p2\"")
+            .create();
+        let third = server
+            .mock("PUT", "/artifacts/build.tar")
+            .match_query(part_query(3, "u2"))
+            .match_body(data[part_size * 2..].to_vec())
+            .with_header("etag", "\"p3\"")
+            .create();
+        let complete = server
+            .mock("POST", "/artifacts/build.tar")
+            .match_query(Matcher::UrlEncoded("uploadId".into(), "u2".into()))
+            .match_body(Matcher::Regex(
+                "<Part><PartNumber>1</PartNumber><ETag>\"p1\"</ETag></Part>\
+                 <Part><PartNumber>2</PartNumber><ETag>\"p2\"</ETag></Part>\
+                 <Part><PartNumber>3</PartNumber><ETag>\"p3\"</ETag></Part>"
+                    .to_string(),
+            ))
+            .with_body(COMPLETED)
+            .create();
+
+        let client = client(&server);
+        let progress = Mutex::new(Vec::new());
+        let saved_parts = Mutex::new(Vec::new());
+        MultipartUploader::new(client.bucket("artifacts"), "build.tar")
+            .manifest(&manifest)
+            .concurrency(1)
+            .on_progress(|update: UploadProgress| {
+                progress.lock().unwrap().push(update);
+                let saved: UploadManifest =
+                    serde_json::from_slice(&std::fs::read(&manifest).unwrap()).unwrap();
+                saved_parts.lock().unwrap().push(saved.parts);
+            })
+            .upload(&data[..])
+            .await
+            .unwrap();
+
+        let progress = progress.into_inner().unwrap();
+        assert_eq!(progress.len(), 2);
+        assert_eq!(progress[1].uploaded_bytes, data.len() as u64);
+        assert_eq!(progress[1].uploaded_parts, 3);
+        // The manifest is saved before progress is reported
+        assert_eq!(
+            saved_parts.into_inner().unwrap()[0][1],
+            ManifestPart {
+                part_number: 2,
+                etag: "\"p2\"".to_string(),
+                size: MIN_PART_SIZE,
+            }
+        );
+        assert!(!manifest.exists());
+        list.assert();
+        second.assert();
+        third.assert();
+        complete.assert();
+    }
+
+    #[tokio::test]
+    async fn test_uploader_aborts_failed_upload_without_manifest() {
+        let mut server = Server::new_async().await;
+        let create = server
+            .mock("POST", "/artifacts/build.tar")
+            .match_query(Matcher::Exact("uploads".into()))
+            .with_body(
+                "<InitiateMultipartUploadResult><Bucket>artifacts</Bucket>\
+                 <Key>build.tar</Key><UploadId>u3</UploadId></InitiateMultipartUploadResult>",
+            )
+            .create();
+        let part = server
+            .mock("PUT", "/artifacts/build.tar")
+            .match_query(part_query(1, "u3"))
+            .match_body("file artifact")
+            .with_status(400)
+            .with_body("<Error><Code>InvalidArgument</Code><Message>bad part</Message></Error>")
+            .create();
+        let abort = server
+            .mock("DELETE", "/artifacts/build.tar")
+            .match_query(Matcher::UrlEncoded("uploadId".into(), "u3".into()))
+            .with_status(204)
+            .create();
+
+        let path = manifest_path("abort-source");
+        std::fs::write(&path, "file artifact").unwrap();
+        let client = client(&server);
+        let error = MultipartUploader::new(client.bucket("artifacts"), "build.tar")
+            .upload_file(&path)
+            .await
+            .unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(
+            error,
+            UploadError::R2(R2Error::Api { ref code, .. }) if code == "InvalidArgument"
+        ));
+        create.assert();
+        part.assert();
+        abort.assert();
+    }
+}
@@ -0,0 +1,143 @@
+/*!
+Presigned URLs for R2 objects.
+
+A presigned URL lets anyone holding it read or write one object until it expires, without R2
+credentials of their own. URLs are signed locally, so creating one sends no request.
+
+```no_run
+# fn example(bucket: cloudflare::endpoints::r2::s3::R2Bucket<'_>) -> Result<(), cloudflare::endpoints::r2::presign::PresignError> {
+use std::time::Duration;
+use cloudflare::endpoints::r2::presign::{PresignMethod, PresignOptions};
+
+let download = bucket.presign_url(
+    PresignMethod::Get,
+    "reports/2024.pdf",
+    &PresignOptions::new(Duration::from_secs(15 * 60)),
+)?;
+# Ok(())
+# }
+```
+ */
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use url::Url;
+
+use super::s3::{self, R2Bucket};
+use super::sigv4::{Signer, R2_REGION};
+
+/// Longest validity of a presigned URL
+pub const MAX_PRESIGN_EXPIRY: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+/// Errors encountered while presigning a URL
+pub enum PresignError {
+    #[error("Presigned URLs must expire after 1 second to 7 days, not {0:?}")]
+    InvalidExpiry(Duration),
+}
+
+/// Operations a presigned URL can allow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PresignMethod {
+    Get,
+    Put,
+    Head,
+    Delete,
+}
+
+impl PresignMethod {
+    fn as_str(&self) -> &'static str {
+        match self {
+            PresignMethod::Get => "GET",
+            PresignMethod::Put => "PUT",
+            PresignMethod::Head => "HEAD",
+            PresignMethod::Delete => "DELETE",
+        }
+    }
+}
+
+/// How long a presigned URL is valid, and what it constrains
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PresignOptions {
+    pub expires_in: Duration,
+    /// For uploads, the `Content-Type` the upload must send. For downloads, the
+    /// `Content-Type` of the response.
+    pub content_type: Option<String>,
+    /// For uploads, the `Content-Disposition` the upload must send. For downloads, the
+    /// `Content-Disposition` of the response, e.g. `attachment; filename="report.pdf"`.
+    pub content_disposition: Option<String>,
+}
+
+impl PresignOptions {
+    pub fn new(expires_in: Duration) -> Self {
+        PresignOptions {
+            expires_in,
+            content_type: None,
+            content_disposition: None,
+        }
+    }
+}
+
+impl R2Bucket<'_> {
+    /// Returns a URL allowing `method` on the object at `key`, valid from now
+    pub fn presign_url(
+        &self,
+        method: PresignMethod,
+        key: &str,
+        options: &PresignOptions,
+    ) -> Result<Url, PresignError> {
+        self.presign_url_at(method, key, options, Utc::now())
+    }
+
+    /// Returns a URL allowing `method` on the object at `key`, valid from `time`
+    pub fn presign_url_at(
+        &self,
+        method: PresignMethod,
+        key: &str,
+        options: &PresignOptions,
+        time: DateTime<Utc>,
+    ) -> Result<Url, PresignError> {
+        if options.expires_in.as_secs() == 0 || options.expires_in > MAX_PRESIGN_EXPIRY {
+            return Err(PresignError::InvalidExpiry(options.expires_in));
+        }
+
+        let constraints = [
+            ("content-type", &options.content_type),
+            ("content-disposition", &options.content_disposition),
+        ];
+        let constraints = constraints
+            .into_iter()
+            .filter_map(|(name, value)| Some((name, value.as_deref()?)));
+        let mut headers = BTreeMap::new();
+        let mut query = Vec::new();
+        for (name, value) in constraints {
+            match method {
+                // Uploads must send the signed headers
+                PresignMethod::Put => {
+                    headers.insert(name.to_string(), value.to_string());
+                }
+                // Downloads override the response headers
+                _ => query.push((format!("response-{name}"), value)),
+            }
+        }
+        let query: Vec<_> = query
+            .iter()
+            .map(|(name, value)| (name.as_str(), *value))
+            .collect();
+        let url = self.client.url(self.name, Some(key), &query);
+        headers.insert("host".to_string(), s3::host(&url));
+
+        let signer = Signer {
+            credentials: &self.client.credentials,
+            region: R2_REGION,
+        };
+        Ok(signer.presign(
+            method.as_str(),
+            &url,
+            &headers,
+            options.expires_in.as_secs(),
+            time,
+        ))
+    }
+}
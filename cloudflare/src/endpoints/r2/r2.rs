@@ -50,7 +50,7 @@ pub struct ListBucketsResult {
     pub buckets: Vec<Bucket>,
 }
 
-type EmptyMap = HashMap<(), ()>;
+pub(super) type EmptyMap = HashMap<(), ()>;
 impl ApiResult for EmptyMap {}
 impl ApiResult for ListBucketsResult {}
 impl ApiResult for Bucket {}
@@ -0,0 +1,514 @@
+/*!
+Objects in R2 buckets, through the S3-compatible API.
+
+The Cloudflare API only manages buckets. Objects are read and written through
+`https://<account>.r2.cloudflarestorage.com`, authenticated with R2 access keys and AWS
+Signature Version 4.
+
+```no_run
+# async fn example() -> Result<(), cloudflare::endpoints::r2::s3::R2Error> {
+use cloudflare::endpoints::r2::s3::{R2Client, R2Credentials};
+
+let client = R2Client::new("account-id", R2Credentials::new("access-key-id", "secret"));
+let bucket = client.bucket("assets");
+bucket.put_object("hello.txt", b"Hello".to_vec(), Default::default()).await?;
+let object = bucket.get_object("hello.txt").await?;
+# Ok(())
+# }
+```
+ */
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Utc};
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode};
+use serde::Deserialize;
+use url::Url;
+
+use super::sigv4::{self, Signer, R2_REGION};
+use super::R2Jurisdiction;
+
+pub use super::sigv4::R2Credentials;
+
+/// Prefix of user metadata headers
+const METADATA_PREFIX: &str = "x-amz-meta-";
+
+#[derive(thiserror::Error, Debug)]
+/// Errors encountered by an [`R2Client`]
+pub enum R2Error {
+    #[error("HTTP request failed: {0}")]
+    Http(#[from] reqwest::Error),
+    #[error("R2 request failed with {status}: {code}: {message}")]
+    Api {
+        status: StatusCode,
+        /// S3 error code, e.g. `NoSuchKey`
+        code: String,
+        message: String,
+    },
+    #[error("Invalid XML in R2 response: {0}")]
+    Xml(#[from] quick_xml::DeError),
+}
+
+impl R2Error {
+    /// Whether the bucket or object does not exist
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, R2Error::Api { status, .. } if *status == StatusCode::NOT_FOUND)
+    }
+
+    /// Whether the request may succeed if sent again: network errors, throttling and server
+    /// errors
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            R2Error::Http(_) => true,
+            R2Error::Api { status, .. } => {
+                status.is_server_error() || *status == StatusCode::TOO_MANY_REQUESTS
+            }
+            R2Error::Xml(_) => false,
+        }
+    }
+}
+
+/// Client for the S3-compatible R2 API of one account
+#[derive(Debug, Clone)]
+pub struct R2Client {
+    http_client: reqwest::Client,
+    endpoint: Url,
+    pub(super) credentials: R2Credentials,
+}
+
+impl R2Client {
+    /// Creates a client for the account's default jurisdiction
+    pub fn new(account_id: &str, credentials: R2Credentials) -> Self {
+        Self::with_jurisdiction(account_id, &R2Jurisdiction::Default, credentials)
+    }
+
+    /// Creates a client for buckets in the given jurisdiction
+    pub fn with_jurisdiction(
+        account_id: &str,
+        jurisdiction: &R2Jurisdiction,
+        credentials: R2Credentials,
+    ) -> Self {
+        let host = match jurisdiction {
+            R2Jurisdiction::Default => format!("{account_id}.r2.cloudflarestorage.com"),
+            R2Jurisdiction::Eu => format!("{account_id}.eu.r2.cloudflarestorage.com"),
+            R2Jurisdiction::Fedramp => format!("{account_id}.fedramp.r2.cloudflarestorage.com"),
+        };
+        let endpoint = Url::parse(&format!("https://{host}")).unwrap();
+        Self::with_endpoint(endpoint, credentials)
+    }
+
+    /// Creates a client for any S3-compatible endpoint, e.g. a local stand-in for tests
+    pub fn with_endpoint(endpoint: Url, credentials: R2Credentials) -> Self {
+        R2Client {
+            http_client: reqwest::Client::new(),
+            endpoint,
+            credentials,
+        }
+    }
+
+    /// The S3 endpoint requests are sent to
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    /// Objects of one bucket
+    pub fn bucket<'a>(&'a self, name: &'a str) -> R2Bucket<'a> {
+        R2Bucket { client: self, name }
+    }
+
+    /// Path-style URL of a bucket or object, with query parameters
+    pub(crate) fn url(&self, bucket: &str, key: Option<&str>, query: &[(&str, &str)]) -> Url {
+        let mut url = self.endpoint.clone();
+        let path = match key {
+            Some(key) => format!("/{bucket}/{}", sigv4::uri_encode(key, false)),
+            None => format!("/{bucket}"),
+        };
+        url.set_path(&path);
+        if !query.is_empty() {
+            let query: Vec<_> = query
+                .iter()
+                .map(|(name, value)| {
+                    let value = sigv4::uri_encode(value, true);
+                    // `?uploads` style parameters have no value
+                    if value.is_empty() {
+                        name.to_string()
+                    } else {
+                        format!("{name}={value}")
+                    }
+                })
+                .collect();
+            url.set_query(Some(&query.join("&")));
+        }
+        url
+    }
+
+    /// Signs and sends a request, failing on any non-2XX status
+    pub(crate) async fn send(
+        &self,
+        method: Method,
+        url: Url,
+        headers: Vec<(String, String)>,
+        body: Vec<u8>,
+    ) -> Result<reqwest::Response, R2Error> {
+        let now = Utc::now();
+        let payload_hash = sigv4::payload_hash(&body);
+        let mut signed: BTreeMap<String, String> = headers
+            .into_iter()
+            .map(|(name, value)| (name.to_lowercase(), value))
+            .collect();
+        signed.insert("host".to_string(), host(&url));
+        signed.insert("x-amz-date".to_string(), sigv4::amz_date(now));
+        signed.insert("x-amz-content-sha256".to_string(), payload_hash.clone());
+        if let Some(token) = &self.credentials.session_token {
+            signed.insert("x-amz-security-token".to_string(), token.clone());
+        }
+        let signer = Signer {
+            credentials: &self.credentials,
+            region: R2_REGION,
+        };
+        let authorization =
+            signer.authorization(method.as_str(), &url, &signed, &payload_hash, now);
+
+        let mut request = self.http_client.request(method, url);
+        for (name, value) in signed {
+            // reqwest sets the host header itself
+            if name != "host" {
+                request = request.header(name, value);
+            }
+        }
+        let response = request
+            .header(reqwest::header::AUTHORIZATION, authorization)
+            .body(body)
+            .send()
+            .await?;
+        if response.status().is_success() {
+            Ok(response)
+        } else {
+            Err(api_error(response).await)
+        }
+    }
+}
+
+/// Objects of one R2 bucket
+#[derive(Debug, Clone, Copy)]
+pub struct R2Bucket<'a> {
+    pub(super) client: &'a R2Client,
+    pub(super) name: &'a str,
+}
+
+impl<'a> R2Bucket<'a> {
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Uploads an object, replacing any object with the same key
+    pub async fn put_object(
+        &self,
+        key: &str,
+        body: Vec<u8>,
+        options: PutObjectOptions,
+    ) -> Result<PutObjectOutput, R2Error> {
+        let url = self.client.url(self.name, Some(key), &[]);
+        let response = self
+            .client
+            .send(Method::PUT, url, options.headers(), body)
+            .await?;
+        Ok(PutObjectOutput {
+            etag: header(response.headers(), "etag").unwrap_or_default(),
+        })
+    }
+
+    /// Downloads an object, or `None` if it does not exist
+    pub async fn get_object(&self, key: &str) -> Result<Option<R2Object>, R2Error> {
+        self.get(key, Vec::new()).await
+    }
+
+    /// Downloads part of an object, or `None` if it does not exist
+    pub async fn get_object_range(
+        &self,
+        key: &str,
+        range: ByteRange,
+    ) -> Result<Option<R2Object>, R2Error> {
+        let headers = vec![("range".to_string(), range.to_string())];
+        self.get(key, headers).await
+    }
+
+    async fn get(
+        &self,
+        key: &str,
+        headers: Vec<(String, String)>,
+    ) -> Result<Option<R2Object>, R2Error> {
+        let url = self.client.url(self.name, Some(key), &[]);
+        let response = match self
+            .client
+            .send(Method::GET, url, headers, Vec::new())
+            .await
+        {
+            Ok(response) => response,
+            Err(e) if e.is_not_found() => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        let metadata = ObjectMetadata::from_headers(response.headers());
+        let body = response.bytes().await?.to_vec();
+        Ok(Some(R2Object { body, metadata }))
+    }
+
+    /// Reads an object's metadata without its body, or `None` if it does not exist
+    pub async fn head_object(&self, key: &str) -> Result<Option<ObjectMetadata>, R2Error> {
+        let url = self.client.url(self.name, Some(key), &[]);
+        match self
+            .client
+            .send(Method::HEAD, url, Vec::new(), Vec::new())
+            .await
+        {
+            Ok(response) => Ok(Some(ObjectMetadata::from_headers(response.headers()))),
+            Err(e) if e.is_not_found() => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Deletes an object. Deleting a missing object succeeds.
+    pub async fn delete_object(&self, key: &str) -> Result<(), R2Error> {
+        let url = self.client.url(self.name, Some(key), &[]);
+        self.client
+            .send(Method::DELETE, url, Vec::new(), Vec::new())
+            .await?;
+        Ok(())
+    }
+
+    /// Lists one page of objects. Pass `next_continuation_token` of the output as
+    /// `continuation_token` to get the next page.
+    pub async fn list_objects_v2(
+        &self,
+        params: &ListObjectsParams,
+    ) -> Result<ListObjectsOutput, R2Error> {
+        let max_keys = params.max_keys.map(|max_keys| max_keys.to_string());
+        let mut query = vec![("list-type", "2")];
+        let optional = [
+            ("continuation-token", params.continuation_token.as_deref()),
+            ("delimiter", params.delimiter.as_deref()),
+            ("max-keys", max_keys.as_deref()),
+            ("prefix", params.prefix.as_deref()),
+            ("start-after", params.start_after.as_deref()),
+        ];
+        query.extend(
+            optional
+                .into_iter()
+                .filter_map(|(name, value)| Some((name, value?))),
+        );
+        let url = self.client.url(self.name, None, &query);
+        let response = self
+            .client
+            .send(Method::GET, url, Vec::new(), Vec::new())
+            .await?;
+        Ok(quick_xml::de::from_str(&response.text().await?)?)
+    }
+
+    /// Copies an object, from this or another bucket, to `key` in this bucket
+    pub async fn copy_object(
+        &self,
+        source_bucket: &str,
+        source_key: &str,
+        key: &str,
+    ) -> Result<CopyObjectOutput, R2Error> {
+        let source = format!("/{source_bucket}/{}", sigv4::uri_encode(source_key, false));
+        let url = self.client.url(self.name, Some(key), &[]);
+        let headers = vec![("x-amz-copy-source".to_string(), source)];
+        let response = self
+            .client
+            .send(Method::PUT, url, headers, Vec::new())
+            .await?;
+        Ok(quick_xml::de::from_str(&response.text().await?)?)
+    }
+}
+
+/// Optional headers of an upload
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PutObjectOptions {
+    pub content_type: Option<String>,
+    pub cache_control: Option<String>,
+    pub content_disposition: Option<String>,
+    /// User metadata, stored as `x-amz-meta-*` headers
+    pub metadata: HashMap<String, String>,
+}
+
+impl PutObjectOptions {
+    pub(crate) fn headers(&self) -> Vec<(String, String)> {
+        let standard = [
+            ("content-type", &self.content_type),
+            ("cache-control", &self.cache_control),
+            ("content-disposition", &self.content_disposition),
+        ];
+        let standard = standard
+            .into_iter()
+            .filter_map(|(name, value)| Some((name.to_string(), value.clone()?)));
+        let metadata = self
+            .metadata
+            .iter()
+            .map(|(name, value)| (format!("{METADATA_PREFIX}{name}"), value.clone()));
+        standard.chain(metadata).collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PutObjectOutput {
+    /// Entity tag of the uploaded object, quoted
+    pub etag: String,
+}
+
+/// A downloaded object
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct R2Object {
+    pub body: Vec<u8>,
+    pub metadata: ObjectMetadata,
+}
+
+/// Properties of an object, read from response headers
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ObjectMetadata {
+    /// Bytes in the response, which is less than the object size for ranged reads
+    pub content_length: Option<u64>,
+    pub content_type: Option<String>,
+    /// Returned by ranged reads, e.g. `bytes 0-9/443`
+    pub content_range: Option<String>,
+    pub cache_control: Option<String>,
+    /// Entity tag, quoted
+    pub etag: Option<String>,
+    pub last_modified: Option<DateTime<Utc>>,
+    /// User metadata, without the `x-amz-meta-` prefix
+    pub metadata: HashMap<String, String>,
+}
+
+impl ObjectMetadata {
+    fn from_headers(headers: &HeaderMap) -> Self {
+        let metadata = headers
+            .iter()
+            .filter_map(|(name, value)| {
+                let name = name.as_str().strip_prefix(METADATA_PREFIX)?;
+                Some((name.to_string(), value.to_str().ok()?.to_string()))
+            })
+            .collect();
+        ObjectMetadata {
+            content_length: header(headers, "content-length").and_then(|v| v.parse().ok()),
+            content_type: header(headers, "content-type"),
+            content_range: header(headers, "content-range"),
+            cache_control: header(headers, "cache-control"),
+            etag: header(headers, "etag"),
+            last_modified: header(headers, "last-modified")
+                .and_then(|v| DateTime::parse_from_rfc2822(&v).ok())
+                .map(|time| time.with_timezone(&Utc)),
+            metadata,
+        }
+    }
+}
+
+/// Bytes of an object to read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteRange {
+    /// From an offset to the end
+    From(u64),
+    /// From the first to the last offset, both included
+    Between(u64, u64),
+    /// The last bytes
+    Suffix(u64),
+}
+
+impl std::fmt::Display for ByteRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ByteRange::From(start) => write!(f, "bytes={start}-"),
+            ByteRange::Between(start, end) => write!(f, "bytes={start}-{end}"),
+            ByteRange::Suffix(length) => write!(f, "bytes=-{length}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListObjectsParams {
+    /// Only list keys starting with this prefix
+    pub prefix: Option<String>,
+    /// Group keys sharing a prefix up to this delimiter into `common_prefixes`
+    pub delimiter: Option<String>,
+    /// Token of the page to list, from a previous page
+    pub continuation_token: Option<String>,
+    /// Only list keys after this one
+    pub start_after: Option<String>,
+    /// Most keys to return, at most 1000
+    pub max_keys: Option<u32>,
+}
+
+/// One page of a bucket listing
+#[derive(Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ListObjectsOutput {
+    pub contents: Vec<ObjectSummary>,
+    pub common_prefixes: Vec<CommonPrefix>,
+    pub is_truncated: bool,
+    pub key_count: Option<u32>,
+    /// Token of the next page, if the listing is truncated
+    pub next_continuation_token: Option<String>,
+}
+
+/// An object in a bucket listing
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ObjectSummary {
+    pub key: String,
+    pub size: u64,
+    #[serde(rename = "ETag")]
+    pub etag: Option<String>,
+    pub last_modified: Option<DateTime<Utc>>,
+    pub storage_class: Option<String>,
+}
+
+/// Keys grouped by a delimiter in a bucket listing
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CommonPrefix {
+    pub prefix: String,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CopyObjectOutput {
+    #[serde(rename = "ETag")]
+    pub etag: String,
+    pub last_modified: Option<DateTime<Utc>>,
+}
+
+/// S3 error response body
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "PascalCase", default)]
+struct ErrorResponse {
+    code: String,
+    message: String,
+}
+
+async fn api_error(response: reqwest::Response) -> R2Error {
+    let status = response.status();
+    // HEAD responses and some gateway errors have no body
+    let body = response.text().await.unwrap_or_default();
+    xml_error(status, &body)
+}
+
+/// An error from an S3 `<Error>` body, which some operations return with a 200 status
+pub(super) fn xml_error(status: StatusCode, body: &str) -> R2Error {
+    let error = quick_xml::de::from_str::<ErrorResponse>(body).unwrap_or_default();
+    R2Error::Api {
+        status,
+        code: error.code,
+        message: error.message,
+    }
+}
+
+pub(super) fn header(headers: &HeaderMap, name: &str) -> Option<String> {
+    Some(headers.get(name)?.to_str().ok()?.to_string())
+}
+
+/// `host[:port]`, as sent in the host header
+pub(super) fn host(url: &Url) -> String {
+    match url.port() {
+        Some(port) => format!("{}:{port}", url.host_str().unwrap_or_default()),
+        None => url.host_str().unwrap_or_default().to_string(),
+    }
+}
@@ -0,0 +1,217 @@
+//! AWS Signature Version 4, as used by the S3-compatible R2 API.
+//! <https://docs.aws.amazon.com/AmazonS3/latest/API/sig-v4-authenticating-requests.html>
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+use url::Url;
+
+/// Region R2 expects in signatures
+pub(crate) const R2_REGION: &str = "auto";
+/// `x-amz-content-sha256` value for a payload that is not signed
+pub(crate) const UNSIGNED_PAYLOAD: &str = "UNSIGNED-PAYLOAD";
+const ALGORITHM: &str = "AWS4-HMAC-SHA256";
+const SERVICE: &str = "s3";
+
+/// R2 API token credentials, as S3 access keys. Temporary credentials also have a session token.
+#[derive(Clone, PartialEq, Eq)]
+pub struct R2Credentials {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    pub session_token: Option<String>,
+}
+
+impl R2Credentials {
+    pub fn new(access_key_id: impl Into<String>, secret_access_key: impl Into<String>) -> Self {
+        R2Credentials {
+            access_key_id: access_key_id.into(),
+            secret_access_key: secret_access_key.into(),
+            session_token: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for R2Credentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print the secrets
+        f.debug_struct("R2Credentials")
+            .field("access_key_id", &self.access_key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Signs requests for one region with one set of credentials
+pub(crate) struct Signer<'a> {
+    pub credentials: &'a R2Credentials,
+    pub region: &'a str,
+}
+
+impl Signer<'_> {
+    /// Returns the `Authorization` header for a request. `headers` must hold every header to
+    /// sign, with lowercase names, including `host`, `x-amz-date` and `x-amz-content-sha256`.
+    pub fn authorization(
+        &self,
+        method: &str,
+        url: &Url,
+        headers: &BTreeMap<String, String>,
+        payload_hash: &str,
+        time: DateTime<Utc>,
+    ) -> String {
+        let signed_headers = headers
+            .keys()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(";");
+        let signature = self.signature(method, url, headers, payload_hash, time);
+        format!(
+            "{ALGORITHM} Credential={}/{}, SignedHeaders={signed_headers}, Signature={signature}",
+            self.credentials.access_key_id,
+            self.scope(time)
+        )
+    }
+
+    /// Returns the hex signature of a request
+    pub fn signature(
+        &self,
+        method: &str,
+        url: &Url,
+        headers: &BTreeMap<String, String>,
+        payload_hash: &str,
+        time: DateTime<Utc>,
+    ) -> String {
+        let canonical_headers: String = headers
+            .iter()
+            .map(|(name, value)| format!("{name}:{}\n", value.trim()))
+            .collect();
+        let signed_headers = headers
+            .keys()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(";");
+        let canonical_request = format!(
+            "{method}\n{}\n{}\n{canonical_headers}\n{signed_headers}\n{payload_hash}",
+            url.path(),
+            canonical_query(url)
+        );
+        let string_to_sign = format!(
+            "{ALGORITHM}\n{}\n{}\n{}",
+            amz_date(time),
+            self.scope(time),
+            hex::encode(Sha256::digest(canonical_request))
+        );
+
+        let date = time.format("%Y%m%d").to_string();
+        let key = hmac(
+            format!("AWS4{}", self.credentials.secret_access_key).as_bytes(),
+            &date,
+        );
+        let key = hmac(&key, self.region);
+        let key = hmac(&key, SERVICE);
+        let key = hmac(&key, "aws4_request");
+        hex::encode(hmac(&key, &string_to_sign))
+    }
+
+    /// Returns `url` with the signature in its query, valid for `expires_in` seconds from
+    /// `time`. Requests to it must send `headers` as they are, which must include `host`.
+    pub fn presign(
+        &self,
+        method: &str,
+        url: &Url,
+        headers: &BTreeMap<String, String>,
+        expires_in: u64,
+        time: DateTime<Utc>,
+    ) -> Url {
+        let signed_headers = headers
+            .keys()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(";");
+        let mut query: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+        query.extend([
+            ("X-Amz-Algorithm".to_string(), ALGORITHM.to_string()),
+            (
+                "X-Amz-Credential".to_string(),
+                format!("{}/{}", self.credentials.access_key_id, self.scope(time)),
+            ),
+            ("X-Amz-Date".to_string(), amz_date(time)),
+            ("X-Amz-Expires".to_string(), expires_in.to_string()),
+            ("X-Amz-SignedHeaders".to_string(), signed_headers),
+        ]);
+        if let Some(token) = &self.credentials.session_token {
+            query.push(("X-Amz-Security-Token".to_string(), token.clone()));
+        }
+
+        let mut presigned = url.clone();
+        presigned.set_query(Some(&encode_query(&query)));
+        let signature = self.signature(method, &presigned, headers, UNSIGNED_PAYLOAD, time);
+        query.push(("X-Amz-Signature".to_string(), signature));
+        presigned.set_query(Some(&encode_query(&query)));
+        presigned
+    }
+
+    /// The credential scope, `<date>/<region>/s3/aws4_request`
+    pub fn scope(&self, time: DateTime<Utc>) -> String {
+        format!(
+            "{}/{}/{SERVICE}/aws4_request",
+            time.format("%Y%m%d"),
+            self.region
+        )
+    }
+}
+
+/// Formats a time as `x-amz-date` expects, e.g. `20130524T000000Z`
+pub(crate) fn amz_date(time: DateTime<Utc>) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Hex SHA-256 of a payload, for `x-amz-content-sha256`
+pub(crate) fn payload_hash(payload: &[u8]) -> String {
+    hex::encode(Sha256::digest(payload))
+}
+
+/// Percent-encodes everything but unreserved characters, and `/` unless `encode_slash` is set
+pub(crate) fn uri_encode(value: &str, encode_slash: bool) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            b'/' if !encode_slash => encoded.push('/'),
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+/// Query parameters encoded the same way as in the signature, in the given order
+fn encode_query(pairs: &[(String, String)]) -> String {
+    pairs
+        .iter()
+        .map(|(name, value)| format!("{}={}", uri_encode(name, true), uri_encode(value, true)))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Query parameters encoded and sorted by name, then value
+fn canonical_query(url: &Url) -> String {
+    let mut pairs: Vec<_> = url
+        .query_pairs()
+        .map(|(name, value)| (uri_encode(&name, true), uri_encode(&value, true)))
+        .collect();
+    pairs.sort();
+    pairs
+        .into_iter()
+        .map(|(name, value)| format!("{name}={value}"))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+fn hmac(key: &[u8], message: &str) -> Vec<u8> {
+    // HMAC accepts keys of any length
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).unwrap();
+    mac.update(message.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
@@ -0,0 +1,195 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::{ApiResult, ApiSuccess};
+
+/// Storage providers Sippy copies from or to
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SippyProvider {
+    Aws,
+    Gcs,
+    R2,
+}
+
+/// Incremental migration of a bucket: objects missing from R2 are read from the source
+/// bucket and copied on first access.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct SippyConfig {
+    pub enabled: bool,
+    pub source: Option<SippySourceInfo>,
+    pub destination: Option<SippyDestinationInfo>,
+}
+
+impl ApiResult for SippyConfig {}
+
+/// The bucket objects are copied from
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct SippySourceInfo {
+    pub provider: SippyProvider,
+    pub bucket: Option<String>,
+    pub region: Option<String>,
+}
+
+/// The R2 bucket objects are copied to
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SippyDestinationInfo {
+    pub provider: SippyProvider,
+    pub bucket: Option<String>,
+    /// Access key ID Sippy writes with
+    pub access_key_id: Option<String>,
+}
+
+/// Gets the Sippy configuration of a bucket.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/sippy/methods/get/>
+#[derive(Debug)]
+pub struct GetBucketSippy<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+}
+
+impl EndpointSpec for GetBucketSippy<'_> {
+    type JsonResponse = SippyConfig;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/sippy",
+            self.account_identifier, self.bucket_name
+        )
+    }
+}
+
+/// Credentials Sippy reads the source bucket with
+#[derive(Serialize, Clone, PartialEq, Eq)]
+#[serde(tag = "provider", rename_all = "lowercase")]
+pub enum SippySource {
+    Aws {
+        bucket: String,
+        region: String,
+        #[serde(rename = "accessKeyId")]
+        access_key_id: String,
+        #[serde(rename = "secretAccessKey")]
+        secret_access_key: String,
+    },
+    Gcs {
+        bucket: String,
+        /// Email of the service account
+        #[serde(rename = "clientEmail")]
+        client_email: String,
+        /// Private key of the service account
+        #[serde(rename = "privateKey")]
+        private_key: String,
+    },
+}
+
+impl std::fmt::Debug for SippySource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print the secrets
+        match self {
+            SippySource::Aws {
+                bucket,
+                region,
+                access_key_id,
+                ..
+            } => f
+                .debug_struct("Aws")
+                .field("bucket", bucket)
+                .field("region", region)
+                .field("access_key_id", access_key_id)
+                .finish_non_exhaustive(),
+            SippySource::Gcs {
+                bucket,
+                client_email,
+                ..
+            } => f
+                .debug_struct("Gcs")
+                .field("bucket", bucket)
+                .field("client_email", client_email)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+/// R2 API token credentials Sippy writes copied objects with
+#[derive(Serialize, Clone, PartialEq, Eq)]
+#[serde(tag = "provider", rename = "r2", rename_all = "camelCase")]
+pub struct SippyDestination {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+}
+
+impl std::fmt::Debug for SippyDestination {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SippyDestination")
+            .field("access_key_id", &self.access_key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct EnableSippyParams {
+    pub source: SippySource,
+    pub destination: SippyDestination,
+}
+
+/// Enables Sippy on a bucket, or replaces its configuration.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/sippy/methods/update/>
+#[derive(Debug)]
+pub struct EnableBucketSippy<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    pub params: EnableSippyParams,
+}
+
+impl EndpointSpec for EnableBucketSippy<'_> {
+    type JsonResponse = SippyConfig;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/sippy",
+            self.account_identifier, self.bucket_name
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody<'_>> {
+        Some(RequestBody::Json(
+            serde_json::to_string(&self.params).unwrap(),
+        ))
+    }
+}
+
+/// Disables Sippy on a bucket. Objects already copied stay in R2.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/sippy/methods/delete/>
+#[derive(Debug)]
+pub struct DisableBucketSippy<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+}
+
+impl EndpointSpec for DisableBucketSippy<'_> {
+    type JsonResponse = SippyConfig;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/sippy",
+            self.account_identifier, self.bucket_name
+        )
+    }
+}
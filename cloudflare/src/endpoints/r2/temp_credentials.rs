@@ -0,0 +1,136 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::sigv4::R2Credentials;
+use crate::framework::client::async_api::Client;
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::{ApiFailure, ApiResult, ApiSuccess};
+
+/// What temporary credentials may do
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum TempCredentialsPermission {
+    /// Read and write objects, and bucket configuration
+    AdminReadWrite,
+    /// Read objects and bucket configuration
+    AdminReadOnly,
+    /// Read and write objects
+    ObjectReadWrite,
+    /// Read objects
+    ObjectReadOnly,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTempCredentialsParams {
+    /// Bucket the credentials are for
+    pub bucket: String,
+    /// Access key ID of the API token the credentials derive from. They cannot do more than it.
+    pub parent_access_key_id: String,
+    pub permission: TempCredentialsPermission,
+    /// How long the credentials are valid
+    pub ttl_seconds: u64,
+    /// Key prefixes the credentials are limited to
+    pub prefixes: Option<Vec<String>>,
+    /// Keys the credentials are limited to
+    pub objects: Option<Vec<String>>,
+}
+
+/// Creates S3 credentials limited to one bucket, and optionally to some prefixes or objects
+/// of it, that expire after a while.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/temporary_credentials/methods/create/>
+#[derive(Debug)]
+pub struct CreateTempCredentials<'a> {
+    pub account_identifier: &'a str,
+    pub params: CreateTempCredentialsParams,
+}
+
+impl EndpointSpec for CreateTempCredentials<'_> {
+    type JsonResponse = TempCredentials;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/temp-access-credentials",
+            self.account_identifier
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody<'_>> {
+        Some(RequestBody::Json(
+            serde_json::to_string(&self.params).unwrap(),
+        ))
+    }
+}
+
+impl CreateTempCredentials<'_> {
+    /// Creates the credentials and pairs them with their expiry, `ttl_seconds` after the request
+    /// was sent, so the expiry is never later than the one R2 enforces
+    pub async fn request_expiring(
+        &self,
+        client: &Client,
+    ) -> Result<ExpiringCredentials, ApiFailure> {
+        let issued_at = Utc::now();
+        let credentials = client.request(self).await?.result;
+        // A TTL too long to represent never expires in practice
+        let expires_at = i64::try_from(self.params.ttl_seconds)
+            .ok()
+            .and_then(Duration::try_seconds)
+            .and_then(|ttl| issued_at.checked_add_signed(ttl))
+            .unwrap_or(DateTime::<Utc>::MAX_UTC);
+        Ok(ExpiringCredentials {
+            credentials: credentials.into(),
+            expires_at,
+        })
+    }
+}
+
+/// Temporary S3 credentials. The API does not say when they expire, see
+/// [`CreateTempCredentials::request_expiring`].
+#[derive(Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TempCredentials {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    pub session_token: String,
+}
+
+impl ApiResult for TempCredentials {}
+
+impl std::fmt::Debug for TempCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print the secrets
+        f.debug_struct("TempCredentials")
+            .field("access_key_id", &self.access_key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl From<TempCredentials> for R2Credentials {
+    fn from(credentials: TempCredentials) -> Self {
+        R2Credentials {
+            access_key_id: credentials.access_key_id,
+            secret_access_key: credentials.secret_access_key,
+            session_token: Some(credentials.session_token),
+        }
+    }
+}
+
+/// Credentials for [`super::s3::R2Client`] that stop working at some point
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpiringCredentials {
+    pub credentials: R2Credentials,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl ExpiringCredentials {
+    /// Whether the credentials expire within `margin` of `now`
+    pub fn expires_within(&self, now: DateTime<Utc>, margin: Duration) -> bool {
+        self.expires_at.signed_duration_since(now) <= margin
+    }
+}
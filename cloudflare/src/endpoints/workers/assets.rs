@@ -0,0 +1,314 @@
+/*!
+Uploads static assets for a Worker.
+
+The flow has three steps:
+1. [`AssetManifest::from_directory`] hashes every file under a directory.
+2. [`AssetsUploader::upload`] creates an upload session with the manifest, then uploads only the
+   buckets of files the API does not already have.
+3. The completion token it returns goes into [`WorkerAssets::jwt`] of the script upload metadata,
+   e.g. via [`worker_assets`].
+ */
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use base64::Engine;
+use futures_util::stream::{self, StreamExt, TryStreamExt};
+use sha2::{Digest, Sha256};
+
+use super::assets_ignore::AssetsIgnore;
+use super::assets_upload::{
+    AssetManifestEntry, AssetsUploadSession, CreateAssetsUploadSession,
+    CreateAssetsUploadSessionParams, UploadAssetFile, UploadAssets,
+};
+use super::script_data_structures::{WorkerAssets, WorkerAssetsConfig};
+use crate::framework::auth::Credentials;
+use crate::framework::client::async_api::Client;
+use crate::framework::response::ApiFailure;
+
+/// Largest single asset accepted by Workers, in bytes
+pub const MAX_ASSET_SIZE: u64 = 25 * 1024 * 1024;
+
+/// Top-level file with `.gitignore` patterns of files to leave out of the manifest
+const ASSETS_IGNORE_FILE: &str = ".assetsignore";
+
+#[derive(thiserror::Error, Debug)]
+/// Errors encountered while uploading assets
+pub enum AssetsError {
+    #[error("Failed to read {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Asset {path} is {size} bytes, over the limit of {MAX_ASSET_SIZE} bytes")]
+    TooLarge { path: String, size: u64 },
+    #[error("Upload session asked for unknown asset hash {0}")]
+    UnknownHash(String),
+    #[error("Cloudflare API request failed: {0}")]
+    Api(#[from] ApiFailure),
+    #[error("Assets upload finished without a completion token")]
+    MissingCompletionToken,
+}
+
+/// An asset on disk
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetFile {
+    /// Location on disk
+    pub path: PathBuf,
+    /// Content hash
+    pub hash: String,
+    /// Size in bytes
+    pub size: u64,
+    /// MIME type inferred from the extension, e.g. `text/html; charset=utf-8`
+    pub content_type: String,
+}
+
+/// Every asset a Worker serves, keyed by URL path
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AssetManifest {
+    files: BTreeMap<String, AssetFile>,
+}
+
+impl AssetManifest {
+    /// Hashes every file under `directory`. URL paths are relative to it and start with `/`.
+    ///
+    /// Like wrangler, files matching the `.gitignore`-style patterns of a top-level
+    /// `.assetsignore` file are left out, as are the `.assetsignore`, `_headers` and
+    /// `_redirects` files themselves and any `.git`, `.DS_Store` or `node_modules`.
+    pub fn from_directory(directory: impl AsRef<Path>) -> Result<Self, AssetsError> {
+        let directory = directory.as_ref();
+        let mut ignore = AssetsIgnore::default();
+        let ignore_file = directory.join(ASSETS_IGNORE_FILE);
+        if ignore_file.is_file() {
+            ignore.extend(&String::from_utf8_lossy(&read(&ignore_file)?));
+        }
+        let mut paths = Vec::new();
+        collect_files(directory, "", &ignore, &mut paths)?;
+
+        let mut manifest = AssetManifest::default();
+        for (url_path, path) in paths {
+            let content = read(&path)?;
+            manifest.insert(url_path, path, &content)?;
+        }
+        Ok(manifest)
+    }
+
+    /// Adds a file already in memory. `path` is where it can be read again during upload.
+    pub fn insert(
+        &mut self,
+        url_path: impl Into<String>,
+        path: impl Into<PathBuf>,
+        content: &[u8],
+    ) -> Result<(), AssetsError> {
+        let url_path = url_path.into();
+        let path = path.into();
+        let size = content.len() as u64;
+        if size > MAX_ASSET_SIZE {
+            return Err(AssetsError::TooLarge {
+                path: url_path,
+                size,
+            });
+        }
+        let extension = path
+            .extension()
+            .map(|e| e.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let hash = hash_asset(content, &extension);
+        let content_type = content_type(&path);
+        self.files.insert(
+            url_path,
+            AssetFile {
+                path,
+                hash,
+                size,
+                content_type,
+            },
+        );
+        Ok(())
+    }
+
+    /// The assets, keyed by URL path
+    pub fn files(&self) -> &BTreeMap<String, AssetFile> {
+        &self.files
+    }
+
+    /// Number of assets
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Whether there are no assets
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Parameters to start an upload session with this manifest
+    pub fn session_params(&self) -> CreateAssetsUploadSessionParams {
+        CreateAssetsUploadSessionParams {
+            manifest: self
+                .files
+                .iter()
+                .map(|(url_path, file)| {
+                    let entry = AssetManifestEntry {
+                        hash: file.hash.clone(),
+                        size: file.size,
+                    };
+                    (url_path.clone(), entry)
+                })
+                .collect(),
+        }
+    }
+
+    fn file_for_hash(&self, hash: &str) -> Option<&AssetFile> {
+        self.files.values().find(|file| file.hash == hash)
+    }
+}
+
+/// Hash identifying an asset's content: the first 32 hex characters of the SHA-256 of its
+/// base64 encoding followed by its extension, so a renamed file type is uploaded again.
+pub fn hash_asset(content: &[u8], extension: &str) -> String {
+    let encoded = base64::engine::general_purpose::STANDARD.encode(content);
+    let digest = Sha256::new()
+        .chain_update(encoded.as_bytes())
+        .chain_update(extension.as_bytes())
+        .finalize();
+    hex::encode(digest)[..32].to_string()
+}
+
+/// MIME type of a file from its extension, as wrangler sends it: `application/octet-stream`
+/// if unknown, and text types in UTF-8
+fn content_type(path: &Path) -> String {
+    let mime = mime_guess::from_path(path).first_or_octet_stream();
+    if mime.type_() == mime_guess::mime::TEXT && mime.get_param("charset").is_none() {
+        format!("{mime}; charset=utf-8")
+    } else {
+        mime.to_string()
+    }
+}
+
+/// Script metadata `assets` entry for a completion token
+pub fn worker_assets(jwt: impl Into<String>, config: WorkerAssetsConfig) -> WorkerAssets {
+    WorkerAssets {
+        jwt: Some(jwt.into()),
+        config: Some(config),
+    }
+}
+
+/// Runs an assets upload session for a script
+pub struct AssetsUploader<'a> {
+    client: &'a Client,
+    account_id: &'a str,
+    script_name: &'a str,
+    concurrency: usize,
+}
+
+impl<'a> AssetsUploader<'a> {
+    /// Buckets uploaded at the same time unless changed with [`AssetsUploader::concurrency`]
+    pub const DEFAULT_CONCURRENCY: usize = 3;
+
+    pub fn new(client: &'a Client, account_id: &'a str, script_name: &'a str) -> Self {
+        AssetsUploader {
+            client,
+            account_id,
+            script_name,
+            concurrency: Self::DEFAULT_CONCURRENCY,
+        }
+    }
+
+    /// Sets how many buckets are uploaded at the same time
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Uploads the assets the API does not have yet and returns the completion token
+    pub async fn upload(&self, manifest: &AssetManifest) -> Result<String, AssetsError> {
+        let session: AssetsUploadSession = self
+            .client
+            .request(&CreateAssetsUploadSession {
+                account_id: self.account_id,
+                script_name: self.script_name,
+                params: manifest.session_params(),
+            })
+            .await?
+            .result;
+        if session.buckets.is_empty() {
+            return Ok(session.jwt);
+        }
+
+        let credentials = Credentials::UserAuthToken { token: session.jwt };
+        let tokens: Vec<Option<String>> = stream::iter(&session.buckets)
+            .map(|bucket| self.upload_bucket(manifest, bucket, &credentials))
+            .buffer_unordered(self.concurrency)
+            .try_collect()
+            .await?;
+        tokens
+            .into_iter()
+            .flatten()
+            .next()
+            .ok_or(AssetsError::MissingCompletionToken)
+    }
+
+    async fn upload_bucket(
+        &self,
+        manifest: &AssetManifest,
+        bucket: &[String],
+        credentials: &Credentials,
+    ) -> Result<Option<String>, AssetsError> {
+        let engine = base64::engine::general_purpose::STANDARD;
+        let mut files = Vec::with_capacity(bucket.len());
+        for hash in bucket {
+            let file = manifest
+                .file_for_hash(hash)
+                .ok_or_else(|| AssetsError::UnknownHash(hash.clone()))?;
+            files.push(UploadAssetFile {
+                hash: hash.clone(),
+                content: engine.encode(read(&file.path)?),
+                content_type: file.content_type.clone(),
+            });
+        }
+        let upload = UploadAssets {
+            account_id: self.account_id,
+            files,
+        };
+        let result = self
+            .client
+            .request_with_credentials(&upload, credentials)
+            .await?
+            .result;
+        Ok(result.jwt)
+    }
+}
+
+fn read(path: &Path) -> Result<Vec<u8>, AssetsError> {
+    std::fs::read(path).map_err(|source| AssetsError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Collects `(url path, file path)` pairs under `directory`, whose URL path is `prefix`
+fn collect_files(
+    directory: &Path,
+    prefix: &str,
+    ignore: &AssetsIgnore,
+    files: &mut Vec<(String, PathBuf)>,
+) -> Result<(), AssetsError> {
+    let io_error = |source| AssetsError::Io {
+        path: directory.to_path_buf(),
+        source,
+    };
+    for entry in std::fs::read_dir(directory).map_err(io_error)? {
+        let entry = entry.map_err(io_error)?;
+        let url_path = format!("{prefix}/{}", entry.file_name().to_string_lossy());
+        let is_dir = entry.file_type().map_err(io_error)?.is_dir();
+        if ignore.is_ignored(&url_path, is_dir) {
+            continue;
+        }
+        if is_dir {
+            collect_files(&entry.path(), &url_path, ignore, files)?;
+        } else {
+            files.push((url_path, entry.path()));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,162 @@
+//! `.gitignore`-style patterns deciding which files under an assets directory are uploaded.
+//! <https://git-scm.com/docs/gitignore#_pattern_format>
+
+/// Patterns applied before the ones of the ignore file, as wrangler does: the files it reads as
+/// configuration, and directories and files that are never meant to be served.
+const DEFAULT_PATTERNS: &[&str] = &[
+    ".assetsignore",
+    "_headers",
+    "_redirects",
+    ".git",
+    ".DS_Store",
+    "node_modules",
+];
+
+/// Ignore rules, where the last rule matching a path decides
+#[derive(Debug, Clone)]
+pub(super) struct AssetsIgnore {
+    rules: Vec<Rule>,
+}
+
+#[derive(Debug, Clone)]
+struct Rule {
+    /// `/`-separated pattern segments
+    segments: Vec<String>,
+    /// `!pattern`: re-include what earlier rules ignored
+    negated: bool,
+    /// `pattern/`: only matches directories
+    directory_only: bool,
+}
+
+impl Default for AssetsIgnore {
+    fn default() -> Self {
+        AssetsIgnore::new(&DEFAULT_PATTERNS.join("\n"))
+    }
+}
+
+impl AssetsIgnore {
+    /// Parses the lines of an ignore file
+    pub fn new(patterns: &str) -> Self {
+        let mut ignore = AssetsIgnore { rules: Vec::new() };
+        ignore.extend(patterns);
+        ignore
+    }
+
+    /// Adds the rules of an ignore file after the current ones
+    pub fn extend(&mut self, patterns: &str) {
+        self.rules.extend(patterns.lines().filter_map(Rule::parse));
+    }
+
+    /// Whether a path, relative to the assets directory and `/`-separated, is left out.
+    /// Files under an ignored directory are never visited, as in git.
+    pub fn is_ignored(&self, path: &str, is_dir: bool) -> bool {
+        let path: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| rule.matches(&path, is_dir))
+            .is_some_and(|rule| !rule.negated)
+    }
+}
+
+impl Rule {
+    fn parse(line: &str) -> Option<Rule> {
+        let line = line.trim_end();
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+        let (negated, pattern) = match line.strip_prefix('!') {
+            Some(pattern) => (true, pattern),
+            None => (false, line.strip_prefix('\\').unwrap_or(line)),
+        };
+        let (directory_only, pattern) = match pattern.strip_suffix('/') {
+            Some(pattern) => (true, pattern),
+            None => (false, pattern),
+        };
+        // A pattern with a `/` is relative to the assets directory; otherwise it matches at
+        // any depth
+        let mut segments: Vec<String> = Vec::new();
+        if !pattern.contains('/') {
+            segments.push("**".to_string());
+        }
+        segments.extend(
+            pattern
+                .split('/')
+                .filter(|s| !s.is_empty())
+                .map(str::to_string),
+        );
+        if segments.iter().all(|s| s == "**") {
+            return None;
+        }
+        Some(Rule {
+            segments,
+            negated,
+            directory_only,
+        })
+    }
+
+    fn matches(&self, path: &[&str], is_dir: bool) -> bool {
+        if self.directory_only && !is_dir {
+            return false;
+        }
+        match_segments(&self.segments, path)
+    }
+}
+
+fn match_segments(pattern: &[String], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((segment, rest)) if segment == "**" => {
+            (0..=path.len()).any(|skip| match_segments(rest, &path[skip..]))
+        }
+        Some((segment, rest)) => match path.split_first() {
+            Some((name, path)) => {
+                let segment: Vec<char> = segment.chars().collect();
+                let name: Vec<char> = name.chars().collect();
+                wildcard(&segment, &name) && match_segments(rest, path)
+            }
+            None => false,
+        },
+    }
+}
+
+/// Matches one path segment against `*`, `?`, `[...]` and `\` escapes
+fn wildcard(pattern: &[char], name: &[char]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some(('*', rest)) => (0..=name.len()).any(|skip| wildcard(rest, &name[skip..])),
+        Some(('?', rest)) => !name.is_empty() && wildcard(rest, &name[1..]),
+        Some(('[', rest)) => match (rest.iter().position(|&c| c == ']'), name.split_first()) {
+            (Some(end), Some((&c, name))) => {
+                class_contains(&rest[..end], c) && wildcard(&rest[end + 1..], name)
+            }
+            // An unclosed `[` is a literal
+            (None, Some((&'[', name))) => wildcard(rest, name),
+            _ => false,
+        },
+        Some(('\\', rest)) if !rest.is_empty() => {
+            name.first() == Some(&rest[0]) && wildcard(&rest[1..], &name[1..])
+        }
+        Some((&c, rest)) => name.first() == Some(&c) && wildcard(rest, &name[1..]),
+    }
+}
+
+/// Whether a `[...]` class, without its brackets, contains a character
+fn class_contains(class: &[char], c: char) -> bool {
+    let (negated, class) = match class.split_first() {
+        Some(('!' | '^', class)) => (true, class),
+        _ => (false, class),
+    };
+    let mut found = false;
+    let mut i = 0;
+    while i < class.len() {
+        if i + 2 < class.len() && class[i + 1] == '-' {
+            found |= (class[i]..=class[i + 2]).contains(&c);
+            i += 3;
+        } else {
+            found |= class[i] == c;
+            i += 1;
+        }
+    }
+    found != negated
+}
@@ -0,0 +1,197 @@
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    use super::super::assets::{hash_asset, worker_assets, AssetManifest, AssetsUploader};
+    use super::super::assets_ignore::AssetsIgnore;
+    use super::super::{
+        AssetsHtmlHandling, AssetsNotFoundHandling, UploadAssetFile, UploadAssets,
+        WorkerAssetsConfig, WorkerScriptMetadata,
+    };
+    use crate::framework::auth::Credentials;
+    use crate::framework::client::async_api::Client;
+    use crate::framework::client::ClientConfig;
+    use crate::framework::endpoint::{EndpointSpec, MultipartBody, MultipartPart};
+    use crate::framework::Environment;
+
+    /// Writes a small site into its own scratch directory. The files are left in place because
+    /// uploads read them again.
+    fn manifest(name: &str) -> AssetManifest {
+        let dir = std::env::temp_dir().join(format!(
+            "cloudflare-rs-assets-{name}-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("css")).unwrap();
+        fs::create_dir_all(dir.join(".git")).unwrap();
+        fs::create_dir_all(dir.join(".well-known")).unwrap();
+        fs::write(dir.join("index.html"), "<h1>Hello</h1>").unwrap();
+        fs::write(dir.join("css/site.css"), "h1 { color: red }").unwrap();
+        fs::write(dir.join("css/site.css.map"), "{}").unwrap();
+        fs::write(dir.join(".git/HEAD"), "ref: refs/heads/main").unwrap();
+        fs::write(dir.join(".well-known/security.txt"), "Contact: a@b.c").unwrap();
+        fs::write(dir.join("_headers"), "/*\n  X-Frame-Options: DENY").unwrap();
+        fs::write(dir.join(".assetsignore"), "# source maps\n*.map").unwrap();
+        AssetManifest::from_directory(&dir).unwrap()
+    }
+
+    #[test]
+    fn test_asset_manifest_from_directory() {
+        let manifest = manifest("manifest");
+        let paths: Vec<_> = manifest.files().keys().map(String::as_str).collect();
+        assert_eq!(
+            paths,
+            vec!["/.well-known/security.txt", "/css/site.css", "/index.html"]
+        );
+
+        let index = &manifest.files()["/index.html"];
+        assert_eq!(index.size, 14);
+        assert_eq!(index.hash, hash_asset(b"<h1>Hello</h1>", "html"));
+        assert_eq!(index.hash.len(), 32);
+        assert_eq!(index.content_type, "text/html; charset=utf-8");
+        // The extension is part of the hash
+        assert_ne!(index.hash, hash_asset(b"<h1>Hello</h1>", "txt"));
+
+        let params = serde_json::to_value(manifest.session_params()).unwrap();
+        assert_eq!(
+            params["manifest"]["/index.html"],
+            json!({"hash": index.hash, "size": 14})
+        );
+    }
+
+    #[test]
+    fn test_assets_ignore_patterns() {
+        let ignore =
+            AssetsIgnore::new("# comment\n*.map\n/drafts/\n!keep.map\ndocs/**/*.md\nfile[0-9].txt");
+        assert!(ignore.is_ignored("/js/app.js.map", false));
+        assert!(!ignore.is_ignored("/js/keep.map", false));
+        assert!(ignore.is_ignored("/drafts", true));
+        assert!(!ignore.is_ignored("/drafts", false));
+        assert!(!ignore.is_ignored("/blog/drafts", true));
+        assert!(ignore.is_ignored("/docs/a/b/readme.md", false));
+        assert!(ignore.is_ignored("/docs/readme.md", false));
+        assert!(!ignore.is_ignored("/readme.md", false));
+        assert!(ignore.is_ignored("/file7.txt", false));
+        assert!(!ignore.is_ignored("/filex.txt", false));
+
+        let defaults = AssetsIgnore::default();
+        assert!(defaults.is_ignored("/.git", true));
+        assert!(defaults.is_ignored("/app/node_modules", true));
+        assert!(defaults.is_ignored("/_redirects", false));
+        assert!(!defaults.is_ignored("/.well-known", true));
+    }
+
+    #[test]
+    fn test_upload_assets_endpoint() {
+        let upload = UploadAssets {
+            account_id: "account",
+            files: vec![UploadAssetFile {
+                hash: "abc".to_string(),
+                content: "PGgxPg==".to_string(),
+                content_type: "text/html; charset=utf-8".to_string(),
+            }],
+        };
+        assert_eq!(upload.path(), "accounts/account/workers/assets/upload");
+        assert_eq!(upload.query(), Some("base64=true".to_string()));
+        let parts = upload.parts();
+        assert_eq!(parts[0].0, "abc");
+        match &parts[0].1 {
+            MultipartPart::File {
+                content,
+                file_name,
+                content_type,
+            } => {
+                assert_eq!(content, b"PGgxPg==");
+                assert_eq!(file_name, "abc");
+                assert_eq!(content_type, "text/html; charset=utf-8");
+            }
+            _ => panic!("Expected a file part"),
+        }
+    }
+
+    #[test]
+    fn test_worker_assets_metadata() {
+        let metadata = WorkerScriptMetadata {
+            main_module: "worker.js".to_string(),
+            assets: Some(worker_assets(
+                "completion-jwt",
+                WorkerAssetsConfig {
+                    html_handling: Some(AssetsHtmlHandling::AutoTrailingSlash),
+                    not_found_handling: Some(AssetsNotFoundHandling::NotFoundPage),
+                    run_worker_first: None,
+                },
+            )),
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&metadata).unwrap();
+        assert_eq!(
+            json["assets"],
+            json!({
+                "jwt": "completion-jwt",
+                "config": {
+                    "html_handling": "auto-trailing-slash",
+                    "not_found_handling": "404-page"
+                }
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_assets_upload_session() {
+        let manifest = manifest("upload");
+        let missing = manifest.files()["/index.html"].hash.clone();
+
+        let mut server = Server::new_async().await;
+        let session = server
+            .mock(
+                "POST",
+                "/accounts/account/workers/scripts/site/assets-upload-session",
+            )
+            .match_header("authorization", "Bearer api-token")
+            .with_body(
+                json!({
+                    "result": {"jwt": "upload-jwt", "buckets": [[missing]]},
+                    "success": true, "errors": [], "messages": []
+                })
+                .to_string(),
+            )
+            .create();
+        let upload = server
+            .mock("POST", "/accounts/account/workers/assets/upload")
+            .match_query(Matcher::UrlEncoded("base64".into(), "true".into()))
+            .match_header("authorization", "Bearer upload-jwt")
+            .match_body(Matcher::Regex(
+                "(?i)content-type: text/html; charset=utf-8\r\n\r\nPGgxPkhlbGxvPC9oMT4="
+                    .to_string(),
+            ))
+            .with_status(201)
+            .with_body(
+                json!({
+                    "result": {"jwt": "completion-jwt"},
+                    "success": true, "errors": [], "messages": []
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new(
+            Credentials::UserAuthToken {
+                token: "api-token".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap();
+        let jwt = AssetsUploader::new(&client, "account", "site")
+            .upload(&manifest)
+            .await
+            .unwrap();
+
+        session.assert();
+        upload.assert();
+        assert_eq!(jwt, "completion-jwt");
+    }
+}
@@ -0,0 +1,137 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::framework::endpoint::{EndpointSpec, Method, MultipartBody, MultipartPart, RequestBody};
+use crate::framework::response::{ApiResult, ApiSuccess};
+
+/// Start an assets upload session for a script. The API answers with the buckets of files it
+/// does not already have.
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/scripts/subresources/assets/subresources/upload/methods/create/>
+#[derive(Debug)]
+pub struct CreateAssetsUploadSession<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Script name
+    pub script_name: &'a str,
+    /// Manifest of every asset the script will serve
+    pub params: CreateAssetsUploadSessionParams,
+}
+
+impl EndpointSpec for CreateAssetsUploadSession<'_> {
+    type JsonResponse = AssetsUploadSession;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/scripts/{}/assets-upload-session",
+            self.account_id, self.script_name
+        )
+    }
+
+    fn body(&self) -> Option<RequestBody<'_>> {
+        Some(RequestBody::Json(
+            serde_json::to_string(&self.params).unwrap(),
+        ))
+    }
+}
+
+/// Parameters for [`CreateAssetsUploadSession`]
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct CreateAssetsUploadSessionParams {
+    /// Asset paths (starting with `/`) to their hash and size
+    pub manifest: BTreeMap<String, AssetManifestEntry>,
+}
+
+/// Hash and size of a single asset
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AssetManifestEntry {
+    /// 32 hex character content hash
+    pub hash: String,
+    /// Size in bytes
+    pub size: u64,
+}
+
+/// An assets upload session
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AssetsUploadSession {
+    /// Token authorizing the uploads. When there is nothing to upload, this is already the
+    /// completion token.
+    pub jwt: String,
+    /// Hashes of the files to upload, grouped into one request per bucket
+    #[serde(default)]
+    pub buckets: Vec<Vec<String>>,
+}
+
+/// Upload a bucket of assets, authenticated with the upload session token rather than the
+/// account credentials. Files are sent base64-encoded, keyed by hash, with their MIME type.
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/assets/subresources/upload/methods/create/>
+#[derive(Debug)]
+pub struct UploadAssets<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Files of the bucket
+    pub files: Vec<UploadAssetFile>,
+}
+
+/// A file of an [`UploadAssets`] bucket
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UploadAssetFile {
+    /// Content hash from the manifest
+    pub hash: String,
+    /// Base64-encoded content
+    pub content: String,
+    /// MIME type the asset is served with, e.g. `text/html; charset=utf-8`
+    pub content_type: String,
+}
+
+impl EndpointSpec for UploadAssets<'_> {
+    type JsonResponse = AssetsUploadResult;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> String {
+        format!("accounts/{}/workers/assets/upload", self.account_id)
+    }
+
+    fn query(&self) -> Option<String> {
+        Some("base64=true".to_string())
+    }
+
+    fn body(&self) -> Option<RequestBody<'_>> {
+        Some(RequestBody::MultiPart(self))
+    }
+}
+
+impl MultipartBody for UploadAssets<'_> {
+    fn parts(&self) -> Vec<(String, MultipartPart)> {
+        self.files
+            .iter()
+            .map(|file| {
+                let part = MultipartPart::File {
+                    content: file.content.clone().into_bytes(),
+                    file_name: file.hash.clone(),
+                    content_type: file.content_type.clone(),
+                };
+                (file.hash.clone(), part)
+            })
+            .collect()
+    }
+}
+
+/// Result of uploading a bucket of assets
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AssetsUploadResult {
+    /// Completion token, returned once every bucket of the session is uploaded
+    pub jwt: Option<String>,
+}
+
+impl ApiResult for AssetsUploadSession {}
+impl ApiResult for AssetsUploadResult {}
@@ -0,0 +1,78 @@
+use std::collections::BTreeMap;
+
+use crate::framework::endpoint::{EndpointSpec, Method, MultipartBody, MultipartPart, RequestBody};
+use crate::framework::response::ApiSuccess;
+
+use super::script_data_structures::WorkerBinding;
+use super::script_settings::WorkerScriptSettings;
+
+/// Set several secrets of a script at once. All changes land in a single new version, so the
+/// script restarts once instead of once per secret.
+///
+/// This patches the script's bindings: every binding not listed in `secrets` or `inherit` is
+/// removed, including other secrets. List the bindings to keep in `inherit`.
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/scripts/subresources/settings/methods/edit/>
+#[derive(Debug)]
+pub struct BulkUpdateSecrets<'a> {
+    /// Account ID of script owner
+    pub account_identifier: &'a str,
+    /// The name of the script to set the secrets on
+    pub script_name: &'a str,
+    /// Secret values to set, keyed by variable name
+    pub secrets: BTreeMap<String, String>,
+    /// Names of the existing bindings to keep unchanged
+    pub inherit: Vec<String>,
+}
+
+impl BulkUpdateSecrets<'_> {
+    /// The bindings the script ends up with
+    pub fn bindings(&self) -> Vec<WorkerBinding> {
+        let inherited = self
+            .inherit
+            .iter()
+            .filter(|name| !self.secrets.contains_key(*name))
+            .map(|name| WorkerBinding::Inherit {
+                name: name.clone(),
+                old_name: None,
+                version_id: None,
+            });
+        let secrets = self
+            .secrets
+            .iter()
+            .map(|(name, text)| WorkerBinding::SecretText {
+                name: name.clone(),
+                text: Some(text.clone()),
+            });
+        inherited.chain(secrets).collect()
+    }
+}
+
+impl EndpointSpec for BulkUpdateSecrets<'_> {
+    type JsonResponse = WorkerScriptSettings;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/scripts/{}/settings",
+            self.account_identifier, self.script_name
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody<'_>> {
+        Some(RequestBody::MultiPart(self))
+    }
+}
+
+impl MultipartBody for BulkUpdateSecrets<'_> {
+    fn parts(&self) -> Vec<(String, MultipartPart)> {
+        let settings = WorkerScriptSettings {
+            bindings: Some(self.bindings()),
+            ..Default::default()
+        };
+        let settings = serde_json::to_string(&settings).unwrap();
+        vec![("settings".to_string(), MultipartPart::Text(settings))]
+    }
+}
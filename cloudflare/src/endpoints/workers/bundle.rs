@@ -0,0 +1,359 @@
+/*!
+Assembles Worker uploads from files on disk.
+
+A [`WorkerBundle`] holds a main module plus any additional modules, each with a module type
+inferred from its file extension. It can be checked against the Workers size limits and turned
+into an [`UploadWorkerScript`] or [`UploadWorkerScriptJson`] request with a consistent
+`main_module`.
+ */
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use base64::Engine;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+
+use super::script_data_structures::{WorkerModule, WorkerScriptContent, WorkerScriptMetadata};
+use super::upload_script::{UploadWorkerScript, UploadWorkerScriptJson};
+
+const MIB: usize = 1024 * 1024;
+
+/// Kind of a Worker module, which determines the content type it is uploaded with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModuleType {
+    /// ES module (`.js`, `.mjs`)
+    EsModule,
+    /// CommonJS module (`.cjs`)
+    CommonJs,
+    /// Compiled WebAssembly (`.wasm`)
+    Wasm,
+    /// Text imported as a string (`.txt`, `.html`)
+    Text,
+    /// Binary data imported as an `ArrayBuffer` (`.bin`)
+    Data,
+    /// Source map (`.map`)
+    SourceMap,
+    /// Python module (`.py`)
+    Python,
+}
+
+impl ModuleType {
+    /// Infers the module type from a file extension, if it is one Workers understands
+    pub fn from_path(path: impl AsRef<Path>) -> Option<ModuleType> {
+        let extension = path.as_ref().extension()?.to_str()?;
+        match extension.to_ascii_lowercase().as_str() {
+            "js" | "mjs" => Some(ModuleType::EsModule),
+            "cjs" => Some(ModuleType::CommonJs),
+            "wasm" => Some(ModuleType::Wasm),
+            "txt" | "html" => Some(ModuleType::Text),
+            "bin" => Some(ModuleType::Data),
+            "map" => Some(ModuleType::SourceMap),
+            "py" => Some(ModuleType::Python),
+            _ => None,
+        }
+    }
+
+    /// The content type the module is uploaded with
+    pub fn content_type(self) -> &'static str {
+        match self {
+            ModuleType::EsModule => "application/javascript+module",
+            ModuleType::CommonJs => "application/javascript",
+            ModuleType::Wasm => "application/wasm",
+            ModuleType::Text => "text/plain",
+            ModuleType::Data => "application/octet-stream",
+            ModuleType::SourceMap => "application/source-map",
+            ModuleType::Python => "text/x-python",
+        }
+    }
+
+    /// Whether a module of this type can be the Worker's entry point
+    pub fn can_be_main_module(self) -> bool {
+        matches!(self, ModuleType::EsModule | ModuleType::Python)
+    }
+}
+
+/// Size limits a bundle must fit in
+/// <https://developers.cloudflare.com/workers/platform/limits/#worker-size>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BundleLimits {
+    /// Maximum size of all modules after gzip compression, in bytes
+    pub max_compressed_size: usize,
+    /// Maximum size of all modules before compression, in bytes
+    pub max_uncompressed_size: usize,
+}
+
+impl BundleLimits {
+    /// Limits on the Workers Free plan
+    pub const FREE: BundleLimits = BundleLimits {
+        max_compressed_size: 3 * MIB,
+        max_uncompressed_size: 64 * MIB,
+    };
+    /// Limits on the Workers Paid plan
+    pub const PAID: BundleLimits = BundleLimits {
+        max_compressed_size: 10 * MIB,
+        max_uncompressed_size: 64 * MIB,
+    };
+}
+
+impl Default for BundleLimits {
+    fn default() -> Self {
+        BundleLimits::PAID
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+/// Errors encountered while assembling a bundle
+pub enum BundleError {
+    #[error("Failed to read {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Entry module {0} is not inside the bundle directory")]
+    EntryOutsideDirectory(PathBuf),
+    #[error("Cannot infer a module type for {0}")]
+    UnknownModuleType(String),
+    #[error("Module {name} of type {module_type:?} cannot be the main module")]
+    InvalidMainModule {
+        name: String,
+        module_type: ModuleType,
+    },
+    #[error("Module {0} is included more than once")]
+    DuplicateModule(String),
+    #[error("Bundle is {size} bytes {kind}, over the limit of {limit} bytes")]
+    TooLarge {
+        kind: &'static str,
+        size: usize,
+        limit: usize,
+    },
+}
+
+/// A Worker main module and its additional modules
+#[derive(Debug, Clone)]
+pub struct WorkerBundle {
+    main_module: WorkerModule,
+    modules: Vec<WorkerModule>,
+}
+
+impl WorkerBundle {
+    /// Starts a bundle from the main module's name and content
+    pub fn new(main_module: impl Into<String>, content: Vec<u8>) -> Result<Self, BundleError> {
+        let name = main_module.into();
+        let module_type = infer_type(&name)?;
+        if !module_type.can_be_main_module() {
+            return Err(BundleError::InvalidMainModule { name, module_type });
+        }
+        Ok(WorkerBundle {
+            main_module: WorkerModule {
+                name,
+                content,
+                content_type: module_type.content_type().to_string(),
+            },
+            modules: Vec::new(),
+        })
+    }
+
+    /// Bundles every file in `directory` whose extension maps to a [`ModuleType`], with `entry`
+    /// as the main module. Module names are paths relative to `directory`. Files with other
+    /// extensions, and hidden files and directories, are skipped.
+    pub fn from_directory(
+        directory: impl AsRef<Path>,
+        entry: impl AsRef<Path>,
+    ) -> Result<Self, BundleError> {
+        let directory = directory.as_ref();
+        let entry = if entry.as_ref().is_absolute() {
+            entry.as_ref().to_path_buf()
+        } else {
+            directory.join(entry.as_ref())
+        };
+        let entry_name = entry
+            .strip_prefix(directory)
+            .map_err(|_| BundleError::EntryOutsideDirectory(entry.clone()))
+            .map(module_name)?;
+
+        let mut bundle = WorkerBundle::new(entry_name, read(&entry)?)?;
+        let mut files = Vec::new();
+        collect_files(directory, &mut files)?;
+        files.sort();
+        for path in files {
+            let name = module_name(path.strip_prefix(directory).unwrap());
+            if name == bundle.main_module.name || ModuleType::from_path(&path).is_none() {
+                continue;
+            }
+            let content = read(&path)?;
+            bundle.add_module(name, content)?;
+        }
+        Ok(bundle)
+    }
+
+    /// Adds a module, inferring its type from the name's extension
+    pub fn add_module(
+        &mut self,
+        name: impl Into<String>,
+        content: Vec<u8>,
+    ) -> Result<(), BundleError> {
+        let name = name.into();
+        let module_type = infer_type(&name)?;
+        self.add_module_with_type(name, content, module_type)
+    }
+
+    /// Adds a module with an explicit type
+    pub fn add_module_with_type(
+        &mut self,
+        name: impl Into<String>,
+        content: Vec<u8>,
+        module_type: ModuleType,
+    ) -> Result<(), BundleError> {
+        let name = name.into();
+        if name == self.main_module.name || self.modules.iter().any(|m| m.name == name) {
+            return Err(BundleError::DuplicateModule(name));
+        }
+        self.modules.push(WorkerModule {
+            name,
+            content,
+            content_type: module_type.content_type().to_string(),
+        });
+        Ok(())
+    }
+
+    /// Name of the main module, as it should appear in `WorkerScriptMetadata::main_module`
+    pub fn main_module(&self) -> &str {
+        &self.main_module.name
+    }
+
+    /// Modules other than the main module
+    pub fn modules(&self) -> &[WorkerModule] {
+        &self.modules
+    }
+
+    /// Total size of all modules, in bytes
+    pub fn size(&self) -> usize {
+        self.all_modules().map(|m| m.content.len()).sum()
+    }
+
+    /// Size of all modules after gzip compression, in bytes
+    pub fn compressed_size(&self) -> usize {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        for module in self.all_modules() {
+            // Writing into a Vec cannot fail
+            encoder.write_all(&module.content).unwrap();
+        }
+        encoder.finish().unwrap().len()
+    }
+
+    /// Checks the bundle against size limits before uploading it
+    pub fn validate(&self, limits: BundleLimits) -> Result<(), BundleError> {
+        let size = self.size();
+        if size > limits.max_uncompressed_size {
+            return Err(BundleError::TooLarge {
+                kind: "uncompressed",
+                size,
+                limit: limits.max_uncompressed_size,
+            });
+        }
+        let compressed = self.compressed_size();
+        if compressed > limits.max_compressed_size {
+            return Err(BundleError::TooLarge {
+                kind: "compressed",
+                size: compressed,
+                limit: limits.max_compressed_size,
+            });
+        }
+        Ok(())
+    }
+
+    /// Builds a multipart upload. `metadata.main_module` is set to the bundle's main module.
+    pub fn into_upload<'a>(
+        self,
+        account_id: &'a str,
+        script_name: &'a str,
+        mut metadata: WorkerScriptMetadata,
+    ) -> UploadWorkerScript<'a> {
+        metadata.main_module = self.main_module.name.clone();
+        UploadWorkerScript {
+            account_id,
+            script_name,
+            metadata,
+            script_content: self.main_module.into(),
+            modules: self.modules.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Builds a JSON upload, base64-encoding every module. `metadata.main_module` is set to the
+    /// bundle's main module.
+    pub fn into_json_upload<'a>(
+        self,
+        account_id: &'a str,
+        script_name: &'a str,
+        mut metadata: WorkerScriptMetadata,
+    ) -> UploadWorkerScriptJson<'a> {
+        let engine = base64::engine::general_purpose::STANDARD;
+        metadata.main_module = self.main_module.name.clone();
+        UploadWorkerScriptJson {
+            account_id,
+            script_name,
+            main_module_content: engine.encode(&self.main_module.content),
+            main_module_name: self.main_module.name,
+            modules: self
+                .modules
+                .into_iter()
+                .map(|m| (m.name, engine.encode(&m.content), m.content_type))
+                .collect(),
+            metadata: Some(metadata),
+        }
+    }
+
+    fn all_modules(&self) -> impl Iterator<Item = &WorkerModule> {
+        std::iter::once(&self.main_module).chain(&self.modules)
+    }
+}
+
+impl From<WorkerModule> for WorkerScriptContent {
+    fn from(module: WorkerModule) -> Self {
+        WorkerScriptContent {
+            content: module.content,
+            content_type: module.content_type,
+            name: module.name,
+        }
+    }
+}
+
+fn infer_type(name: &str) -> Result<ModuleType, BundleError> {
+    ModuleType::from_path(name).ok_or_else(|| BundleError::UnknownModuleType(name.to_string()))
+}
+
+/// Module name for a path relative to the bundle directory, always `/`-separated
+fn module_name(relative: &Path) -> String {
+    relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn read(path: &Path) -> Result<Vec<u8>, BundleError> {
+    std::fs::read(path).map_err(|source| BundleError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn collect_files(directory: &Path, files: &mut Vec<PathBuf>) -> Result<(), BundleError> {
+    let io_error = |source| BundleError::Io {
+        path: directory.to_path_buf(),
+        source,
+    };
+    for entry in std::fs::read_dir(directory).map_err(io_error)? {
+        let entry = entry.map_err(io_error)?;
+        if entry.file_name().to_string_lossy().starts_with('.') {
+            continue;
+        }
+        let path = entry.path();
+        if entry.file_type().map_err(io_error)?.is_dir() {
+            collect_files(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
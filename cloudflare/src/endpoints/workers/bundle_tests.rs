@@ -0,0 +1,227 @@
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use super::super::bundle::{BundleError, BundleLimits, ModuleType, WorkerBundle};
+    use super::super::script_data_structures::WorkerScriptMetadata;
+    use crate::framework::endpoint::{EndpointSpec, MultipartBody, MultipartPart, RequestBody};
+
+    fn metadata() -> WorkerScriptMetadata {
+        WorkerScriptMetadata {
+            main_module: String::new(),
+            bindings: None,
+            compatibility_date: Some("2024-01-01".to_string()),
+            compatibility_flags: None,
+            usage_model: None,
+            placement: None,
+            tail_consumers: None,
+            keep_bindings_on_error: None,
+            observability: None,
+            assets: None,
+            tags: None,
+            migrations: None,
+        }
+    }
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "cloudflare-rs-bundle-{name}-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_module_type_inference() {
+        assert_eq!(
+            ModuleType::from_path("index.mjs"),
+            Some(ModuleType::EsModule)
+        );
+        assert_eq!(
+            ModuleType::from_path("lib/util.js"),
+            Some(ModuleType::EsModule)
+        );
+        assert_eq!(
+            ModuleType::from_path("legacy.cjs"),
+            Some(ModuleType::CommonJs)
+        );
+        assert_eq!(ModuleType::from_path("add.WASM"), Some(ModuleType::Wasm));
+        assert_eq!(ModuleType::from_path("page.html"), Some(ModuleType::Text));
+        assert_eq!(ModuleType::from_path("blob.bin"), Some(ModuleType::Data));
+        assert_eq!(
+            ModuleType::from_path("index.js.map"),
+            Some(ModuleType::SourceMap)
+        );
+        assert_eq!(ModuleType::from_path("main.py"), Some(ModuleType::Python));
+        assert_eq!(ModuleType::from_path("README.md"), None);
+        assert_eq!(ModuleType::from_path("Makefile"), None);
+        assert_eq!(ModuleType::Wasm.content_type(), "application/wasm");
+    }
+
+    #[test]
+    fn test_bundle_from_directory() {
+        let dir = scratch_dir("directory");
+        fs::create_dir_all(dir.join("lib")).unwrap();
+        fs::create_dir_all(dir.join(".cache")).unwrap();
+        fs::write(dir.join("index.mjs"), "import './lib/util.js';").unwrap();
+        fs::write(dir.join("lib/util.js"), "export const x = 1;").unwrap();
+        fs::write(dir.join("add.wasm"), [0, 97, 115, 109]).unwrap();
+        fs::write(dir.join("README.md"), "not a module").unwrap();
+        fs::write(dir.join(".cache/stale.js"), "hidden").unwrap();
+
+        let bundle = WorkerBundle::from_directory(&dir, "index.mjs").unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(bundle.main_module(), "index.mjs");
+        let names: Vec<_> = bundle.modules().iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, vec!["add.wasm", "lib/util.js"]);
+        assert_eq!(bundle.modules()[0].content_type, "application/wasm");
+        assert_eq!(bundle.validate(BundleLimits::FREE).ok(), Some(()));
+
+        let upload = bundle.into_upload("account", "script", metadata());
+        assert_eq!(upload.metadata.main_module, "index.mjs");
+        assert_eq!(upload.script_content.name, "index.mjs");
+        assert_eq!(
+            upload.script_content.content_type,
+            "application/javascript+module"
+        );
+        let parts: Vec<_> = upload.parts().into_iter().map(|(name, _)| name).collect();
+        assert_eq!(
+            parts,
+            vec!["metadata", "index.mjs", "add.wasm", "lib/util.js"]
+        );
+    }
+
+    #[test]
+    fn test_bundle_upload_part_types() {
+        let mut bundle = WorkerBundle::new("worker.mjs", b"export default {}".to_vec()).unwrap();
+        bundle
+            .add_module("add.wasm", vec![0, 97, 115, 109])
+            .unwrap();
+        bundle
+            .add_module("page.html", b"<p>hi</p>".to_vec())
+            .unwrap();
+        bundle.add_module("blob.bin", vec![0xff]).unwrap();
+
+        let parts = bundle.into_upload("account", "script", metadata()).parts();
+        assert!(matches!(parts[0].1, MultipartPart::Text(_)));
+        let types: Vec<_> = parts[1..]
+            .iter()
+            .map(|(name, part)| match part {
+                MultipartPart::File {
+                    file_name,
+                    content_type,
+                    ..
+                } => {
+                    assert_eq!(file_name, name);
+                    (name.as_str(), content_type.as_str())
+                }
+                _ => panic!("expected a file part for {name}"),
+            })
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                ("worker.mjs", "application/javascript+module"),
+                ("add.wasm", "application/wasm"),
+                ("page.html", "text/plain"),
+                ("blob.bin", "application/octet-stream"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bundle_json_upload_encodes_modules() {
+        let mut bundle = WorkerBundle::new("worker.mjs", b"export default {}".to_vec()).unwrap();
+        bundle
+            .add_module("data.bin", vec![0xde, 0xad, 0xbe, 0xef])
+            .unwrap();
+
+        let upload = bundle.into_json_upload("account", "script", metadata());
+        assert_eq!(upload.main_module_name, "worker.mjs");
+        assert_eq!(upload.main_module_content, "ZXhwb3J0IGRlZmF1bHQge30=");
+        assert_eq!(
+            upload.modules,
+            vec![(
+                "data.bin".to_string(),
+                "3q2+7w==".to_string(),
+                "application/octet-stream".to_string()
+            )]
+        );
+        match upload.body() {
+            Some(RequestBody::Json(json)) => {
+                assert!(json.contains("\"main_module\":\"worker.mjs\""))
+            }
+            _ => panic!("Expected JSON body"),
+        }
+    }
+
+    #[test]
+    fn test_bundle_errors() {
+        assert!(matches!(
+            WorkerBundle::new("add.wasm", vec![]),
+            Err(BundleError::InvalidMainModule {
+                module_type: ModuleType::Wasm,
+                ..
+            })
+        ));
+        assert!(matches!(
+            WorkerBundle::new("worker", vec![]),
+            Err(BundleError::UnknownModuleType(_))
+        ));
+
+        let mut bundle = WorkerBundle::new("worker.js", vec![]).unwrap();
+        assert!(matches!(
+            bundle.add_module("worker.js", vec![]),
+            Err(BundleError::DuplicateModule(_))
+        ));
+        bundle
+            .add_module_with_type("rules", b"a=b".to_vec(), ModuleType::Text)
+            .unwrap();
+        assert_eq!(bundle.modules()[0].content_type, "text/plain");
+
+        assert!(matches!(
+            WorkerBundle::from_directory(
+                std::env::temp_dir().join("cloudflare-rs-missing"),
+                "index.js"
+            ),
+            Err(BundleError::Io { .. })
+        ));
+    }
+
+    #[test]
+    fn test_bundle_size_limits() {
+        let bundle = WorkerBundle::new("worker.js", vec![b'a'; 2048]).unwrap();
+        assert_eq!(bundle.size(), 2048);
+        assert!(bundle.compressed_size() < 2048);
+
+        let tiny = BundleLimits {
+            max_compressed_size: 1024,
+            max_uncompressed_size: 1024,
+        };
+        assert!(matches!(
+            bundle.validate(tiny),
+            Err(BundleError::TooLarge {
+                kind: "uncompressed",
+                size: 2048,
+                limit: 1024
+            })
+        ));
+
+        let compressed_only = BundleLimits {
+            max_compressed_size: 8,
+            max_uncompressed_size: 4096,
+        };
+        assert!(matches!(
+            bundle.validate(compressed_only),
+            Err(BundleError::TooLarge {
+                kind: "compressed",
+                ..
+            })
+        ));
+        assert_eq!(bundle.validate(BundleLimits::default()).ok(), Some(()));
+    }
+}
@@ -0,0 +1,142 @@
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::ApiSuccess;
+
+use super::version_data_structures::{
+    WorkerAnnotations, WorkerDeployment, WorkerDeploymentStrategy, WorkerDeploymentVersion,
+};
+
+/// Create a deployment, routing traffic to one version or splitting it by percentage between
+/// two versions (a gradual deployment)
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/scripts/subresources/deployments/methods/create/>
+#[derive(Debug)]
+pub struct CreateWorkerDeployment<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Script name
+    pub script_name: &'a str,
+    /// Deployment parameters
+    pub params: CreateWorkerDeploymentParams,
+    /// Deploy even if the versions' bindings differ from the latest deployment
+    pub force: bool,
+}
+
+impl EndpointSpec for CreateWorkerDeployment<'_> {
+    type JsonResponse = WorkerDeployment;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/scripts/{}/deployments",
+            self.account_id, self.script_name
+        )
+    }
+
+    fn query(&self) -> Option<String> {
+        self.force.then(|| "force=true".to_string())
+    }
+
+    fn body(&self) -> Option<RequestBody<'_>> {
+        Some(RequestBody::Json(
+            serde_json::to_string(&self.params).unwrap(),
+        ))
+    }
+}
+
+/// Parameters for [`CreateWorkerDeployment`]
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone)]
+pub struct CreateWorkerDeploymentParams {
+    /// Traffic split strategy
+    pub strategy: WorkerDeploymentStrategy,
+    /// Versions and their share of traffic. Percentages must add up to 100.
+    pub versions: Vec<WorkerDeploymentVersion>,
+    /// Deployment annotations
+    pub annotations: Option<WorkerAnnotations>,
+}
+
+impl CreateWorkerDeploymentParams {
+    /// The maximum number of versions a deployment can split traffic between
+    pub const MAX_VERSIONS: usize = 2;
+
+    /// Route all traffic to a single version
+    pub fn single(version_id: impl Into<String>) -> Self {
+        Self::percentages(vec![(version_id.into(), 100.0)])
+    }
+
+    /// Split traffic between versions, given as `(version_id, percentage)` pairs. Fails on
+    /// splits the API would reject.
+    pub fn split(versions: Vec<(String, f64)>) -> Result<Self, DeploymentSplitError> {
+        let params = Self::percentages(versions);
+        params.validate()?;
+        Ok(params)
+    }
+
+    fn percentages(versions: Vec<(String, f64)>) -> Self {
+        Self {
+            strategy: WorkerDeploymentStrategy::Percentage,
+            versions: versions
+                .into_iter()
+                .map(|(version_id, percentage)| WorkerDeploymentVersion {
+                    percentage,
+                    version_id,
+                })
+                .collect(),
+            annotations: None,
+        }
+    }
+
+    /// Attach a message to the deployment
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.annotations
+            .get_or_insert_with(Default::default)
+            .message = Some(message.into());
+        self
+    }
+
+    fn validate(&self) -> Result<(), DeploymentSplitError> {
+        if self.versions.is_empty() {
+            return Err(DeploymentSplitError::NoVersions);
+        }
+        if self.versions.len() > Self::MAX_VERSIONS {
+            return Err(DeploymentSplitError::TooManyVersions {
+                count: self.versions.len(),
+                max: Self::MAX_VERSIONS,
+            });
+        }
+        if let Some(v) = self
+            .versions
+            .iter()
+            .find(|v| !(0.0..=100.0).contains(&v.percentage))
+        {
+            return Err(DeploymentSplitError::InvalidPercentage {
+                version_id: v.version_id.clone(),
+                percentage: v.percentage,
+            });
+        }
+        let total: f64 = self.versions.iter().map(|v| v.percentage).sum();
+        if (total - 100.0).abs() > 1e-6 {
+            return Err(DeploymentSplitError::TotalNot100 { total });
+        }
+        Ok(())
+    }
+}
+
+#[derive(thiserror::Error, Debug, PartialEq)]
+/// A traffic split that the API would reject
+pub enum DeploymentSplitError {
+    #[error("Deployment must contain at least one version")]
+    NoVersions,
+    #[error("Deployment contains {count} versions, but at most {max} are allowed")]
+    TooManyVersions { count: usize, max: usize },
+    #[error("Version {version_id} has percentage {percentage}, which is not between 0 and 100")]
+    InvalidPercentage { version_id: String, percentage: f64 },
+    #[error("Deployment percentages add up to {total}, not 100")]
+    TotalNot100 { total: f64 },
+}
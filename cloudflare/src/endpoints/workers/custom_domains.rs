@@ -0,0 +1,165 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::framework::endpoint::{serialize_query, EndpointSpec, Method, RequestBody};
+use crate::framework::response::{ApiResult, ApiSuccess};
+
+/// List the custom domains Workers are attached to
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/domains/methods/list/>
+#[derive(Debug)]
+pub struct ListWorkerDomains<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Filters
+    pub params: ListWorkerDomainsParams,
+}
+
+/// Filters for [`ListWorkerDomains`]
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ListWorkerDomainsParams {
+    /// Only domains with this hostname
+    pub hostname: Option<String>,
+    /// Only domains attached to this script
+    pub service: Option<String>,
+    /// Only domains attached to this environment
+    pub environment: Option<String>,
+    /// Only domains in this zone
+    pub zone_id: Option<String>,
+    /// Only domains in the zone with this name
+    pub zone_name: Option<String>,
+}
+
+impl EndpointSpec for ListWorkerDomains<'_> {
+    type JsonResponse = Vec<WorkerDomain>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!("accounts/{}/workers/domains", self.account_id)
+    }
+
+    fn query(&self) -> Option<String> {
+        serialize_query(&self.params)
+    }
+}
+
+/// Attach a Worker to a custom domain. Cloudflare creates the DNS record and certificate.
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/domains/methods/update/>
+#[derive(Debug)]
+pub struct AttachWorkerDomain<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Domain to attach
+    pub params: AttachWorkerDomainParams,
+}
+
+/// Parameters for [`AttachWorkerDomain`]
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone)]
+pub struct AttachWorkerDomainParams {
+    /// Hostname to attach, e.g. `api.example.com`
+    pub hostname: String,
+    /// Script name
+    pub service: String,
+    /// Zone the hostname belongs to
+    pub zone_id: String,
+    /// Environment of the script, `production` if omitted
+    pub environment: Option<String>,
+}
+
+impl EndpointSpec for AttachWorkerDomain<'_> {
+    type JsonResponse = WorkerDomain;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn path(&self) -> String {
+        format!("accounts/{}/workers/domains", self.account_id)
+    }
+
+    fn body(&self) -> Option<RequestBody<'_>> {
+        Some(RequestBody::Json(
+            serde_json::to_string(&self.params).unwrap(),
+        ))
+    }
+}
+
+/// Get a Worker custom domain
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/domains/methods/get/>
+#[derive(Debug)]
+pub struct GetWorkerDomain<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Domain identifier
+    pub domain_id: &'a str,
+}
+
+impl EndpointSpec for GetWorkerDomain<'_> {
+    type JsonResponse = WorkerDomain;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/domains/{}",
+            self.account_id, self.domain_id
+        )
+    }
+}
+
+/// Detach a Worker from a custom domain
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/domains/methods/delete/>
+#[derive(Debug)]
+pub struct DetachWorkerDomain<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Domain identifier
+    pub domain_id: &'a str,
+}
+
+impl EndpointSpec for DetachWorkerDomain<'_> {
+    type JsonResponse = ();
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/domains/{}",
+            self.account_id, self.domain_id
+        )
+    }
+}
+
+/// A custom domain a Worker is attached to
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct WorkerDomain {
+    /// Domain identifier
+    pub id: String,
+    /// Attached hostname
+    pub hostname: String,
+    /// Script name
+    pub service: String,
+    /// Environment of the script
+    pub environment: Option<String>,
+    /// Zone the hostname belongs to
+    pub zone_id: String,
+    /// Name of the zone
+    pub zone_name: Option<String>,
+    /// Certificate serving the hostname
+    pub cert_id: Option<String>,
+}
+
+impl ApiResult for WorkerDomain {}
+impl ApiResult for Vec<WorkerDomain> {}
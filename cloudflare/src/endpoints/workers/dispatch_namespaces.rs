@@ -0,0 +1,139 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::{ApiResult, ApiSuccess};
+
+/// List the Workers for Platforms dispatch namespaces of an account
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/methods/list/>
+#[derive(Debug)]
+pub struct ListDispatchNamespaces<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+}
+
+impl EndpointSpec for ListDispatchNamespaces<'_> {
+    type JsonResponse = Vec<DispatchNamespace>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!("accounts/{}/workers/dispatch/namespaces", self.account_id)
+    }
+}
+
+/// Create a dispatch namespace
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/methods/create/>
+#[derive(Debug)]
+pub struct CreateDispatchNamespace<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Namespace to create
+    pub params: CreateDispatchNamespaceParams,
+}
+
+/// Parameters for [`CreateDispatchNamespace`]
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct CreateDispatchNamespaceParams {
+    /// Namespace name, generated if omitted
+    pub name: Option<String>,
+}
+
+impl EndpointSpec for CreateDispatchNamespace<'_> {
+    type JsonResponse = DispatchNamespace;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> String {
+        format!("accounts/{}/workers/dispatch/namespaces", self.account_id)
+    }
+
+    fn body(&self) -> Option<RequestBody<'_>> {
+        Some(RequestBody::Json(
+            serde_json::to_string(&self.params).unwrap(),
+        ))
+    }
+}
+
+/// Get a dispatch namespace
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/methods/get/>
+#[derive(Debug)]
+pub struct GetDispatchNamespace<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Namespace name
+    pub dispatch_namespace: &'a str,
+}
+
+impl EndpointSpec for GetDispatchNamespace<'_> {
+    type JsonResponse = DispatchNamespace;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/dispatch/namespaces/{}",
+            self.account_id, self.dispatch_namespace
+        )
+    }
+}
+
+/// Delete a dispatch namespace and every script in it
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/methods/delete/>
+#[derive(Debug)]
+pub struct DeleteDispatchNamespace<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Namespace name
+    pub dispatch_namespace: &'a str,
+}
+
+impl EndpointSpec for DeleteDispatchNamespace<'_> {
+    type JsonResponse = ();
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/dispatch/namespaces/{}",
+            self.account_id, self.dispatch_namespace
+        )
+    }
+}
+
+/// A Workers for Platforms dispatch namespace, holding customer scripts that a dispatch Worker
+/// routes requests to
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct DispatchNamespace {
+    /// Namespace identifier
+    pub namespace_id: String,
+    /// Namespace name
+    pub namespace_name: String,
+    pub created_on: Option<DateTime<Utc>>,
+    /// Identifier of the user who created the namespace
+    pub created_by: Option<String>,
+    pub modified_on: Option<DateTime<Utc>>,
+    /// Identifier of the user who last modified the namespace
+    pub modified_by: Option<String>,
+    /// Number of scripts in the namespace
+    pub script_count: Option<u64>,
+    /// Whether scripts in the namespace can use trusted-only features such as `request.cf`
+    pub trusted_workers: Option<bool>,
+}
+
+impl ApiResult for DispatchNamespace {}
+impl ApiResult for Vec<DispatchNamespace> {}
@@ -0,0 +1,386 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::framework::endpoint::{
+    serialize_query, EndpointSpec, Method, MultipartBody, MultipartPart, RequestBody,
+};
+use crate::framework::response::{ApiResult, ApiSuccess};
+
+use super::script_data_structures::{WorkerScript, WorkerScriptContent, WorkerScriptMetadata};
+use super::upload_script::script_parts;
+
+/// Upload a script into a dispatch namespace. Takes the same metadata and content as
+/// [`super::UploadWorkerScript`]; set `metadata.tags` to tag the script.
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/subresources/scripts/methods/update/>
+#[derive(Debug)]
+pub struct UploadDispatchNamespaceScript<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Namespace name
+    pub dispatch_namespace: &'a str,
+    /// Script name
+    pub script_name: &'a str,
+    /// Script metadata
+    pub metadata: WorkerScriptMetadata,
+    /// Main script content
+    pub script_content: WorkerScriptContent,
+    /// Additional modules (WASM, data blobs, etc.)
+    pub modules: Vec<WorkerScriptContent>,
+}
+
+impl EndpointSpec for UploadDispatchNamespaceScript<'_> {
+    type JsonResponse = WorkerScript;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn path(&self) -> String {
+        namespace_script_path(self.account_id, self.dispatch_namespace, self.script_name)
+    }
+
+    fn body(&self) -> Option<RequestBody<'_>> {
+        Some(RequestBody::MultiPart(self))
+    }
+}
+
+impl MultipartBody for UploadDispatchNamespaceScript<'_> {
+    fn parts(&self) -> Vec<(String, MultipartPart)> {
+        let metadata_json =
+            serde_json::to_string(&self.metadata).unwrap_or_else(|_| "{}".to_string());
+        script_parts(metadata_json, &self.script_content, &self.modules)
+    }
+}
+
+/// Get a script in a dispatch namespace
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/subresources/scripts/methods/get/>
+#[derive(Debug)]
+pub struct GetDispatchNamespaceScript<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Namespace name
+    pub dispatch_namespace: &'a str,
+    /// Script name
+    pub script_name: &'a str,
+}
+
+impl EndpointSpec for GetDispatchNamespaceScript<'_> {
+    type JsonResponse = DispatchNamespaceScript;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        namespace_script_path(self.account_id, self.dispatch_namespace, self.script_name)
+    }
+}
+
+/// Delete a script from a dispatch namespace
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/subresources/scripts/methods/delete/>
+#[derive(Debug)]
+pub struct DeleteDispatchNamespaceScript<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Namespace name
+    pub dispatch_namespace: &'a str,
+    /// Script name
+    pub script_name: &'a str,
+    /// Delete even if the script is still bound to other Workers
+    pub force: bool,
+}
+
+impl EndpointSpec for DeleteDispatchNamespaceScript<'_> {
+    type JsonResponse = ();
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn path(&self) -> String {
+        namespace_script_path(self.account_id, self.dispatch_namespace, self.script_name)
+    }
+
+    fn query(&self) -> Option<String> {
+        self.force.then(|| "force=true".to_string())
+    }
+}
+
+/// List the scripts in a dispatch namespace, optionally filtered by tag
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/subresources/scripts/methods/list/>
+#[derive(Debug)]
+pub struct ListDispatchNamespaceScripts<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Namespace name
+    pub dispatch_namespace: &'a str,
+    /// Only scripts matching these tags
+    pub tags: ScriptTagFilter,
+}
+
+impl EndpointSpec for ListDispatchNamespaceScripts<'_> {
+    type JsonResponse = Vec<WorkerScript>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        namespace_scripts_path(self.account_id, self.dispatch_namespace)
+    }
+
+    fn query(&self) -> Option<String> {
+        self.tags.query()
+    }
+}
+
+/// Delete every script in a dispatch namespace that matches a tag filter, e.g. all scripts of
+/// one customer
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/subresources/scripts/methods/bulk_delete/>
+#[derive(Debug)]
+pub struct DeleteDispatchNamespaceScriptsByTag<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Namespace name
+    pub dispatch_namespace: &'a str,
+    /// Scripts to delete, never empty
+    tags: ScriptTagFilter,
+}
+
+impl<'a> DeleteDispatchNamespaceScriptsByTag<'a> {
+    /// Fails on an empty filter, which would delete every script of the namespace. Use
+    /// [`super::DeleteDispatchNamespace`] for that.
+    pub fn new(
+        account_id: &'a str,
+        dispatch_namespace: &'a str,
+        tags: ScriptTagFilter,
+    ) -> Result<Self, EmptyTagFilter> {
+        if tags.is_empty() {
+            return Err(EmptyTagFilter);
+        }
+        Ok(DeleteDispatchNamespaceScriptsByTag {
+            account_id,
+            dispatch_namespace,
+            tags,
+        })
+    }
+
+    /// Scripts to delete
+    pub fn tags(&self) -> &ScriptTagFilter {
+        &self.tags
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("Tag filter is empty and would match every script in the namespace")]
+/// A bulk delete without any tag to filter by
+pub struct EmptyTagFilter;
+
+impl EndpointSpec for DeleteDispatchNamespaceScriptsByTag<'_> {
+    type JsonResponse = ();
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn path(&self) -> String {
+        namespace_scripts_path(self.account_id, self.dispatch_namespace)
+    }
+
+    fn query(&self) -> Option<String> {
+        self.tags.query()
+    }
+}
+
+/// Tag filter for listing and deleting scripts in a dispatch namespace
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScriptTagFilter {
+    tags: Vec<(String, bool)>,
+}
+
+impl ScriptTagFilter {
+    /// Matches scripts that have `tag`
+    pub fn with(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push((tag.into(), true));
+        self
+    }
+
+    /// Matches scripts that do not have `tag`
+    pub fn without(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push((tag.into(), false));
+        self
+    }
+
+    /// Whether the filter matches every script
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+    }
+
+    fn query(&self) -> Option<String> {
+        if self.tags.is_empty() {
+            return None;
+        }
+        let tags = self
+            .tags
+            .iter()
+            .map(|(tag, present)| format!("{tag}:{}", if *present { "yes" } else { "no" }))
+            .collect::<Vec<_>>()
+            .join(",");
+        serialize_query(&[("tags", tags)])
+    }
+}
+
+/// Get the tags of a script in a dispatch namespace
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/subresources/scripts/subresources/tags/methods/list/>
+#[derive(Debug)]
+pub struct GetDispatchScriptTags<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Namespace name
+    pub dispatch_namespace: &'a str,
+    /// Script name
+    pub script_name: &'a str,
+}
+
+impl EndpointSpec for GetDispatchScriptTags<'_> {
+    type JsonResponse = Vec<String>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "{}/tags",
+            namespace_script_path(self.account_id, self.dispatch_namespace, self.script_name)
+        )
+    }
+}
+
+/// Replace the tags of a script in a dispatch namespace
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/subresources/scripts/subresources/tags/methods/update/>
+#[derive(Debug)]
+pub struct UpdateDispatchScriptTags<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Namespace name
+    pub dispatch_namespace: &'a str,
+    /// Script name
+    pub script_name: &'a str,
+    /// The new tags
+    pub tags: Vec<String>,
+}
+
+impl EndpointSpec for UpdateDispatchScriptTags<'_> {
+    type JsonResponse = Vec<String>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "{}/tags",
+            namespace_script_path(self.account_id, self.dispatch_namespace, self.script_name)
+        )
+    }
+
+    fn body(&self) -> Option<RequestBody<'_>> {
+        Some(RequestBody::Json(
+            serde_json::to_string(&self.tags).unwrap(),
+        ))
+    }
+}
+
+/// Add a single tag to a script in a dispatch namespace
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/subresources/scripts/subresources/tags/methods/update/>
+#[derive(Debug)]
+pub struct AddDispatchScriptTag<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Namespace name
+    pub dispatch_namespace: &'a str,
+    /// Script name
+    pub script_name: &'a str,
+    /// Tag to add
+    pub tag: &'a str,
+}
+
+impl EndpointSpec for AddDispatchScriptTag<'_> {
+    type JsonResponse = ();
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "{}/tags/{}",
+            namespace_script_path(self.account_id, self.dispatch_namespace, self.script_name),
+            urlencoding::encode(self.tag)
+        )
+    }
+}
+
+/// Remove a single tag from a script in a dispatch namespace
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/subresources/scripts/subresources/tags/methods/delete/>
+#[derive(Debug)]
+pub struct DeleteDispatchScriptTag<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Namespace name
+    pub dispatch_namespace: &'a str,
+    /// Script name
+    pub script_name: &'a str,
+    /// Tag to remove
+    pub tag: &'a str,
+}
+
+impl EndpointSpec for DeleteDispatchScriptTag<'_> {
+    type JsonResponse = ();
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "{}/tags/{}",
+            namespace_script_path(self.account_id, self.dispatch_namespace, self.script_name),
+            urlencoding::encode(self.tag)
+        )
+    }
+}
+
+/// A script in a dispatch namespace
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct DispatchNamespaceScript {
+    /// Namespace the script belongs to
+    pub dispatch_namespace: Option<String>,
+    pub created_on: Option<DateTime<Utc>>,
+    pub modified_on: Option<DateTime<Utc>>,
+    /// The script itself
+    pub script: WorkerScript,
+}
+
+impl ApiResult for DispatchNamespaceScript {}
+
+fn namespace_scripts_path(account_id: &str, dispatch_namespace: &str) -> String {
+    format!("accounts/{account_id}/workers/dispatch/namespaces/{dispatch_namespace}/scripts")
+}
+
+fn namespace_script_path(account_id: &str, dispatch_namespace: &str, script_name: &str) -> String {
+    format!(
+        "{}/{script_name}",
+        namespace_scripts_path(account_id, dispatch_namespace)
+    )
+}
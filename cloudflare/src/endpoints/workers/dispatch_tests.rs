@@ -0,0 +1,206 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{
+        AddDispatchScriptTag, CreateDispatchNamespace, CreateDispatchNamespaceParams,
+        DeleteDispatchNamespaceScript, DeleteDispatchNamespaceScriptsByTag, DispatchNamespace,
+        DispatchNamespaceOutbound, DispatchNamespaceScript, EmptyTagFilter,
+        ListDispatchNamespaceScripts, ScriptTagFilter, UpdateDispatchScriptTags,
+        UploadDispatchNamespaceScript, WorkerBinding, WorkerScriptContent, WorkerScriptMetadata,
+    };
+    use crate::framework::endpoint::{EndpointSpec, Method, MultipartPart, RequestBody};
+
+    #[test]
+    fn test_create_dispatch_namespace() {
+        let endpoint = CreateDispatchNamespace {
+            account_id: "account",
+            params: CreateDispatchNamespaceParams {
+                name: Some("customers".to_string()),
+            },
+        };
+        assert_eq!(endpoint.method(), Method::POST);
+        assert_eq!(
+            endpoint.path(),
+            "accounts/account/workers/dispatch/namespaces"
+        );
+        match endpoint.body() {
+            Some(RequestBody::Json(json)) => assert_eq!(json, r#"{"name":"customers"}"#),
+            _ => panic!("Expected JSON body"),
+        }
+    }
+
+    #[test]
+    fn test_dispatch_namespace_deserialization() {
+        let namespace: DispatchNamespace = serde_json::from_value(serde_json::json!({
+            "namespace_id": "0f2ac74b498b48028cb68387c421e279",
+            "namespace_name": "customers",
+            "created_on": "2024-01-01T00:00:00Z",
+            "created_by": "user",
+            "modified_on": "2024-01-02T00:00:00Z",
+            "modified_by": "user",
+            "script_count": 42,
+            "trusted_workers": false
+        }))
+        .unwrap();
+        assert_eq!(namespace.namespace_name, "customers");
+        assert_eq!(namespace.script_count, Some(42));
+    }
+
+    #[test]
+    fn test_upload_dispatch_script_shares_script_parts() {
+        let endpoint = UploadDispatchNamespaceScript {
+            account_id: "account",
+            dispatch_namespace: "customers",
+            script_name: "customer-a",
+            metadata: WorkerScriptMetadata {
+                main_module: "worker.js".to_string(),
+                tags: Some(vec!["customer-a".to_string(), "free".to_string()]),
+                ..Default::default()
+            },
+            script_content: WorkerScriptContent {
+                content: b"export default {}".to_vec(),
+                content_type: "application/javascript+module".to_string(),
+                name: "worker.js".to_string(),
+            },
+            modules: vec![],
+        };
+        assert_eq!(endpoint.method(), Method::PUT);
+        assert_eq!(
+            endpoint.path(),
+            "accounts/account/workers/dispatch/namespaces/customers/scripts/customer-a"
+        );
+
+        let Some(RequestBody::MultiPart(multipart)) = endpoint.body() else {
+            panic!("Expected multipart body");
+        };
+        let parts = multipart.parts();
+        assert_eq!(parts.len(), 2);
+        let MultipartPart::Text(metadata) = &parts[0].1 else {
+            panic!("Expected metadata text part");
+        };
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(metadata).unwrap(),
+            serde_json::json!({"main_module": "worker.js", "tags": ["customer-a", "free"]})
+        );
+        assert_eq!(parts[1].0, "worker.js");
+    }
+
+    #[test]
+    fn test_dispatch_script_deserialization() {
+        let script: DispatchNamespaceScript = serde_json::from_value(serde_json::json!({
+            "created_on": "2024-01-01T00:00:00Z",
+            "dispatch_namespace": "customers",
+            "modified_on": "2024-01-02T00:00:00Z",
+            "script": {
+                "id": "customer-a",
+                "tags": ["customer-a"]
+            }
+        }))
+        .unwrap();
+        assert_eq!(script.dispatch_namespace.as_deref(), Some("customers"));
+        assert_eq!(script.script.tags, Some(vec!["customer-a".to_string()]));
+    }
+
+    #[test]
+    fn test_delete_dispatch_script() {
+        let endpoint = DeleteDispatchNamespaceScript {
+            account_id: "account",
+            dispatch_namespace: "customers",
+            script_name: "customer-a",
+            force: true,
+        };
+        assert_eq!(endpoint.method(), Method::DELETE);
+        assert_eq!(endpoint.query(), Some("force=true".to_string()));
+    }
+
+    #[test]
+    fn test_script_tag_filter() {
+        let list = ListDispatchNamespaceScripts {
+            account_id: "account",
+            dispatch_namespace: "customers",
+            tags: ScriptTagFilter::default(),
+        };
+        assert_eq!(list.query(), None);
+
+        assert_eq!(
+            DeleteDispatchNamespaceScriptsByTag::new(
+                "account",
+                "customers",
+                ScriptTagFilter::default()
+            )
+            .unwrap_err(),
+            EmptyTagFilter
+        );
+
+        let delete = DeleteDispatchNamespaceScriptsByTag::new(
+            "account",
+            "customers",
+            ScriptTagFilter::default()
+                .with("customer-a")
+                .without("plan/free & trial"),
+        )
+        .unwrap();
+        assert_eq!(delete.method(), Method::DELETE);
+        assert_eq!(
+            delete.path(),
+            "accounts/account/workers/dispatch/namespaces/customers/scripts"
+        );
+        assert_eq!(
+            delete.query(),
+            Some("tags=customer-a%3Ayes%2Cplan%2Ffree+%26+trial%3Ano".to_string())
+        );
+    }
+
+    #[test]
+    fn test_add_dispatch_script_tag_encodes_tag() {
+        let endpoint = AddDispatchScriptTag {
+            account_id: "account",
+            dispatch_namespace: "customers",
+            script_name: "customer-a",
+            tag: "plan/free trial",
+        };
+        assert_eq!(
+            endpoint.path(),
+            "accounts/account/workers/dispatch/namespaces/customers/scripts/customer-a/tags/plan%2Ffree%20trial"
+        );
+    }
+
+    #[test]
+    fn test_update_dispatch_script_tags() {
+        let endpoint = UpdateDispatchScriptTags {
+            account_id: "account",
+            dispatch_namespace: "customers",
+            script_name: "customer-a",
+            tags: vec!["customer-a".to_string(), "paid".to_string()],
+        };
+        assert_eq!(endpoint.method(), Method::PUT);
+        assert_eq!(
+            endpoint.path(),
+            "accounts/account/workers/dispatch/namespaces/customers/scripts/customer-a/tags"
+        );
+        match endpoint.body() {
+            Some(RequestBody::Json(json)) => assert_eq!(json, r#"["customer-a","paid"]"#),
+            _ => panic!("Expected JSON body"),
+        }
+    }
+
+    #[test]
+    fn test_dispatch_namespace_binding() {
+        let binding = WorkerBinding::DispatchNamespace {
+            name: "DISPATCHER".to_string(),
+            namespace: "customers".to_string(),
+            outbound: Some(DispatchNamespaceOutbound {
+                params: Some(vec!["customer_id".to_string()]),
+                worker: None,
+            }),
+        };
+        assert_eq!(
+            serde_json::to_value(&binding).unwrap(),
+            serde_json::json!({
+                "type": "dispatch_namespace",
+                "name": "DISPATCHER",
+                "namespace": "customers",
+                "outbound": {"params": ["customer_id"]}
+            })
+        );
+    }
+}
@@ -1,8 +1,10 @@
 #[cfg(test)]
 mod tests {
     use super::super::{
-        DurableObjectInfo, DurableObjectNamespace, DurableObjectsResultInfo,
-        ListDurableObjectNamespaces, ListDurableObjectsInNamespace, ListDurableObjectsResponse,
+        next_migrations, DurableObjectInfo, DurableObjectMigration, DurableObjectNamespace,
+        DurableObjectsResultInfo, ListDurableObjectNamespaces, ListDurableObjectsInNamespace,
+        ListDurableObjectsResponse, MigrationError, RenamedClass, TransferredClass,
+        WorkerMigrationStep, WorkerMigrations, WorkerScriptMetadata,
     };
     use crate::framework::endpoint::EndpointSpec;
 
@@ -245,4 +247,102 @@ mod tests {
             serde_json::from_str(&json_empty).unwrap();
         assert_eq!(result_info_empty, deserialized_empty);
     }
+
+    fn migrations() -> Vec<DurableObjectMigration> {
+        vec![
+            DurableObjectMigration {
+                tag: "v1".to_string(),
+                step: WorkerMigrationStep {
+                    new_sqlite_classes: Some(vec!["Counter".to_string()]),
+                    ..Default::default()
+                },
+            },
+            DurableObjectMigration {
+                tag: "v2".to_string(),
+                step: WorkerMigrationStep {
+                    renamed_classes: Some(vec![RenamedClass {
+                        from: "Counter".to_string(),
+                        to: "Tally".to_string(),
+                    }]),
+                    ..Default::default()
+                },
+            },
+            DurableObjectMigration {
+                tag: "v3".to_string(),
+                step: WorkerMigrationStep {
+                    transferred_classes: Some(vec![TransferredClass {
+                        from: "Room".to_string(),
+                        from_script: "chat-legacy".to_string(),
+                        to: "Room".to_string(),
+                    }]),
+                    deleted_classes: Some(vec!["Session".to_string()]),
+                    ..Default::default()
+                },
+            },
+        ]
+    }
+
+    #[test]
+    fn test_next_migrations() {
+        let migrations = migrations();
+
+        let all = next_migrations(&migrations, None).unwrap().unwrap();
+        assert_eq!(all.old_tag, None);
+        assert_eq!(all.new_tag.as_deref(), Some("v3"));
+        assert_eq!(all.steps.len(), 3);
+
+        let pending = next_migrations(&migrations, Some("v1")).unwrap().unwrap();
+        assert_eq!(
+            pending,
+            WorkerMigrations {
+                old_tag: Some("v1".to_string()),
+                new_tag: Some("v3".to_string()),
+                steps: vec![migrations[1].step.clone(), migrations[2].step.clone()],
+            }
+        );
+
+        assert_eq!(next_migrations(&migrations, Some("v3")).unwrap(), None);
+        assert_eq!(next_migrations(&[], None).unwrap(), None);
+    }
+
+    #[test]
+    fn test_next_migrations_errors() {
+        let mut migrations = migrations();
+        assert_eq!(
+            next_migrations(&migrations, Some("v9")),
+            Err(MigrationError::UnknownDeployedTag("v9".to_string()))
+        );
+
+        migrations[2].tag = "v1".to_string();
+        assert_eq!(
+            next_migrations(&migrations, None),
+            Err(MigrationError::DuplicateTag("v1".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_migrations_in_upload_metadata() {
+        let metadata = WorkerScriptMetadata {
+            main_module: "worker.js".to_string(),
+            migrations: next_migrations(&migrations(), Some("v2")).unwrap(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            serde_json::to_value(&metadata).unwrap(),
+            serde_json::json!({
+                "main_module": "worker.js",
+                "migrations": {
+                    "old_tag": "v2",
+                    "new_tag": "v3",
+                    "steps": [{
+                        "deleted_classes": ["Session"],
+                        "transferred_classes": [
+                            {"from": "Room", "from_script": "chat-legacy", "to": "Room"}
+                        ]
+                    }]
+                }
+            })
+        );
+    }
 }
@@ -0,0 +1,32 @@
+use crate::framework::endpoint::{EndpointSpec, Method};
+use crate::framework::response::ApiSuccess;
+
+use super::version_data_structures::WorkerDeployment;
+
+/// Get a single deployment of a Workers script
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/scripts/subresources/deployments/methods/get/>
+#[derive(Debug)]
+pub struct GetWorkerDeployment<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Script name
+    pub script_name: &'a str,
+    /// Deployment identifier
+    pub deployment_id: &'a str,
+}
+
+impl EndpointSpec for GetWorkerDeployment<'_> {
+    type JsonResponse = WorkerDeployment;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/scripts/{}/deployments/{}",
+            self.account_id, self.script_name, self.deployment_id
+        )
+    }
+}
@@ -0,0 +1,32 @@
+use crate::framework::endpoint::{EndpointSpec, Method};
+use crate::framework::response::ApiSuccess;
+
+use super::version_data_structures::WorkerVersion;
+
+/// Get a single version of a Workers script, including its bindings
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/scripts/subresources/versions/methods/get/>
+#[derive(Debug)]
+pub struct GetWorkerVersion<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Script name
+    pub script_name: &'a str,
+    /// Version identifier
+    pub version_id: &'a str,
+}
+
+impl EndpointSpec for GetWorkerVersion<'_> {
+    type JsonResponse = WorkerVersion;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/scripts/{}/versions/{}",
+            self.account_id, self.script_name, self.version_id
+        )
+    }
+}
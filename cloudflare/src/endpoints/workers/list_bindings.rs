@@ -1,4 +1,4 @@
-use super::WorkersBinding;
+use super::WorkerBinding;
 use crate::framework::endpoint::{EndpointSpec, Method};
 use crate::framework::response::ApiSuccess;
 
@@ -13,7 +13,7 @@ pub struct ListBindings<'a> {
 }
 
 impl EndpointSpec for ListBindings<'_> {
-    type JsonResponse = Vec<WorkersBinding>;
+    type JsonResponse = Vec<WorkerBinding>;
     type ResponseType = ApiSuccess<Self::JsonResponse>;
 
     fn method(&self) -> Method {
@@ -0,0 +1,31 @@
+use crate::framework::endpoint::{EndpointSpec, Method};
+use crate::framework::response::ApiSuccess;
+
+use super::version_data_structures::WorkerDeploymentList;
+
+/// List the deployments of a Workers script. The most recent deployment is the one serving
+/// traffic, see [`WorkerDeploymentList::active`].
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/scripts/subresources/deployments/methods/list/>
+#[derive(Debug)]
+pub struct ListWorkerDeployments<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Script name
+    pub script_name: &'a str,
+}
+
+impl EndpointSpec for ListWorkerDeployments<'_> {
+    type JsonResponse = WorkerDeploymentList;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/scripts/{}/deployments",
+            self.account_id, self.script_name
+        )
+    }
+}
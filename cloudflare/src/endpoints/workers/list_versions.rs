@@ -0,0 +1,51 @@
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+use crate::framework::endpoint::{serialize_query, EndpointSpec, Method};
+use crate::framework::response::ApiSuccess;
+
+use super::version_data_structures::WorkerVersionList;
+
+/// List the versions of a Workers script, newest first
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/scripts/subresources/versions/methods/list/>
+#[derive(Debug)]
+pub struct ListWorkerVersions<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Script name
+    pub script_name: &'a str,
+    /// Filters and pagination
+    pub params: ListWorkerVersionsParams,
+}
+
+/// Query parameters for [`ListWorkerVersions`]
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ListWorkerVersionsParams {
+    /// Only return versions that can be used in a deployment
+    pub deployable: Option<bool>,
+    /// Page number
+    pub page: Option<u32>,
+    /// Number of versions per page
+    pub per_page: Option<u32>,
+}
+
+impl EndpointSpec for ListWorkerVersions<'_> {
+    type JsonResponse = WorkerVersionList;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/scripts/{}/versions",
+            self.account_id, self.script_name
+        )
+    }
+
+    fn query(&self) -> Option<String> {
+        serialize_query(&self.params)
+    }
+}
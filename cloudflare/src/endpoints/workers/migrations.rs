@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// Durable Object migrations sent with a script upload. The upload fails unless `old_tag`
+/// matches the tag the script currently has, which keeps migrations from being applied twice.
+/// <https://developers.cloudflare.com/durable-objects/reference/durable-objects-migrations/>
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorkerMigrations {
+    /// Tag the script is expected to have, `None` if it has never been migrated
+    pub old_tag: Option<String>,
+    /// Tag the script has after the upload
+    pub new_tag: Option<String>,
+    /// Migration steps, applied in order
+    pub steps: Vec<WorkerMigrationStep>,
+}
+
+/// One Durable Object migration step
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorkerMigrationStep {
+    /// New classes using key-value storage
+    pub new_classes: Option<Vec<String>>,
+    /// New classes using SQLite storage
+    pub new_sqlite_classes: Option<Vec<String>>,
+    /// Classes renamed within the script, keeping their objects
+    pub renamed_classes: Option<Vec<RenamedClass>>,
+    /// Classes deleted along with all their objects
+    pub deleted_classes: Option<Vec<String>>,
+    /// Classes moved from another script, keeping their objects
+    pub transferred_classes: Option<Vec<TransferredClass>>,
+}
+
+/// A Durable Object class renamed within a script
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RenamedClass {
+    pub from: String,
+    pub to: String,
+}
+
+/// A Durable Object class moved from another script
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TransferredClass {
+    /// Class name in the other script
+    pub from: String,
+    /// The other script
+    pub from_script: String,
+    /// Class name in this script
+    pub to: String,
+}
+
+/// A tagged migration step, as listed in a project's configuration. Tags must be unique and
+/// the list must only ever be appended to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DurableObjectMigration {
+    pub tag: String,
+    pub step: WorkerMigrationStep,
+}
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+/// Errors encountered while working out which migrations to apply
+pub enum MigrationError {
+    #[error("Deployed migration tag {0} is not in the list of migrations")]
+    UnknownDeployedTag(String),
+    #[error("Migration tag {0} is used more than once")]
+    DuplicateTag(String),
+}
+
+/// Works out the migrations to upload, given every migration of the project in order and the
+/// tag the deployed script has (`WorkerScript::migration_tag`). Returns `None` when the script
+/// is up to date.
+pub fn next_migrations(
+    migrations: &[DurableObjectMigration],
+    deployed_tag: Option<&str>,
+) -> Result<Option<WorkerMigrations>, MigrationError> {
+    for (index, migration) in migrations.iter().enumerate() {
+        if migrations[..index].iter().any(|m| m.tag == migration.tag) {
+            return Err(MigrationError::DuplicateTag(migration.tag.clone()));
+        }
+    }
+
+    let pending = match deployed_tag {
+        None => migrations,
+        Some(tag) => {
+            let applied = migrations
+                .iter()
+                .position(|m| m.tag == tag)
+                .ok_or_else(|| MigrationError::UnknownDeployedTag(tag.to_string()))?;
+            &migrations[applied + 1..]
+        }
+    };
+    let Some(last) = pending.last() else {
+        return Ok(None);
+    };
+    Ok(Some(WorkerMigrations {
+        old_tag: deployed_tag.map(str::to_string),
+        new_tag: Some(last.tag.clone()),
+        steps: pending.iter().map(|m| m.step.clone()).collect(),
+    }))
+}
@@ -3,38 +3,95 @@ use crate::framework::response::ApiResult;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+pub mod assets;
+mod assets_ignore;
+mod assets_tests;
+mod assets_upload;
+mod bulk_secrets;
+pub mod bundle;
+mod bundle_tests;
+mod create_deployment;
 mod create_route;
 mod create_secret;
 mod create_tail;
+mod custom_domains;
 mod delete_do;
 mod delete_route;
 mod delete_script;
 mod delete_secret;
 mod delete_tail;
+mod dispatch_namespaces;
+mod dispatch_scripts;
+mod dispatch_tests;
 mod do_tests;
+mod get_deployment;
 mod get_script;
+mod get_version;
 mod list_bindings;
+mod list_deployments;
 mod list_do_namespaces;
 mod list_do_objects;
 mod list_routes;
 mod list_scripts;
 mod list_secrets;
 mod list_tails;
+mod list_versions;
+mod migrations;
+mod schedules;
+mod script_content;
 mod script_data_structures;
+mod script_settings;
 mod script_tests;
+pub mod secrets;
+mod secrets_tests;
 mod send_tail_heartbeat;
+mod settings_tests;
+mod subdomain;
+#[cfg(feature = "tail")]
+mod tail_consumer;
+mod tail_events;
+mod tail_tests;
+mod trigger_tests;
 mod upload_script;
+mod upload_version;
+mod version_data_structures;
+mod version_tests;
 
+pub use assets_upload::{
+    AssetManifestEntry, AssetsUploadResult, AssetsUploadSession, CreateAssetsUploadSession,
+    CreateAssetsUploadSessionParams, UploadAssetFile, UploadAssets,
+};
+pub use bulk_secrets::BulkUpdateSecrets;
+pub use create_deployment::{
+    CreateWorkerDeployment, CreateWorkerDeploymentParams, DeploymentSplitError,
+};
 pub use create_route::{CreateRoute, CreateRouteParams};
 pub use create_secret::{CreateSecret, CreateSecretParams};
 pub use create_tail::{CreateTail, CreateTailParams};
+pub use custom_domains::{
+    AttachWorkerDomain, AttachWorkerDomainParams, DetachWorkerDomain, GetWorkerDomain,
+    ListWorkerDomains, ListWorkerDomainsParams, WorkerDomain,
+};
 pub use delete_do::DeleteDurableObject;
 pub use delete_route::DeleteRoute;
 pub use delete_script::DeleteScript;
 pub use delete_secret::DeleteSecret;
 pub use delete_tail::DeleteTail;
+pub use dispatch_namespaces::{
+    CreateDispatchNamespace, CreateDispatchNamespaceParams, DeleteDispatchNamespace,
+    DispatchNamespace, GetDispatchNamespace, ListDispatchNamespaces,
+};
+pub use dispatch_scripts::{
+    AddDispatchScriptTag, DeleteDispatchNamespaceScript, DeleteDispatchNamespaceScriptsByTag,
+    DeleteDispatchScriptTag, DispatchNamespaceScript, EmptyTagFilter, GetDispatchNamespaceScript,
+    GetDispatchScriptTags, ListDispatchNamespaceScripts, ScriptTagFilter, UpdateDispatchScriptTags,
+    UploadDispatchNamespaceScript,
+};
+pub use get_deployment::GetWorkerDeployment;
 pub use get_script::GetWorkerScript;
+pub use get_version::GetWorkerVersion;
 pub use list_bindings::ListBindings;
+pub use list_deployments::ListWorkerDeployments;
 pub use list_do_namespaces::{DurableObjectNamespace, ListDurableObjectNamespaces};
 pub use list_do_objects::{
     DurableObjectInfo, DurableObjectsResultInfo, ListDurableObjectsInNamespace,
@@ -44,13 +101,45 @@ pub use list_routes::ListRoutes;
 pub use list_scripts::ListWorkerScripts;
 pub use list_secrets::ListSecrets;
 pub use list_tails::ListTails;
+pub use list_versions::{ListWorkerVersions, ListWorkerVersionsParams};
+pub use migrations::{
+    next_migrations, DurableObjectMigration, MigrationError, RenamedClass, TransferredClass,
+    WorkerMigrationStep, WorkerMigrations,
+};
+pub use schedules::{
+    CronError, CronSchedule, GetWorkerSchedules, UpdateWorkerSchedules, WorkerSchedule,
+    WorkerSchedules,
+};
+pub use script_content::{parse_script_content, GetWorkerScriptContent, ScriptContentError};
 pub use script_data_structures::{
-    ListWorkersResponse, WorkerBinding, WorkerModule, WorkerObservability, WorkerPlacement,
+    AssetsHtmlHandling, AssetsNotFoundHandling, DispatchNamespaceOutbound,
+    DispatchNamespaceOutboundWorker, ListWorkersResponse, RateLimitSimple, WorkerAssets,
+    WorkerAssetsConfig, WorkerBinding, WorkerModule, WorkerObservability, WorkerPlacement,
     WorkerScript, WorkerScriptContent, WorkerScriptMetadata, WorkerScriptResponse,
     WorkerScriptUploadRequest, WorkerTailConsumer, WorkerUsageModel,
 };
+pub use script_settings::{
+    EditWorkerScriptSettings, GetWorkerScriptSettings, GetWorkerUsageModel, UpdateWorkerUsageModel,
+    WorkerLimits, WorkerScriptSettings, WorkerUsageModelSetting,
+};
 pub use send_tail_heartbeat::SendTailHeartbeat;
+pub use subdomain::{
+    AccountSubdomain, GetAccountSubdomain, GetScriptSubdomain, ScriptSubdomain,
+    ScriptSubdomainParams, UpdateAccountSubdomain, UpdateScriptSubdomain,
+};
+#[cfg(feature = "tail")]
+pub use tail_consumer::{TailError, TailEventStream, TailOptions, TailSession};
+pub use tail_events::{
+    TailEvent, TailEventInfo, TailException, TailFilters, TailHeaderFilter, TailLog, TailOutcome,
+    TailRequest, TailResponse, TailStatus,
+};
 pub use upload_script::{UploadWorkerScript, UploadWorkerScriptJson};
+pub use upload_version::UploadWorkerVersion;
+pub use version_data_structures::{
+    WorkerAnnotations, WorkerDeployment, WorkerDeploymentList, WorkerDeploymentStrategy,
+    WorkerDeploymentVersion, WorkerVersion, WorkerVersionInfo, WorkerVersionList,
+    WorkerVersionMetadata, WorkerVersionResources, WorkerVersionScript, WorkerVersionScriptRuntime,
+};
 
 /// Workers KV Route
 /// Routes are basic patterns used to enable or disable workers that match requests.
@@ -104,85 +193,17 @@ pub struct WorkersTail {
 impl ApiResult for WorkersTail {}
 impl ApiResult for Vec<WorkersTail> {}
 
-// Binding for a Workers Script
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
-#[serde(tag = "type", rename_all = "snake_case")]
-pub enum WorkersBinding {
-    Ai {
-        name: String,
-    },
-    AnalyticsEngine {
-        name: String,
-        dataset: String,
-    },
-    Assets {
-        name: String,
-    },
-    BrowserRendering {
-        name: String,
-    },
-    D1 {
-        name: String,
-        id: String,
-    },
-    DurableObjectNamespace {
-        name: String,
-        class_name: String,
-    },
-    Hyperdrive {
-        name: String,
-        id: String,
-    },
-    KvNamespace {
-        name: String,
-        namespace_id: String,
-    },
-    MtlsCertificate {
-        name: String,
-        certificate_id: String,
-    },
-    PlainText {
-        name: String,
-        text: String,
-    },
-    Queue {
-        name: String,
-        queue_name: String,
-    },
-    R2Bucket {
-        name: String,
-        bucket_name: String,
-    },
-    SecretText {
-        name: String,
-        // When fetching bindings, the text field of a Secret is not returned
-        text: Option<String>,
-    },
-    Service {
-        name: String,
-        service: String,
-        environment: String,
-    },
-    TailConsumer {
-        service: String,
-    },
-    Vectorize {
-        name: String,
-        index_name: String,
-    },
-    VersionMetadata {
-        name: String,
-    },
-}
-
-impl ApiResult for WorkersBinding {}
-impl ApiResult for Vec<WorkersBinding> {}
+/// Binding for a Workers Script
+#[deprecated(note = "merged into `WorkerBinding`, which both reads and uploads bindings")]
+pub type WorkersBinding = WorkerBinding;
 
 #[cfg(test)]
 mod tests {
     use std::collections::VecDeque;
 
-    use super::WorkersBinding;
+    use super::{
+        DispatchNamespaceOutbound, DispatchNamespaceOutboundWorker, RateLimitSimple, WorkerBinding,
+    };
 
     #[test]
     fn test_deserializing_worker_bindings() {
@@ -211,6 +232,15 @@ mod tests {
                 "name": "<VARIABLE_NAME>",
                 "id": "<D1_ID>"
               },
+              {
+                "type": "dispatch_namespace",
+                "name": "<VARIABLE_NAME>",
+                "namespace": "<NAMESPACE_NAME>",
+                "outbound": {
+                  "params": ["customer_id"],
+                  "worker": {"service": "<OUTBOUND_WORKER>"}
+                }
+              },
               {
                 "type": "durable_object_namespace",
                 "name": "<VARIABLE_NAME>",
@@ -273,129 +303,208 @@ mod tests {
             ]
         );
 
-        let result: Result<Vec<WorkersBinding>, serde_json::Error> =
-            serde_json::from_value(payload);
+        let result: Result<Vec<WorkerBinding>, serde_json::Error> = serde_json::from_value(payload);
         assert!(result.is_ok());
 
         let mut bindings = VecDeque::from(result.unwrap());
-        assert_eq!(17, bindings.len());
+        assert_eq!(18, bindings.len());
 
         assert_eq!(
             bindings.pop_front().unwrap(),
-            WorkersBinding::Ai {
+            WorkerBinding::Ai {
                 name: "<VARIABLE_NAME>".to_string()
             }
         );
         assert_eq!(
             bindings.pop_front().unwrap(),
-            WorkersBinding::AnalyticsEngine {
+            WorkerBinding::AnalyticsEngine {
                 name: "<VARIABLE_NAME>".to_string(),
                 dataset: "<DATASET>".to_string()
             }
         );
         assert_eq!(
             bindings.pop_front().unwrap(),
-            WorkersBinding::Assets {
+            WorkerBinding::Assets {
                 name: "<VARIABLE_NAME>".to_string()
             }
         );
         assert_eq!(
             bindings.pop_front().unwrap(),
-            WorkersBinding::BrowserRendering {
+            WorkerBinding::BrowserRendering {
                 name: "<VARIABLE_NAME>".to_string()
             }
         );
         assert_eq!(
             bindings.pop_front().unwrap(),
-            WorkersBinding::D1 {
+            WorkerBinding::D1 {
                 name: "<VARIABLE_NAME>".to_string(),
                 id: "<D1_ID>".to_string()
             }
         );
         assert_eq!(
             bindings.pop_front().unwrap(),
-            WorkersBinding::DurableObjectNamespace {
+            WorkerBinding::DispatchNamespace {
+                name: "<VARIABLE_NAME>".to_string(),
+                namespace: "<NAMESPACE_NAME>".to_string(),
+                outbound: Some(DispatchNamespaceOutbound {
+                    params: Some(vec!["customer_id".to_string()]),
+                    worker: Some(DispatchNamespaceOutboundWorker {
+                        service: Some("<OUTBOUND_WORKER>".to_string()),
+                        environment: None,
+                    }),
+                }),
+            }
+        );
+        assert_eq!(
+            bindings.pop_front().unwrap(),
+            WorkerBinding::DurableObjectNamespace {
                 name: "<VARIABLE_NAME>".to_string(),
-                class_name: "<DO_CLASS_NAME>".to_string()
+                class_name: "<DO_CLASS_NAME>".to_string(),
+                script_name: None,
+                environment: None,
+                namespace_id: None,
             }
         );
         assert_eq!(
             bindings.pop_front().unwrap(),
-            WorkersBinding::Hyperdrive {
+            WorkerBinding::Hyperdrive {
                 name: "<VARIABLE_NAME>".to_string(),
                 id: "<HYPERDRIVE_ID>".to_string()
             }
         );
         assert_eq!(
             bindings.pop_front().unwrap(),
-            WorkersBinding::KvNamespace {
+            WorkerBinding::KvNamespace {
                 name: "<VARIABLE_NAME>".to_string(),
                 namespace_id: "<KV_ID>".to_string()
             }
         );
         assert_eq!(
             bindings.pop_front().unwrap(),
-            WorkersBinding::MtlsCertificate {
+            WorkerBinding::MtlsCertificate {
                 name: "<VARIABLE_NAME>".to_string(),
                 certificate_id: "<MTLS_CERTIFICATE_ID>".to_string()
             }
         );
         assert_eq!(
             bindings.pop_front().unwrap(),
-            WorkersBinding::PlainText {
+            WorkerBinding::PlainText {
                 name: "<VARIABLE_NAME>".to_string(),
                 text: "<VARIABLE_VALUE>".to_string()
             }
         );
         assert_eq!(
             bindings.pop_front().unwrap(),
-            WorkersBinding::Queue {
+            WorkerBinding::Queue {
                 name: "<VARIABLE_NAME>".to_string(),
                 queue_name: "<QUEUE_NAME>".to_string()
             }
         );
         assert_eq!(
             bindings.pop_front().unwrap(),
-            WorkersBinding::R2Bucket {
+            WorkerBinding::R2Bucket {
                 name: "<VARIABLE_NAME>".to_string(),
-                bucket_name: "<R2_BUCKET_NAME>".to_string()
+                bucket_name: "<R2_BUCKET_NAME>".to_string(),
+                jurisdiction: None,
             }
         );
         assert_eq!(
             bindings.pop_front().unwrap(),
-            WorkersBinding::SecretText {
+            WorkerBinding::SecretText {
                 name: "<VARIABLE_NAME>".to_string(),
                 text: Some("<SECRET_VALUE>".to_string())
             }
         );
         assert_eq!(
             bindings.pop_front().unwrap(),
-            WorkersBinding::Service {
+            WorkerBinding::Service {
                 name: "<VARIABLE_NAME>".to_string(),
                 service: "<SERVICE_NAME>".to_string(),
-                environment: "production".to_string()
+                environment: Some("production".to_string())
             }
         );
         assert_eq!(
             bindings.pop_front().unwrap(),
-            WorkersBinding::TailConsumer {
+            WorkerBinding::TailConsumer {
                 service: "<WORKER_NAME>".to_string()
             }
         );
         assert_eq!(
             bindings.pop_front().unwrap(),
-            WorkersBinding::Vectorize {
+            WorkerBinding::Vectorize {
                 name: "<VARIABLE_NAME>".to_string(),
                 index_name: "<INDEX_NAME>".to_string()
             }
         );
         assert_eq!(
             bindings.pop_front().unwrap(),
-            WorkersBinding::VersionMetadata {
+            WorkerBinding::VersionMetadata {
                 name: "<VARIABLE_NAME>".to_string()
             }
         );
 
         assert!(bindings.is_empty());
     }
+
+    #[test]
+    fn test_worker_bindings_round_trip() {
+        let payload = serde_json::json!(
+            [
+              {
+                "type": "durable_object_namespace",
+                "name": "COUNTER",
+                "class_name": "Counter",
+                "namespace_id": "<DO_NAMESPACE_ID>"
+              },
+              {
+                "type": "ratelimit",
+                "name": "LIMITER",
+                "namespace_id": "1001",
+                "simple": {"limit": 100, "period": 60}
+              },
+              {
+                "type": "secret_text",
+                "name": "API_KEY"
+              },
+              {
+                "type": "secrets_store_secret",
+                "name": "STORED",
+                "store_id": "<STORE_ID>",
+                "secret_name": "api-key"
+              },
+              {
+                "type": "workflow",
+                "name": "ONBOARDING",
+                "workflow_name": "onboarding",
+                "class_name": "Onboarding"
+              },
+              {
+                "type": "images",
+                "name": "IMAGES"
+              },
+              {
+                "type": "some_future_binding",
+                "name": "FUTURE",
+                "setting": {"nested": [1, 2, 3]}
+              }
+            ]
+        );
+
+        let bindings: Vec<WorkerBinding> = serde_json::from_value(payload.clone()).unwrap();
+        assert_eq!(
+            bindings[1],
+            WorkerBinding::RateLimit {
+                name: "LIMITER".to_string(),
+                namespace_id: "1001".to_string(),
+                simple: RateLimitSimple {
+                    limit: 100,
+                    period: 60
+                },
+            }
+        );
+        assert_eq!(bindings[6], WorkerBinding::Unknown(payload[6].clone()));
+
+        // Bindings read from the API upload back unchanged
+        assert_eq!(serde_json::to_value(&bindings).unwrap(), payload);
+    }
 }
@@ -0,0 +1,254 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::{ApiResult, ApiSuccess};
+
+/// Get the cron triggers of a script
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/scripts/subresources/schedules/methods/get/>
+#[derive(Debug)]
+pub struct GetWorkerSchedules<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Script name
+    pub script_name: &'a str,
+}
+
+impl EndpointSpec for GetWorkerSchedules<'_> {
+    type JsonResponse = WorkerSchedules;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/scripts/{}/schedules",
+            self.account_id, self.script_name
+        )
+    }
+}
+
+/// Replace the cron triggers of a script. An empty list removes them all.
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/scripts/subresources/schedules/methods/update/>
+#[derive(Debug)]
+pub struct UpdateWorkerSchedules<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Script name
+    pub script_name: &'a str,
+    /// The new cron triggers
+    pub schedules: Vec<CronSchedule>,
+}
+
+impl EndpointSpec for UpdateWorkerSchedules<'_> {
+    type JsonResponse = WorkerSchedules;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/scripts/{}/schedules",
+            self.account_id, self.script_name
+        )
+    }
+
+    fn body(&self) -> Option<RequestBody<'_>> {
+        Some(RequestBody::Json(
+            serde_json::to_string(&self.schedules).unwrap(),
+        ))
+    }
+}
+
+/// Cron triggers of a script
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct WorkerSchedules {
+    pub schedules: Vec<WorkerSchedule>,
+}
+
+/// A cron trigger
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct WorkerSchedule {
+    /// Cron expression
+    pub cron: String,
+    pub created_on: Option<DateTime<Utc>>,
+    pub modified_on: Option<DateTime<Utc>>,
+}
+
+impl ApiResult for WorkerSchedules {}
+
+/// A cron expression checked client-side, so a typo fails before the whole schedule list is
+/// rejected by the API
+/// <https://developers.cloudflare.com/workers/configuration/cron-triggers/#supported-cron-expressions>
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    cron: String,
+}
+
+impl CronSchedule {
+    /// Parses a five-field cron expression: minute, hour, day of month, month and day of week
+    pub fn parse(expression: &str) -> Result<CronSchedule, CronError> {
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        if fields.len() != CRON_FIELDS.len() {
+            return Err(CronError::FieldCount(fields.len()));
+        }
+        for (value, field) in fields.iter().zip(CRON_FIELDS.iter()) {
+            if !field.accepts(value) {
+                return Err(CronError::InvalidField {
+                    field: field.name,
+                    value: value.to_string(),
+                });
+            }
+        }
+        Ok(CronSchedule {
+            cron: fields.join(" "),
+        })
+    }
+
+    /// The normalized expression
+    pub fn as_str(&self) -> &str {
+        &self.cron
+    }
+}
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+/// A cron expression that the API would reject
+pub enum CronError {
+    #[error("Cron expression has {0} fields, expected 5")]
+    FieldCount(usize),
+    #[error("Invalid {field} field in cron expression: {value}")]
+    InvalidField { field: &'static str, value: String },
+}
+
+struct CronField {
+    name: &'static str,
+    min: u32,
+    max: u32,
+    names: &'static [&'static str],
+    /// Allows `L`, `W` (day of month) or `L`, `#` (day of week) modifiers
+    special: Special,
+}
+
+#[derive(PartialEq)]
+enum Special {
+    None,
+    DayOfMonth,
+    DayOfWeek,
+}
+
+const CRON_FIELDS: [CronField; 5] = [
+    CronField {
+        name: "minute",
+        min: 0,
+        max: 59,
+        names: &[],
+        special: Special::None,
+    },
+    CronField {
+        name: "hour",
+        min: 0,
+        max: 23,
+        names: &[],
+        special: Special::None,
+    },
+    CronField {
+        name: "day of month",
+        min: 1,
+        max: 31,
+        names: &[],
+        special: Special::DayOfMonth,
+    },
+    CronField {
+        name: "month",
+        min: 1,
+        max: 12,
+        names: &[
+            "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+        ],
+        special: Special::None,
+    },
+    CronField {
+        name: "day of week",
+        min: 0,
+        max: 7,
+        names: &["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"],
+        special: Special::DayOfWeek,
+    },
+];
+
+impl CronField {
+    fn accepts(&self, value: &str) -> bool {
+        value.split(',').all(|item| self.accepts_item(item))
+    }
+
+    fn accepts_item(&self, item: &str) -> bool {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => (range, Some(step)),
+            None => (item, None),
+        };
+        if let Some(step) = step {
+            if !matches!(step.parse::<u32>(), Ok(s) if s >= 1 && s <= self.max) {
+                return false;
+            }
+        }
+        if range == "*" {
+            return true;
+        }
+        if let Some((start, end)) = range.split_once('-') {
+            return match (self.value(start), self.value(end)) {
+                (Some(start), Some(end)) => start <= end,
+                _ => false,
+            };
+        }
+        if step.is_none() && self.accepts_special(range) {
+            return true;
+        }
+        self.value(range).is_some()
+    }
+
+    fn accepts_special(&self, value: &str) -> bool {
+        match self.special {
+            Special::None => false,
+            Special::DayOfMonth => {
+                value == "L"
+                    || value == "LW"
+                    || value
+                        .strip_suffix('W')
+                        .is_some_and(|day| self.value(day).is_some())
+            }
+            Special::DayOfWeek => {
+                if let Some(day) = value.strip_suffix('L') {
+                    return self.value(day).is_some();
+                }
+                match value.split_once('#') {
+                    Some((day, nth)) => {
+                        self.value(day).is_some()
+                            && matches!(nth.parse::<u32>(), Ok(n) if (1..=5).contains(&n))
+                    }
+                    None => false,
+                }
+            }
+        }
+    }
+
+    fn value(&self, value: &str) -> Option<u32> {
+        // Month names start at 1 (JAN), day names at 0 (SUN)
+        let first_name = if self.special == Special::DayOfWeek {
+            0
+        } else {
+            1
+        };
+        let number = match value.parse::<u32>() {
+            Ok(number) => number,
+            Err(_) => {
+                let upper = value.to_ascii_uppercase();
+                self.names.iter().position(|name| *name == upper)? as u32 + first_name
+            }
+        };
+        (self.min..=self.max).contains(&number).then_some(number)
+    }
+}
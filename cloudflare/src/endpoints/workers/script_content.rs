@@ -0,0 +1,111 @@
+use crate::framework::endpoint::{EndpointSpec, Method};
+
+use super::script_data_structures::WorkerModule;
+
+/// Download the deployed content of a Workers script. The body is `multipart/form-data` with one
+/// part per module; split it with [`parse_script_content`].
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/scripts/subresources/content/methods/get/>
+#[derive(Debug)]
+pub struct GetWorkerScriptContent<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Script name
+    pub script_name: &'a str,
+}
+
+impl EndpointSpec for GetWorkerScriptContent<'_> {
+    const IS_RAW_BODY: bool = true;
+
+    type JsonResponse = ();
+    type ResponseType = Vec<u8>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/scripts/{}/content/v2",
+            self.account_id, self.script_name
+        )
+    }
+}
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+/// A script content response that could not be split into modules
+pub enum ScriptContentError {
+    #[error("Script content is not multipart: it does not start with a boundary")]
+    MissingBoundary,
+    #[error("Multipart part {0} has no headers/body separator")]
+    MalformedPart(usize),
+    #[error("Multipart part {0} has no name")]
+    UnnamedPart(usize),
+    #[error("Multipart body is missing its closing boundary")]
+    Unterminated,
+}
+
+/// Splits a downloaded script into its modules, in the order they were uploaded.
+///
+/// The boundary is read from the first line of the body, which always holds it, rather than
+/// from the `Content-Type` header, so that only the body is needed, e.g. one saved to disk.
+pub fn parse_script_content(body: &[u8]) -> Result<Vec<WorkerModule>, ScriptContentError> {
+    let first_line_end = find(body, b"\r\n").ok_or(ScriptContentError::MissingBoundary)?;
+    let first_line = &body[..first_line_end];
+    if !first_line.starts_with(b"--") || first_line.len() <= 2 {
+        return Err(ScriptContentError::MissingBoundary);
+    }
+    let delimiter = [b"\r\n", first_line].concat();
+
+    let mut modules = Vec::new();
+    let mut rest = &body[first_line_end..];
+    loop {
+        // `rest` starts right after a boundary: either `--` (closing) or CRLF and a part
+        if rest.starts_with(b"--") {
+            return Ok(modules);
+        }
+        let part_start = rest
+            .strip_prefix(b"\r\n")
+            .ok_or(ScriptContentError::Unterminated)?;
+        let part_end = find(part_start, &delimiter).ok_or(ScriptContentError::Unterminated)?;
+        modules.push(parse_part(&part_start[..part_end], modules.len())?);
+        rest = &part_start[part_end + delimiter.len()..];
+    }
+}
+
+fn parse_part(part: &[u8], index: usize) -> Result<WorkerModule, ScriptContentError> {
+    let headers_end = find(part, b"\r\n\r\n").ok_or(ScriptContentError::MalformedPart(index))?;
+    let headers = String::from_utf8_lossy(&part[..headers_end]);
+
+    let mut name = None;
+    let mut content_type = None;
+    for line in headers.split("\r\n") {
+        let Some((header, value)) = line.split_once(':') else {
+            continue;
+        };
+        if header.trim().eq_ignore_ascii_case("content-disposition") {
+            name = disposition_param(value, "name");
+        } else if header.trim().eq_ignore_ascii_case("content-type") {
+            content_type = Some(value.trim().to_string());
+        }
+    }
+
+    Ok(WorkerModule {
+        name: name.ok_or(ScriptContentError::UnnamedPart(index))?,
+        content: part[headers_end + 4..].to_vec(),
+        content_type: content_type.unwrap_or_else(|| "application/octet-stream".to_string()),
+    })
+}
+
+/// Value of a `Content-Disposition` parameter, e.g. `name` in `form-data; name="worker.js"`
+fn disposition_param(disposition: &str, param: &str) -> Option<String> {
+    disposition.split(';').skip(1).find_map(|pair| {
+        let (key, value) = pair.split_once('=')?;
+        (key.trim() == param).then(|| value.trim().trim_matches('"').to_string())
+    })
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
@@ -4,6 +4,8 @@ use serde_with::skip_serializing_none;
 
 use crate::framework::response::ApiResult;
 
+use super::migrations::WorkerMigrations;
+
 /// A Workers script representation
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
@@ -36,6 +38,8 @@ pub struct WorkerScript {
     pub logpush: Option<bool>,
     /// Tail consumer settings
     pub tail_consumers: Option<Vec<WorkerTailConsumer>>,
+    /// Tag of the last Durable Object migration applied to the script
+    pub migration_tag: Option<String>,
 }
 
 /// Workers usage model
@@ -70,7 +74,7 @@ pub struct WorkerTailConsumer {
 
 /// Worker script metadata for uploads
 #[skip_serializing_none]
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Debug, Clone, Default)]
 pub struct WorkerScriptMetadata {
     /// Main module name (entry point)
     pub main_module: String,
@@ -90,73 +94,249 @@ pub struct WorkerScriptMetadata {
     pub keep_bindings_on_error: Option<bool>,
     /// Observability configuration
     pub observability: Option<WorkerObservability>,
+    /// Static assets uploaded for the script
+    pub assets: Option<WorkerAssets>,
+    /// Script tags, used to filter and bulk delete scripts in a dispatch namespace
+    pub tags: Option<Vec<String>>,
+    /// Durable Object migrations to apply with this upload
+    pub migrations: Option<WorkerMigrations>,
 }
 
-/// Worker observability settings
+/// Static assets attached to a script upload
 #[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct WorkerAssets {
+    /// Completion token returned by the assets upload session
+    pub jwt: Option<String>,
+    /// How assets are served
+    pub config: Option<WorkerAssetsConfig>,
+}
+
+/// How static assets are served
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorkerAssetsConfig {
+    /// How trailing slashes and `.html` extensions are handled
+    pub html_handling: Option<AssetsHtmlHandling>,
+    /// What is served when no asset matches the request
+    pub not_found_handling: Option<AssetsNotFoundHandling>,
+    /// Run the Worker before serving matching assets
+    pub run_worker_first: Option<bool>,
+}
+
+/// How trailing slashes and `.html` extensions are handled when serving assets
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum AssetsHtmlHandling {
+    AutoTrailingSlash,
+    ForceTrailingSlash,
+    DropTrailingSlash,
+    None,
+}
+
+/// What is served when no asset matches the request
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum AssetsNotFoundHandling {
+    /// Serve the nearest `404.html`
+    #[serde(rename = "404-page")]
+    NotFoundPage,
+    /// Serve `/index.html`
+    SinglePageApplication,
+    /// Fall through to the Worker, or respond with a 404
+    None,
+}
+
+/// Worker observability settings
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
 pub struct WorkerObservability {
+    /// Whether Workers Logs are collected
+    pub enabled: Option<bool>,
+    /// Fraction of requests to log, between 0 and 1
+    pub head_sampling_rate: Option<f64>,
     /// Logpush enabled
     pub logpush: Option<bool>,
 }
 
-/// Worker binding types for configuration
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// A binding between a Worker and a resource, as uploaded in script metadata and returned by
+/// [`super::ListBindings`]. Binding types this crate does not know yet are kept as
+/// [`WorkerBinding::Unknown`], so bindings read from the API can be uploaded back unchanged.
+/// <https://developers.cloudflare.com/workers/configuration/multipart-upload-metadata/#bindings>
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum WorkerBinding {
-    /// Plain text binding
-    PlainText { name: String, text: String },
-    /// JSON binding  
-    Json {
+    /// Workers AI binding
+    Ai { name: String },
+    /// Analytics Engine binding
+    AnalyticsEngine { name: String, dataset: String },
+    /// Static assets binding
+    Assets { name: String },
+    /// Browser Rendering binding
+    BrowserRendering { name: String },
+    /// D1 database binding
+    D1 { name: String, id: String },
+    /// Data blob binding, referencing a multipart upload part
+    DataBlob { name: String, part: String },
+    /// Workers for Platforms dispatch namespace binding
+    DispatchNamespace {
         name: String,
-        json: serde_json::Value,
+        namespace: String,
+        outbound: Option<DispatchNamespaceOutbound>,
     },
-    /// Environment variable binding
-    Environment { name: String, text: String },
-    /// Secret text binding
-    SecretText { name: String, text: String },
-    /// WebAssembly module binding
-    WasmModule { name: String, part: String },
-    /// Data blob binding
-    DataBlob { name: String, part: String },
-    /// KV namespace binding
-    KvNamespace { name: String, namespace_id: String },
     /// Durable Object namespace binding
     DurableObjectNamespace {
         name: String,
         class_name: String,
         script_name: Option<String>,
         environment: Option<String>,
+        namespace_id: Option<String>,
+    },
+    /// Hyperdrive configuration binding
+    Hyperdrive { name: String, id: String },
+    /// Cloudflare Images binding
+    Images { name: String },
+    /// Keeps a binding of the previous version, e.g. a secret whose value is not re-sent
+    Inherit {
+        name: String,
+        old_name: Option<String>,
+        version_id: Option<String>,
+    },
+    /// JSON binding
+    Json {
+        name: String,
+        json: serde_json::Value,
+    },
+    /// KV namespace binding
+    KvNamespace { name: String, namespace_id: String },
+    /// Logfwdr binding
+    Logfwdr { name: String, destination: String },
+    /// mTLS certificate binding
+    MtlsCertificate {
+        name: String,
+        certificate_id: String,
     },
+    /// Plain text binding
+    PlainText { name: String, text: String },
+    /// Queue producer binding
+    Queue { name: String, queue_name: String },
     /// R2 bucket binding
     R2Bucket {
         name: String,
         bucket_name: String,
         jurisdiction: Option<String>,
     },
-    /// Queue binding
-    Queue { name: String, queue_name: String },
-    /// D1 database binding
-    D1Database { name: String, database_id: String },
+    /// Rate limiting binding
+    #[serde(rename = "ratelimit")]
+    RateLimit {
+        name: String,
+        namespace_id: String,
+        simple: RateLimitSimple,
+    },
+    /// Secret text binding. The text is not returned when bindings are read.
+    SecretText { name: String, text: Option<String> },
+    /// Secrets Store secret binding
+    SecretsStoreSecret {
+        name: String,
+        store_id: String,
+        secret_name: String,
+    },
     /// Service binding
     Service {
         name: String,
         service: String,
         environment: Option<String>,
     },
-    /// Analytics Engine binding
-    AnalyticsEngine { name: String, dataset: String },
-    /// mTLS certificate binding
-    MtlsCertificate {
-        name: String,
-        certificate_id: String,
-    },
-    /// Logfwdr binding
-    Logfwdr { name: String, destination: String },
-    /// AI binding
-    Ai { name: String },
+    /// Tail consumer
+    TailConsumer { service: String },
+    /// Text blob binding, referencing a multipart upload part
+    TextBlob { name: String, part: String },
+    /// Vectorize index binding
+    Vectorize { name: String, index_name: String },
     /// Version metadata binding
     VersionMetadata { name: String },
+    /// WebAssembly module binding, referencing a multipart upload part
+    WasmModule { name: String, part: String },
+    /// Workflow binding
+    Workflow {
+        name: String,
+        workflow_name: String,
+        class_name: Option<String>,
+        script_name: Option<String>,
+    },
+    /// A binding type this crate does not model, kept as returned by the API
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+/// Limit of a rate limiting binding
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitSimple {
+    /// Requests allowed per period
+    pub limit: u32,
+    /// Period in seconds, either 10 or 60
+    pub period: u32,
+}
+
+impl WorkerBinding {
+    /// Name the binding is exposed under in the Worker, if it has one
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            WorkerBinding::Ai { name }
+            | WorkerBinding::AnalyticsEngine { name, .. }
+            | WorkerBinding::Assets { name }
+            | WorkerBinding::BrowserRendering { name }
+            | WorkerBinding::D1 { name, .. }
+            | WorkerBinding::DataBlob { name, .. }
+            | WorkerBinding::DispatchNamespace { name, .. }
+            | WorkerBinding::DurableObjectNamespace { name, .. }
+            | WorkerBinding::Hyperdrive { name, .. }
+            | WorkerBinding::Images { name }
+            | WorkerBinding::Inherit { name, .. }
+            | WorkerBinding::Json { name, .. }
+            | WorkerBinding::KvNamespace { name, .. }
+            | WorkerBinding::Logfwdr { name, .. }
+            | WorkerBinding::MtlsCertificate { name, .. }
+            | WorkerBinding::PlainText { name, .. }
+            | WorkerBinding::Queue { name, .. }
+            | WorkerBinding::R2Bucket { name, .. }
+            | WorkerBinding::RateLimit { name, .. }
+            | WorkerBinding::SecretText { name, .. }
+            | WorkerBinding::SecretsStoreSecret { name, .. }
+            | WorkerBinding::Service { name, .. }
+            | WorkerBinding::TextBlob { name, .. }
+            | WorkerBinding::Vectorize { name, .. }
+            | WorkerBinding::VersionMetadata { name }
+            | WorkerBinding::WasmModule { name, .. }
+            | WorkerBinding::Workflow { name, .. } => Some(name),
+            WorkerBinding::TailConsumer { .. } => None,
+            WorkerBinding::Unknown(value) => value.get("name")?.as_str(),
+        }
+    }
+}
+
+impl ApiResult for WorkerBinding {}
+impl ApiResult for Vec<WorkerBinding> {}
+
+/// Outbound Worker that handles `fetch()` calls made by scripts in a dispatch namespace
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct DispatchNamespaceOutbound {
+    /// Names of the parameters passed from the dispatcher to the outbound Worker
+    pub params: Option<Vec<String>>,
+    /// The outbound Worker
+    pub worker: Option<DispatchNamespaceOutboundWorker>,
+}
+
+/// Outbound Worker script
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct DispatchNamespaceOutboundWorker {
+    /// Outbound Worker script name
+    pub service: Option<String>,
+    /// Outbound Worker environment
+    pub environment: Option<String>,
 }
 
 /// Worker script content for multipart uploads
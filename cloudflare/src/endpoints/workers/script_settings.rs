@@ -0,0 +1,177 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::framework::endpoint::{EndpointSpec, Method, MultipartBody, MultipartPart, RequestBody};
+use crate::framework::response::{ApiResult, ApiSuccess};
+
+use super::script_data_structures::{
+    WorkerBinding, WorkerObservability, WorkerPlacement, WorkerTailConsumer, WorkerUsageModel,
+};
+
+/// Get the settings of a Workers script
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/scripts/subresources/settings/methods/get/>
+#[derive(Debug)]
+pub struct GetWorkerScriptSettings<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Script name
+    pub script_name: &'a str,
+}
+
+impl EndpointSpec for GetWorkerScriptSettings<'_> {
+    type JsonResponse = WorkerScriptSettings;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/scripts/{}/settings",
+            self.account_id, self.script_name
+        )
+    }
+}
+
+/// Patch the settings of a Workers script without re-uploading its code. Only the fields that
+/// are set are changed.
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/scripts/subresources/settings/methods/edit/>
+#[derive(Debug)]
+pub struct EditWorkerScriptSettings<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Script name
+    pub script_name: &'a str,
+    /// Settings to change
+    pub settings: WorkerScriptSettings,
+}
+
+impl EndpointSpec for EditWorkerScriptSettings<'_> {
+    type JsonResponse = WorkerScriptSettings;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/scripts/{}/settings",
+            self.account_id, self.script_name
+        )
+    }
+
+    fn body(&self) -> Option<RequestBody<'_>> {
+        Some(RequestBody::MultiPart(self))
+    }
+}
+
+impl MultipartBody for EditWorkerScriptSettings<'_> {
+    fn parts(&self) -> Vec<(String, MultipartPart)> {
+        let settings = serde_json::to_string(&self.settings).unwrap();
+        vec![("settings".to_string(), MultipartPart::Text(settings))]
+    }
+}
+
+/// Settings of a Workers script
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct WorkerScriptSettings {
+    /// Worker bindings. Bindings left out of a patch are removed.
+    pub bindings: Option<Vec<WorkerBinding>>,
+    /// Compatibility date
+    pub compatibility_date: Option<String>,
+    /// Compatibility flags
+    pub compatibility_flags: Option<Vec<String>>,
+    /// Resource limits
+    pub limits: Option<WorkerLimits>,
+    /// Whether Logpush is enabled
+    pub logpush: Option<bool>,
+    /// Observability configuration
+    pub observability: Option<WorkerObservability>,
+    /// Script placement
+    pub placement: Option<WorkerPlacement>,
+    /// Tail consumers
+    pub tail_consumers: Option<Vec<WorkerTailConsumer>>,
+    /// Usage model
+    pub usage_model: Option<WorkerUsageModel>,
+}
+
+/// Resource limits of a Workers script
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorkerLimits {
+    /// Maximum CPU time per invocation, in milliseconds
+    pub cpu_ms: Option<u32>,
+}
+
+impl ApiResult for WorkerScriptSettings {}
+
+/// Get the usage model of a Workers script
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/scripts/subresources/usage_model/methods/get/>
+#[derive(Debug)]
+pub struct GetWorkerUsageModel<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Script name
+    pub script_name: &'a str,
+}
+
+impl EndpointSpec for GetWorkerUsageModel<'_> {
+    type JsonResponse = WorkerUsageModelSetting;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/scripts/{}/usage-model",
+            self.account_id, self.script_name
+        )
+    }
+}
+
+/// Change the usage model of a Workers script
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/scripts/subresources/usage_model/methods/update/>
+#[derive(Debug)]
+pub struct UpdateWorkerUsageModel<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Script name
+    pub script_name: &'a str,
+    /// The new usage model
+    pub params: WorkerUsageModelSetting,
+}
+
+impl EndpointSpec for UpdateWorkerUsageModel<'_> {
+    type JsonResponse = WorkerUsageModelSetting;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/scripts/{}/usage-model",
+            self.account_id, self.script_name
+        )
+    }
+
+    fn body(&self) -> Option<RequestBody<'_>> {
+        Some(RequestBody::Json(
+            serde_json::to_string(&self.params).unwrap(),
+        ))
+    }
+}
+
+/// Usage model of a Workers script
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct WorkerUsageModelSetting {
+    pub usage_model: WorkerUsageModel,
+}
+
+impl ApiResult for WorkerUsageModelSetting {}
@@ -41,6 +41,7 @@ mod tests {
                 environment: Some("prod".to_string()),
                 namespace: Some("default".to_string()),
             }]),
+            migration_tag: Some("v2".to_string()),
         };
 
         let json = serde_json::to_string(&worker).unwrap();
@@ -69,6 +70,7 @@ mod tests {
             placement: None,
             logpush: None,
             tail_consumers: None,
+            migration_tag: None,
         };
 
         let json = serde_json::to_string(&worker).unwrap();
@@ -121,9 +123,9 @@ mod tests {
                 bucket_name: "storage".to_string(),
                 jurisdiction: Some("eu".to_string()),
             },
-            WorkerBinding::D1Database {
+            WorkerBinding::D1 {
                 name: "MY_DB".to_string(),
-                database_id: "db-456".to_string(),
+                id: "db-456".to_string(),
             },
         ];
 
@@ -169,7 +171,11 @@ mod tests {
             keep_bindings_on_error: Some(true),
             observability: Some(WorkerObservability {
                 logpush: Some(true),
+                ..Default::default()
             }),
+            assets: None,
+            tags: None,
+            migrations: None,
         };
 
         let json = serde_json::to_string(&metadata).unwrap();
@@ -219,6 +225,9 @@ mod tests {
             tail_consumers: None,
             keep_bindings_on_error: None,
             observability: None,
+            assets: None,
+            tags: None,
+            migrations: None,
         };
 
         let script_content = WorkerScriptContent {
@@ -263,6 +272,9 @@ mod tests {
             tail_consumers: None,
             keep_bindings_on_error: None,
             observability: None,
+            assets: None,
+            tags: None,
+            migrations: None,
         };
 
         let script_content = WorkerScriptContent {
@@ -305,6 +317,9 @@ mod tests {
             tail_consumers: None,
             keep_bindings_on_error: None,
             observability: None,
+            assets: None,
+            tags: None,
+            migrations: None,
         };
 
         let upload_request = UploadWorkerScriptJson {
@@ -343,13 +358,13 @@ mod tests {
                 name: "JSON".to_string(),
                 json: serde_json::json!({}),
             },
-            WorkerBinding::Environment {
-                name: "ENV".to_string(),
-                text: "prod".to_string(),
+            WorkerBinding::Hyperdrive {
+                name: "HYPERDRIVE".to_string(),
+                id: "hd123".to_string(),
             },
             WorkerBinding::SecretText {
                 name: "SECRET".to_string(),
-                text: "hidden".to_string(),
+                text: Some("hidden".to_string()),
             },
             WorkerBinding::WasmModule {
                 name: "WASM".to_string(),
@@ -368,6 +383,7 @@ mod tests {
                 class_name: "Counter".to_string(),
                 script_name: None,
                 environment: None,
+                namespace_id: None,
             },
             WorkerBinding::R2Bucket {
                 name: "BUCKET".to_string(),
@@ -378,9 +394,9 @@ mod tests {
                 name: "QUEUE".to_string(),
                 queue_name: "tasks".to_string(),
             },
-            WorkerBinding::D1Database {
+            WorkerBinding::D1 {
                 name: "DB".to_string(),
-                database_id: "db123".to_string(),
+                id: "db123".to_string(),
             },
             WorkerBinding::Service {
                 name: "SERVICE".to_string(),
@@ -0,0 +1,266 @@
+/*!
+Keeps the secrets of a Worker in sync with a desired set, e.g. one loaded from a `.dev.vars`
+or dotenv file with [`read_dotenv`].
+
+[`SecretsSync::plan`] compares the desired names with the secrets the script has and reports
+what would change, without changing anything. [`SecretsSync::apply`] then uploads every change in
+one [`BulkUpdateSecrets`] request, so the script restarts once.
+ */
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use super::bulk_secrets::BulkUpdateSecrets;
+use super::list_secrets::ListSecrets;
+use super::script_settings::GetWorkerScriptSettings;
+use crate::framework::client::async_api::Client;
+use crate::framework::response::ApiFailure;
+
+#[derive(thiserror::Error, Debug)]
+/// Errors encountered while syncing secrets
+pub enum SecretsError {
+    #[error("Failed to read {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Invalid line {line} in dotenv file: expected KEY=VALUE")]
+    InvalidLine { line: usize },
+    #[error("Unterminated quoted value on line {line} of dotenv file")]
+    UnterminatedQuote { line: usize },
+    #[error("Cloudflare API request failed: {0}")]
+    Api(#[from] ApiFailure),
+}
+
+/// Parses dotenv syntax: `KEY=VALUE` lines, optionally prefixed with `export`. Blank lines and
+/// `#` comments are skipped. Values may be single-quoted (taken literally) or double-quoted
+/// (`\n`, `\"` and `\\` escapes, may span lines). Later keys override earlier ones.
+pub fn parse_dotenv(contents: &str) -> Result<BTreeMap<String, String>, SecretsError> {
+    let mut secrets = BTreeMap::new();
+    let mut lines = contents.lines().enumerate();
+    while let Some((index, line)) = lines.next() {
+        let line_number = index + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        let (key, value) = line
+            .split_once('=')
+            .ok_or(SecretsError::InvalidLine { line: line_number })?;
+        let key = key.trim();
+        if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(SecretsError::InvalidLine { line: line_number });
+        }
+
+        let value = value.trim_start();
+        let value = if let Some(quoted) = value.strip_prefix('"') {
+            let mut raw = quoted.to_string();
+            let end = loop {
+                if let Some(end) = closing_quote(&raw) {
+                    break end;
+                }
+                let (_, next) = lines
+                    .next()
+                    .ok_or(SecretsError::UnterminatedQuote { line: line_number })?;
+                raw.push('\n');
+                raw.push_str(next);
+            };
+            unescape(&raw[..end])
+        } else if let Some(quoted) = value.strip_prefix('\'') {
+            quoted
+                .trim_end()
+                .strip_suffix('\'')
+                .ok_or(SecretsError::UnterminatedQuote { line: line_number })?
+                .to_string()
+        } else {
+            // Unquoted values end at an inline comment
+            match value.find(" #") {
+                Some(comment) => value[..comment].trim_end().to_string(),
+                None => value.trim_end().to_string(),
+            }
+        };
+        secrets.insert(key.to_string(), value);
+    }
+    Ok(secrets)
+}
+
+/// Reads and parses a dotenv file, see [`parse_dotenv`]
+pub fn read_dotenv(path: impl AsRef<Path>) -> Result<BTreeMap<String, String>, SecretsError> {
+    let path = path.as_ref();
+    let contents = std::fs::read_to_string(path).map_err(|source| SecretsError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    parse_dotenv(&contents)
+}
+
+/// Byte offset of the first unescaped `"` in a double-quoted value
+fn closing_quote(raw: &str) -> Option<usize> {
+    let mut escaped = false;
+    for (offset, c) in raw.char_indices() {
+        match c {
+            '"' if !escaped => return Some(offset),
+            '\\' => escaped = !escaped,
+            _ => escaped = false,
+        }
+    }
+    None
+}
+
+fn unescape(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some('t') => unescaped.push('\t'),
+            Some(other) => unescaped.push(other),
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+/// Changes a sync makes, by secret name. Secret values cannot be read back, so every desired
+/// secret that already exists is overwritten.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SecretsPlan {
+    /// Secrets the script does not have yet
+    pub create: Vec<String>,
+    /// Existing secrets that are overwritten
+    pub update: Vec<String>,
+    /// Secrets that are not desired and are removed
+    pub delete: Vec<String>,
+}
+
+impl SecretsPlan {
+    /// Whether the sync changes nothing
+    pub fn is_empty(&self) -> bool {
+        self.create.is_empty() && self.update.is_empty() && self.delete.is_empty()
+    }
+}
+
+/// Dry-run output: one line per secret, prefixed with `+` (create), `~` (update) or `-` (delete)
+impl fmt::Display for SecretsPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No secret changes");
+        }
+        for name in &self.create {
+            writeln!(f, "+ {name}")?;
+        }
+        for name in &self.update {
+            writeln!(f, "~ {name}")?;
+        }
+        for name in &self.delete {
+            writeln!(f, "- {name}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Syncs the secrets of a script
+pub struct SecretsSync<'a> {
+    client: &'a Client,
+    account_id: &'a str,
+    script_name: &'a str,
+    delete_extras: bool,
+}
+
+impl<'a> SecretsSync<'a> {
+    pub fn new(client: &'a Client, account_id: &'a str, script_name: &'a str) -> Self {
+        SecretsSync {
+            client,
+            account_id,
+            script_name,
+            delete_extras: false,
+        }
+    }
+
+    /// Also deletes secrets that are not in the desired set. Off by default.
+    pub fn delete_extras(mut self, delete_extras: bool) -> Self {
+        self.delete_extras = delete_extras;
+        self
+    }
+
+    /// Works out the changes without making them, for a dry run
+    pub async fn plan(
+        &self,
+        desired: &BTreeMap<String, String>,
+    ) -> Result<SecretsPlan, SecretsError> {
+        let current: BTreeSet<String> = self
+            .client
+            .request(&ListSecrets {
+                account_identifier: self.account_id,
+                script_name: self.script_name,
+            })
+            .await?
+            .result
+            .into_iter()
+            .map(|secret| secret.name)
+            .collect();
+
+        let (update, create) = desired
+            .keys()
+            .cloned()
+            .partition(|name| current.contains(name));
+        let delete = if self.delete_extras {
+            current
+                .into_iter()
+                .filter(|name| !desired.contains_key(name))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        Ok(SecretsPlan {
+            create,
+            update,
+            delete,
+        })
+    }
+
+    /// Makes the changes in a single new version and returns what was changed
+    pub async fn apply(
+        &self,
+        desired: &BTreeMap<String, String>,
+    ) -> Result<SecretsPlan, SecretsError> {
+        let plan = self.plan(desired).await?;
+        if plan.is_empty() {
+            return Ok(plan);
+        }
+
+        // Every binding that is neither set nor deleted is kept as it is
+        let settings = self
+            .client
+            .request(&GetWorkerScriptSettings {
+                account_id: self.account_id,
+                script_name: self.script_name,
+            })
+            .await?
+            .result;
+        let inherit = settings
+            .bindings
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|binding| binding.name())
+            .filter(|name| !plan.delete.iter().any(|deleted| deleted == name))
+            .map(str::to_string)
+            .collect();
+
+        self.client
+            .request(&BulkUpdateSecrets {
+                account_identifier: self.account_id,
+                script_name: self.script_name,
+                secrets: desired.clone(),
+                inherit,
+            })
+            .await?;
+        Ok(plan)
+    }
+}
@@ -0,0 +1,208 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    use super::super::secrets::{parse_dotenv, SecretsError, SecretsPlan, SecretsSync};
+    use super::super::{BulkUpdateSecrets, WorkerBinding};
+    use crate::framework::auth::Credentials;
+    use crate::framework::client::async_api::Client;
+    use crate::framework::client::ClientConfig;
+    use crate::framework::endpoint::{EndpointSpec, Method, MultipartPart, RequestBody};
+    use crate::framework::Environment;
+
+    fn secrets(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_dotenv() {
+        let parsed = parse_dotenv(
+            r#"
+# Local development secrets
+API_KEY=abc123
+export DB_URL = postgres://localhost/db # inline comment
+EMPTY=
+SINGLE='literal \n $value'
+DOUBLE="line one\nsays \"hi\"" # trailing comment
+MULTILINE="-----BEGIN KEY-----
+abc
+-----END KEY-----"
+API_KEY=overridden
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            parsed,
+            secrets(&[
+                ("API_KEY", "overridden"),
+                ("DB_URL", "postgres://localhost/db"),
+                ("EMPTY", ""),
+                ("SINGLE", "literal \\n $value"),
+                ("DOUBLE", "line one\nsays \"hi\""),
+                ("MULTILINE", "-----BEGIN KEY-----\nabc\n-----END KEY-----"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_dotenv_errors() {
+        assert!(matches!(
+            parse_dotenv("OK=1\nnot a pair"),
+            Err(SecretsError::InvalidLine { line: 2 })
+        ));
+        assert!(matches!(
+            parse_dotenv("BAD-KEY=1"),
+            Err(SecretsError::InvalidLine { line: 1 })
+        ));
+        assert!(matches!(
+            parse_dotenv("KEY=\"never closed\nOTHER=1"),
+            Err(SecretsError::UnterminatedQuote { line: 1 })
+        ));
+    }
+
+    #[test]
+    fn test_secrets_plan_display() {
+        let plan = SecretsPlan {
+            create: vec!["NEW".to_string()],
+            update: vec!["ROTATED".to_string()],
+            delete: vec!["OLD".to_string()],
+        };
+        assert_eq!(plan.to_string(), "+ NEW\n~ ROTATED\n- OLD\n");
+        assert_eq!(SecretsPlan::default().to_string(), "No secret changes\n");
+    }
+
+    #[test]
+    fn test_bulk_update_secrets() {
+        let endpoint = BulkUpdateSecrets {
+            account_identifier: "account",
+            script_name: "my-worker",
+            secrets: secrets(&[("API_KEY", "abc"), ("DB_URL", "postgres://")]),
+            inherit: vec!["KV".to_string(), "API_KEY".to_string()],
+        };
+        assert_eq!(endpoint.method(), Method::PATCH);
+        assert_eq!(
+            endpoint.path(),
+            "accounts/account/workers/scripts/my-worker/settings"
+        );
+
+        // A secret being set is not also inherited
+        assert_eq!(
+            endpoint.bindings(),
+            vec![
+                WorkerBinding::Inherit {
+                    name: "KV".to_string(),
+                    old_name: None,
+                    version_id: None,
+                },
+                WorkerBinding::SecretText {
+                    name: "API_KEY".to_string(),
+                    text: Some("abc".to_string()),
+                },
+                WorkerBinding::SecretText {
+                    name: "DB_URL".to_string(),
+                    text: Some("postgres://".to_string()),
+                },
+            ]
+        );
+
+        let Some(RequestBody::MultiPart(multipart)) = endpoint.body() else {
+            panic!("Expected multipart body");
+        };
+        let parts = multipart.parts();
+        let MultipartPart::Text(settings) = &parts[0].1 else {
+            panic!("Expected text part");
+        };
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(settings).unwrap(),
+            json!({"bindings": [
+                {"type": "inherit", "name": "KV"},
+                {"type": "secret_text", "name": "API_KEY", "text": "abc"},
+                {"type": "secret_text", "name": "DB_URL", "text": "postgres://"}
+            ]})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_secrets_sync() {
+        let mut server = Server::new_async().await;
+        let list = server
+            .mock("GET", "/accounts/account/workers/scripts/my-worker/secrets")
+            .with_body(
+                json!({
+                    "result": [
+                        {"name": "API_KEY", "type": "secret_text"},
+                        {"name": "STALE", "type": "secret_text"}
+                    ],
+                    "success": true, "errors": [], "messages": []
+                })
+                .to_string(),
+            )
+            .expect(2)
+            .create();
+        let settings = server
+            .mock(
+                "GET",
+                "/accounts/account/workers/scripts/my-worker/settings",
+            )
+            .with_body(
+                json!({
+                    "result": {"bindings": [
+                        {"type": "kv_namespace", "name": "KV", "namespace_id": "kv"},
+                        {"type": "secret_text", "name": "API_KEY"},
+                        {"type": "secret_text", "name": "STALE"}
+                    ]},
+                    "success": true, "errors": [], "messages": []
+                })
+                .to_string(),
+            )
+            .create();
+        let patch = server
+            .mock(
+                "PATCH",
+                "/accounts/account/workers/scripts/my-worker/settings",
+            )
+            .match_body(Matcher::AllOf(vec![
+                Matcher::Regex(r#""type":"inherit","name":"KV""#.to_string()),
+                Matcher::Regex(r#""name":"NEW","text":"2""#.to_string()),
+                Matcher::Regex(r#""name":"API_KEY","text":"1""#.to_string()),
+            ]))
+            .with_body(
+                json!({
+                    "result": {},
+                    "success": true, "errors": [], "messages": []
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new(
+            Credentials::UserAuthToken {
+                token: "api-token".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap();
+        let desired = secrets(&[("API_KEY", "1"), ("NEW", "2")]);
+        let sync = SecretsSync::new(&client, "account", "my-worker").delete_extras(true);
+
+        let expected = SecretsPlan {
+            create: vec!["NEW".to_string()],
+            update: vec!["API_KEY".to_string()],
+            delete: vec!["STALE".to_string()],
+        };
+        assert_eq!(sync.plan(&desired).await.unwrap(), expected);
+        assert_eq!(sync.apply(&desired).await.unwrap(), expected);
+
+        list.assert();
+        settings.assert();
+        patch.assert();
+    }
+}
@@ -0,0 +1,154 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{
+        parse_script_content, EditWorkerScriptSettings, GetWorkerScriptContent, ScriptContentError,
+        UpdateWorkerUsageModel, WorkerObservability, WorkerScriptSettings, WorkerTailConsumer,
+        WorkerUsageModel, WorkerUsageModelSetting,
+    };
+    use crate::framework::endpoint::{EndpointSpec, Method, MultipartPart, RequestBody};
+
+    #[test]
+    fn test_get_script_content() {
+        let endpoint = GetWorkerScriptContent {
+            account_id: "account",
+            script_name: "my-worker",
+        };
+        assert_eq!(endpoint.method(), Method::GET);
+        assert_eq!(
+            endpoint.path(),
+            "accounts/account/workers/scripts/my-worker/content/v2"
+        );
+    }
+
+    #[test]
+    fn test_parse_script_content() {
+        let body = concat!(
+            "--abc123\r\n",
+            "Content-Disposition: form-data; name=\"worker.js\"\r\n",
+            "Content-Type: application/javascript+module\r\n",
+            "\r\n",
+            "import { greet } from \"./lib.js\";\r\nexport default {};\r\n",
+            "--abc123\r\n",
+            "Content-Disposition: form-data; name=\"lib.js\"; filename=\"lib.js\"\r\n",
+            "\r\n",
+            "export const greet = () => \"hi\";\r\n",
+            "--abc123--\r\n",
+        );
+
+        let modules = parse_script_content(body.as_bytes()).unwrap();
+        assert_eq!(modules.len(), 2);
+        assert_eq!(modules[0].name, "worker.js");
+        assert_eq!(modules[0].content_type, "application/javascript+module");
+        assert_eq!(
+            modules[0].content,
+            b"import { greet } from \"./lib.js\";\r\nexport default {};"
+        );
+        assert_eq!(modules[1].name, "lib.js");
+        assert_eq!(modules[1].content_type, "application/octet-stream");
+        assert_eq!(modules[1].content, b"export const greet = () => \"hi\";");
+    }
+
+    #[test]
+    fn test_parse_script_content_errors() {
+        assert_eq!(
+            parse_script_content(b"export default {};").unwrap_err(),
+            ScriptContentError::MissingBoundary
+        );
+        assert_eq!(
+            parse_script_content(b"--b\r\nContent-Type: text/plain\r\n\r\nhi\r\n--b--")
+                .unwrap_err(),
+            ScriptContentError::UnnamedPart(0)
+        );
+        assert_eq!(
+            parse_script_content(
+                b"--b\r\nContent-Disposition: form-data; name=\"a.js\"\r\n\r\nunterminated"
+            )
+            .unwrap_err(),
+            ScriptContentError::Unterminated
+        );
+    }
+
+    #[test]
+    fn test_edit_settings_only_sends_set_fields() {
+        let endpoint = EditWorkerScriptSettings {
+            account_id: "account",
+            script_name: "my-worker",
+            settings: WorkerScriptSettings {
+                logpush: Some(true),
+                observability: Some(WorkerObservability {
+                    enabled: Some(true),
+                    head_sampling_rate: Some(0.1),
+                    ..Default::default()
+                }),
+                tail_consumers: Some(vec![WorkerTailConsumer {
+                    service: "tail-worker".to_string(),
+                    environment: None,
+                    namespace: None,
+                }]),
+                ..Default::default()
+            },
+        };
+        assert_eq!(endpoint.method(), Method::PATCH);
+        assert_eq!(
+            endpoint.path(),
+            "accounts/account/workers/scripts/my-worker/settings"
+        );
+
+        let Some(RequestBody::MultiPart(multipart)) = endpoint.body() else {
+            panic!("Expected multipart body");
+        };
+        let parts = multipart.parts();
+        assert_eq!(parts.len(), 1);
+        assert_eq!(parts[0].0, "settings");
+        let MultipartPart::Text(settings) = &parts[0].1 else {
+            panic!("Expected text part");
+        };
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(settings).unwrap(),
+            serde_json::json!({
+                "logpush": true,
+                "observability": {"enabled": true, "head_sampling_rate": 0.1},
+                "tail_consumers": [{"service": "tail-worker"}]
+            })
+        );
+    }
+
+    #[test]
+    fn test_settings_deserialization() {
+        let settings: WorkerScriptSettings = serde_json::from_value(serde_json::json!({
+            "compatibility_date": "2024-09-23",
+            "compatibility_flags": ["nodejs_compat"],
+            "limits": {"cpu_ms": 50},
+            "logpush": false,
+            "placement": {"mode": "smart"},
+            "usage_model": "standard"
+        }))
+        .unwrap();
+        assert_eq!(settings.compatibility_flags.unwrap(), vec!["nodejs_compat"]);
+        assert_eq!(settings.limits.unwrap().cpu_ms, Some(50));
+        assert_eq!(settings.usage_model, Some(WorkerUsageModel::Standard));
+        assert!(settings.bindings.is_none());
+    }
+
+    #[test]
+    fn test_update_usage_model() {
+        let endpoint = UpdateWorkerUsageModel {
+            account_id: "account",
+            script_name: "my-worker",
+            params: WorkerUsageModelSetting {
+                usage_model: WorkerUsageModel::Standard,
+            },
+        };
+        assert_eq!(endpoint.method(), Method::PUT);
+        assert_eq!(
+            endpoint.path(),
+            "accounts/account/workers/scripts/my-worker/usage-model"
+        );
+        match endpoint.body() {
+            Some(RequestBody::Json(json)) => {
+                assert_eq!(json, r#"{"usage_model":"standard"}"#)
+            }
+            _ => panic!("Expected JSON body"),
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::{ApiResult, ApiSuccess};
+
+/// Get the account's `workers.dev` subdomain
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/subdomains/methods/get/>
+#[derive(Debug)]
+pub struct GetAccountSubdomain<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+}
+
+impl EndpointSpec for GetAccountSubdomain<'_> {
+    type JsonResponse = AccountSubdomain;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!("accounts/{}/workers/subdomain", self.account_id)
+    }
+}
+
+/// Create or change the account's `workers.dev` subdomain
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/subdomains/methods/update/>
+#[derive(Debug)]
+pub struct UpdateAccountSubdomain<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// The new subdomain
+    pub params: AccountSubdomain,
+}
+
+impl EndpointSpec for UpdateAccountSubdomain<'_> {
+    type JsonResponse = AccountSubdomain;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn path(&self) -> String {
+        format!("accounts/{}/workers/subdomain", self.account_id)
+    }
+
+    fn body(&self) -> Option<RequestBody<'_>> {
+        Some(RequestBody::Json(
+            serde_json::to_string(&self.params).unwrap(),
+        ))
+    }
+}
+
+/// The account's `workers.dev` subdomain, i.e. `<subdomain>.workers.dev`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AccountSubdomain {
+    pub subdomain: String,
+}
+
+/// Get whether a script is reachable on `workers.dev`
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/scripts/subresources/subdomain/methods/get/>
+#[derive(Debug)]
+pub struct GetScriptSubdomain<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Script name
+    pub script_name: &'a str,
+}
+
+impl EndpointSpec for GetScriptSubdomain<'_> {
+    type JsonResponse = ScriptSubdomain;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/scripts/{}/subdomain",
+            self.account_id, self.script_name
+        )
+    }
+}
+
+/// Enable or disable a script on `workers.dev`
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/scripts/subresources/subdomain/methods/create/>
+#[derive(Debug)]
+pub struct UpdateScriptSubdomain<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Script name
+    pub script_name: &'a str,
+    /// Whether the script and its previews are enabled
+    pub params: ScriptSubdomainParams,
+}
+
+impl EndpointSpec for UpdateScriptSubdomain<'_> {
+    type JsonResponse = ScriptSubdomain;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/scripts/{}/subdomain",
+            self.account_id, self.script_name
+        )
+    }
+
+    fn body(&self) -> Option<RequestBody<'_>> {
+        Some(RequestBody::Json(
+            serde_json::to_string(&self.params).unwrap(),
+        ))
+    }
+}
+
+/// Parameters for [`UpdateScriptSubdomain`]
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone)]
+pub struct ScriptSubdomainParams {
+    /// Whether the script is served on `workers.dev`
+    pub enabled: bool,
+    /// Whether preview URLs are served on `workers.dev`, same as `enabled` if omitted
+    pub previews_enabled: Option<bool>,
+}
+
+/// Whether a script is reachable on `workers.dev`
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ScriptSubdomain {
+    /// Whether the script is served on `workers.dev`
+    pub enabled: bool,
+    /// Whether preview URLs are served on `workers.dev`
+    pub previews_enabled: Option<bool>,
+}
+
+impl ApiResult for AccountSubdomain {}
+impl ApiResult for ScriptSubdomain {}
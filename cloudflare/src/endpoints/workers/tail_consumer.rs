@@ -0,0 +1,184 @@
+use std::pin::Pin;
+use std::time::Duration;
+
+use futures_util::stream::{self, Stream};
+use futures_util::{SinkExt, StreamExt};
+use tokio::net::TcpStream;
+use tokio::time::{interval_at, Instant, Interval};
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::HeaderValue;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+use super::tail_events::{TailEvent, TailFilters};
+use super::{CreateTail, CreateTailParams, DeleteTail, SendTailHeartbeat, WorkersTail};
+use crate::framework::client::async_api::Client;
+use crate::framework::response::ApiFailure;
+
+/// WebSocket subprotocol spoken by the tail service
+const TAIL_PROTOCOL: &str = "trace-v1";
+
+/// Options for a [`TailSession`]
+#[derive(Debug, Clone)]
+pub struct TailOptions {
+    /// Server-side event filters
+    pub filters: TailFilters,
+    /// How often to send a heartbeat to keep the tail from expiring
+    pub heartbeat_interval: Duration,
+}
+
+impl Default for TailOptions {
+    fn default() -> Self {
+        TailOptions {
+            filters: TailFilters::default(),
+            heartbeat_interval: Duration::from_secs(60),
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+/// Errors encountered while tailing a Worker
+pub enum TailError {
+    #[error("Cloudflare API request failed: {0}")]
+    Api(#[from] ApiFailure),
+    #[error("Tail {0} was created without a WebSocket URL")]
+    MissingUrl(String),
+    #[error("Tail WebSocket error: {0}")]
+    WebSocket(#[from] tokio_tungstenite::tungstenite::Error),
+    #[error("Failed to parse tail event: {0}")]
+    Parse(#[from] serde_json::Error),
+}
+
+/// Stream of events returned by [`TailSession::events`]
+pub type TailEventStream<'s> =
+    Pin<Box<dyn Stream<Item = Result<TailEvent, TailError>> + Send + 's>>;
+
+/// A live tail of a Worker's invocations, like `wrangler tail`
+pub struct TailSession<'a> {
+    client: &'a Client,
+    account_identifier: String,
+    script_name: String,
+    tail: WorkersTail,
+    socket: WebSocketStream<MaybeTlsStream<TcpStream>>,
+    heartbeat: Interval,
+}
+
+impl<'a> TailSession<'a> {
+    /// Creates a tail for the script, connects to its WebSocket and applies the filters
+    pub async fn connect(
+        client: &'a Client,
+        account_identifier: &str,
+        script_name: &str,
+        options: TailOptions,
+    ) -> Result<TailSession<'a>, TailError> {
+        let tail = client
+            .request(&CreateTail {
+                account_identifier,
+                script_name,
+                params: CreateTailParams::default(),
+            })
+            .await?
+            .result;
+        let socket = match open_socket(&tail, &options.filters).await {
+            Ok(socket) => socket,
+            Err(e) => {
+                // Tails are capped per script, so do not leave this one open until it expires.
+                // The connect error is the one worth returning.
+                let _ = client
+                    .request(&DeleteTail {
+                        account_identifier,
+                        script_name,
+                        tail_id: &tail.id,
+                    })
+                    .await;
+                return Err(e);
+            }
+        };
+
+        let period = options.heartbeat_interval;
+        Ok(TailSession {
+            client,
+            account_identifier: account_identifier.to_string(),
+            script_name: script_name.to_string(),
+            tail,
+            socket,
+            heartbeat: interval_at(Instant::now() + period, period),
+        })
+    }
+
+    /// The tail this session is attached to
+    pub fn tail(&self) -> &WorkersTail {
+        &self.tail
+    }
+
+    /// Stream of events, sending heartbeats while it is polled. Ends when the server closes the
+    /// connection.
+    pub fn events(&mut self) -> TailEventStream<'_> {
+        Box::pin(stream::unfold(self, |session| async move {
+            let event = session.next_event().await;
+            event.map(|event| (event, session))
+        }))
+    }
+
+    async fn next_event(&mut self) -> Option<Result<TailEvent, TailError>> {
+        loop {
+            tokio::select! {
+                _ = self.heartbeat.tick() => {
+                    let heartbeat = SendTailHeartbeat {
+                        account_identifier: &self.account_identifier,
+                        script_name: &self.script_name,
+                        tail_id: &self.tail.id,
+                    };
+                    match self.client.request(&heartbeat).await {
+                        Ok(response) => self.tail = response.result,
+                        Err(e) => return Some(Err(e.into())),
+                    }
+                }
+                message = self.socket.next() => {
+                    let data = match message? {
+                        Ok(Message::Text(text)) => text.as_bytes().to_vec(),
+                        Ok(Message::Binary(bytes)) => bytes.to_vec(),
+                        Ok(Message::Close(_)) => return None,
+                        Ok(_) => continue,
+                        Err(e) => return Some(Err(e.into())),
+                    };
+                    return Some(serde_json::from_slice(&data).map_err(TailError::from));
+                }
+            }
+        }
+    }
+
+    /// Closes the WebSocket and deletes the tail
+    pub async fn close(mut self) -> Result<(), TailError> {
+        // The tail is deleted below regardless, so a failed close handshake is not fatal.
+        let _ = self.socket.close(None).await;
+        self.client
+            .request(&DeleteTail {
+                account_identifier: &self.account_identifier,
+                script_name: &self.script_name,
+                tail_id: &self.tail.id,
+            })
+            .await?;
+        Ok(())
+    }
+}
+
+/// Connects to the WebSocket of a tail and applies the filters
+async fn open_socket(
+    tail: &WorkersTail,
+    filters: &TailFilters,
+) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>, TailError> {
+    let url = tail
+        .url
+        .clone()
+        .ok_or_else(|| TailError::MissingUrl(tail.id.clone()))?;
+
+    let mut request = url.into_client_request()?;
+    request.headers_mut().insert(
+        "Sec-WebSocket-Protocol",
+        HeaderValue::from_static(TAIL_PROTOCOL),
+    );
+    let (mut socket, _) = tokio_tungstenite::connect_async(request).await?;
+    socket.send(Message::text(filters.to_message())).await?;
+    Ok(socket)
+}
@@ -0,0 +1,213 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// How a Worker invocation ended
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum TailOutcome {
+    Ok,
+    Canceled,
+    Exception,
+    ExceededCpu,
+    ExceededMemory,
+    /// Any outcome not known to this crate
+    #[serde(other)]
+    Unknown,
+}
+
+/// Coarse invocation status used to filter a tail, like `wrangler tail --status`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TailStatus {
+    Ok,
+    /// Exceptions, exceeded limits and unknown errors
+    Error,
+    Canceled,
+}
+
+impl TailStatus {
+    /// The invocation outcomes matched by this status
+    pub fn outcomes(self) -> &'static [TailOutcome] {
+        match self {
+            TailStatus::Ok => &[TailOutcome::Ok],
+            TailStatus::Error => &[
+                TailOutcome::Exception,
+                TailOutcome::ExceededCpu,
+                TailOutcome::ExceededMemory,
+                TailOutcome::Unknown,
+            ],
+            TailStatus::Canceled => &[TailOutcome::Canceled],
+        }
+    }
+}
+
+/// Filters applied server-side to a tail session. Events must match every filter that is set.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TailFilters {
+    /// Only show invocations with these statuses
+    pub status: Option<Vec<TailStatus>>,
+    /// Only show requests with these HTTP methods
+    pub method: Option<Vec<String>>,
+    /// Fraction of events to keep, between 0 and 1
+    pub sampling_rate: Option<f64>,
+    /// Only show requests with this header, optionally with a value matching `query`
+    pub header: Option<TailHeaderFilter>,
+    /// Only show requests from these client IPs
+    pub client_ip: Option<Vec<String>>,
+    /// Only show events whose logs or exceptions contain this text
+    pub search: Option<String>,
+}
+
+/// Header filter of a tail session
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct TailHeaderFilter {
+    /// Header name
+    pub key: String,
+    /// Header value to match
+    pub query: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum TailApiFilter {
+    Outcome(Vec<TailOutcome>),
+    Method(Vec<String>),
+    SamplingRate(f64),
+    Header(TailHeaderFilter),
+    ClientIp(Vec<String>),
+    Query(String),
+}
+
+#[derive(Serialize, Debug)]
+struct TailFilterMessage {
+    filters: Vec<TailApiFilter>,
+    debug: bool,
+}
+
+impl TailFilters {
+    /// The message sent over the tail WebSocket to apply these filters
+    pub fn to_message(&self) -> String {
+        let mut filters = Vec::new();
+        if let Some(status) = &self.status {
+            let outcomes = status
+                .iter()
+                .flat_map(|s| s.outcomes().iter().copied())
+                .collect();
+            filters.push(TailApiFilter::Outcome(outcomes));
+        }
+        if let Some(method) = &self.method {
+            filters.push(TailApiFilter::Method(method.clone()));
+        }
+        if let Some(sampling_rate) = self.sampling_rate {
+            filters.push(TailApiFilter::SamplingRate(sampling_rate));
+        }
+        if let Some(header) = &self.header {
+            filters.push(TailApiFilter::Header(header.clone()));
+        }
+        if let Some(client_ip) = &self.client_ip {
+            filters.push(TailApiFilter::ClientIp(client_ip.clone()));
+        }
+        if let Some(search) = &self.search {
+            filters.push(TailApiFilter::Query(search.clone()));
+        }
+        serde_json::to_string(&TailFilterMessage {
+            filters,
+            debug: false,
+        })
+        .unwrap()
+    }
+}
+
+/// A single Worker invocation received from a tail session
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TailEvent {
+    /// How the invocation ended
+    pub outcome: TailOutcome,
+    /// Name of the Worker that was invoked
+    pub script_name: Option<String>,
+    /// Uncaught exceptions
+    #[serde(default)]
+    pub exceptions: Vec<TailException>,
+    /// `console` calls made during the invocation
+    #[serde(default)]
+    pub logs: Vec<TailLog>,
+    /// When the invocation started, in milliseconds since the Unix epoch
+    pub event_timestamp: Option<i64>,
+    /// What triggered the invocation
+    pub event: Option<TailEventInfo>,
+    /// CPU time used, in milliseconds
+    pub cpu_time: Option<u64>,
+    /// Wall time used, in milliseconds
+    pub wall_time: Option<u64>,
+    /// Whether the event was truncated because it was too large
+    pub truncated: Option<bool>,
+}
+
+/// An uncaught exception from a Worker invocation
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TailException {
+    /// Exception type, e.g. `Error`
+    pub name: String,
+    /// Exception message
+    pub message: String,
+    /// When the exception was thrown, in milliseconds since the Unix epoch
+    pub timestamp: i64,
+}
+
+/// A `console` call from a Worker invocation
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct TailLog {
+    /// The arguments passed to the `console` call
+    pub message: Vec<serde_json::Value>,
+    /// Log level, e.g. `log`, `warn` or `error`
+    pub level: String,
+    /// When the log was written, in milliseconds since the Unix epoch
+    pub timestamp: i64,
+}
+
+/// What triggered a Worker invocation
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged, rename_all = "camelCase")]
+pub enum TailEventInfo {
+    /// An HTTP request
+    Request {
+        request: TailRequest,
+        response: Option<TailResponse>,
+    },
+    /// A cron trigger
+    #[serde(rename_all = "camelCase")]
+    Scheduled { cron: String, scheduled_time: i64 },
+    /// A queue batch
+    #[serde(rename_all = "camelCase")]
+    Queue { queue: String, batch_size: u32 },
+    /// An email
+    #[serde(rename_all = "camelCase")]
+    Email {
+        mail_from: String,
+        rcpt_to: String,
+        raw_size: u64,
+    },
+    /// A Durable Object alarm
+    #[serde(rename_all = "camelCase")]
+    Alarm { scheduled_time: String },
+    /// Any other trigger
+    Other(serde_json::Value),
+}
+
+/// The HTTP request that triggered a Worker invocation
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct TailRequest {
+    pub url: String,
+    pub method: String,
+    #[serde(default)]
+    pub headers: std::collections::HashMap<String, String>,
+    /// Cloudflare request properties (colo, country, ...)
+    pub cf: Option<serde_json::Value>,
+}
+
+/// The response returned by a Worker invocation
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TailResponse {
+    pub status: u16,
+}
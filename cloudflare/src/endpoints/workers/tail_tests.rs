@@ -0,0 +1,165 @@
+#[cfg(test)]
+mod tests {
+    use super::super::tail_events::{
+        TailEvent, TailEventInfo, TailFilters, TailHeaderFilter, TailOutcome, TailStatus,
+    };
+
+    #[test]
+    fn test_tail_filters_message() {
+        let filters = TailFilters {
+            status: Some(vec![TailStatus::Error, TailStatus::Canceled]),
+            method: Some(vec!["POST".to_string()]),
+            sampling_rate: Some(0.5),
+            header: Some(TailHeaderFilter {
+                key: "x-request-id".to_string(),
+                query: None,
+            }),
+            client_ip: Some(vec!["203.0.113.7".to_string()]),
+            search: Some("timeout".to_string()),
+        };
+
+        let message: serde_json::Value = serde_json::from_str(&filters.to_message()).unwrap();
+        assert_eq!(
+            message,
+            serde_json::json!({
+                "filters": [
+                    {"outcome": ["exception", "exceededCpu", "exceededMemory", "unknown", "canceled"]},
+                    {"method": ["POST"]},
+                    {"sampling_rate": 0.5},
+                    {"header": {"key": "x-request-id"}},
+                    {"client_ip": ["203.0.113.7"]},
+                    {"query": "timeout"}
+                ],
+                "debug": false
+            })
+        );
+
+        assert_eq!(
+            TailFilters::default().to_message(),
+            r#"{"filters":[],"debug":false}"#
+        );
+    }
+
+    #[test]
+    fn test_request_tail_event_deserialization() {
+        let json = r#"{
+            "outcome": "exception",
+            "scriptName": "my-worker",
+            "exceptions": [
+                {"name": "Error", "message": "boom", "timestamp": 1710775422123}
+            ],
+            "logs": [
+                {"message": ["handling", {"id": 1}], "level": "log", "timestamp": 1710775422100}
+            ],
+            "eventTimestamp": 1710775422000,
+            "event": {
+                "request": {
+                    "url": "https://example.com/api",
+                    "method": "POST",
+                    "headers": {"content-type": "application/json"},
+                    "cf": {"colo": "LHR"}
+                },
+                "response": {"status": 500}
+            },
+            "cpuTime": 3,
+            "wallTime": 12
+        }"#;
+
+        let event: TailEvent = serde_json::from_str(json).unwrap();
+        assert_eq!(event.outcome, TailOutcome::Exception);
+        assert_eq!(event.script_name.as_deref(), Some("my-worker"));
+        assert_eq!(event.exceptions[0].message, "boom");
+        assert_eq!(event.logs[0].message[1]["id"], 1);
+        assert_eq!(event.cpu_time, Some(3));
+        match event.event {
+            Some(TailEventInfo::Request { request, response }) => {
+                assert_eq!(request.method, "POST");
+                assert_eq!(request.headers["content-type"], "application/json");
+                assert_eq!(response.unwrap().status, 500);
+            }
+            other => panic!("Expected request event, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_other_tail_event_kinds() {
+        let scheduled: TailEvent = serde_json::from_str(
+            r#"{"outcome": "ok", "event": {"cron": "*/5 * * * *", "scheduledTime": 1710775200000}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            scheduled.event,
+            Some(TailEventInfo::Scheduled {
+                cron: "*/5 * * * *".to_string(),
+                scheduled_time: 1710775200000,
+            })
+        );
+        assert!(scheduled.logs.is_empty());
+
+        let queue: TailEvent = serde_json::from_str(
+            r#"{"outcome": "exceededCpu", "event": {"queue": "jobs", "batchSize": 10}}"#,
+        )
+        .unwrap();
+        assert_eq!(queue.outcome, TailOutcome::ExceededCpu);
+        assert!(matches!(
+            queue.event,
+            Some(TailEventInfo::Queue { batch_size: 10, .. })
+        ));
+
+        let unknown: TailEvent = serde_json::from_str(
+            r#"{"outcome": "scriptNotFound", "event": {"consumedEvents": []}}"#,
+        )
+        .unwrap();
+        assert_eq!(unknown.outcome, TailOutcome::Unknown);
+        assert!(matches!(unknown.event, Some(TailEventInfo::Other(_))));
+    }
+
+    #[cfg(feature = "tail")]
+    #[tokio::test]
+    async fn test_failed_connect_deletes_tail() {
+        use super::super::{TailError, TailOptions, TailSession};
+        use crate::framework::auth::Credentials;
+        use crate::framework::client::async_api::Client;
+        use crate::framework::client::ClientConfig;
+        use crate::framework::Environment;
+
+        let mut server = mockito::Server::new_async().await;
+        // The tail URL points at the mock server, which refuses the WebSocket handshake
+        let create = server
+            .mock("POST", "/accounts/acct/workers/scripts/api/tails")
+            .with_body(
+                serde_json::json!({
+                    "result": {
+                        "id": "tail-1",
+                        "url": format!("ws://{}/socket", server.host_with_port()),
+                        "expires_at": "2030-01-01T00:00:00Z"
+                    },
+                    "success": true,
+                    "errors": [],
+                    "messages": []
+                })
+                .to_string(),
+            )
+            .create();
+        let delete = server
+            .mock("DELETE", "/accounts/acct/workers/scripts/api/tails/tail-1")
+            .with_body(r#"{"result": null, "success": true, "errors": [], "messages": []}"#)
+            .create();
+        let client = Client::new(
+            Credentials::UserAuthToken {
+                token: "api-token".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap();
+
+        let error = TailSession::connect(&client, "acct", "api", TailOptions::default())
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(error, TailError::WebSocket(_)));
+        create.assert();
+        delete.assert();
+    }
+}
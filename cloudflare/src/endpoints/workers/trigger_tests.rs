@@ -0,0 +1,199 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{
+        AccountSubdomain, AttachWorkerDomain, AttachWorkerDomainParams, CronError, CronSchedule,
+        DetachWorkerDomain, GetScriptSubdomain, ListWorkerDomains, ListWorkerDomainsParams,
+        ScriptSubdomainParams, UpdateAccountSubdomain, UpdateScriptSubdomain,
+        UpdateWorkerSchedules, WorkerDomain, WorkerSchedules,
+    };
+    use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+
+    fn json_body(endpoint: &impl EndpointSpec) -> String {
+        match endpoint.body() {
+            Some(RequestBody::Json(json)) => json,
+            _ => panic!("Expected JSON body"),
+        }
+    }
+
+    #[test]
+    fn test_valid_cron_expressions() {
+        for expression in [
+            "* * * * *",
+            "*/30 * * * *",
+            "0 17 * * sun",
+            "10 7 * * mon-fri",
+            "0 0 1,15 * *",
+            "59 23 LW * *",
+            "0 0 L * *",
+            "0 12 15W * *",
+            "0 0 * * 5L",
+            "0 9 * * MON#2",
+            "0 0 1 JAN-MAR/2 *",
+        ] {
+            assert!(
+                CronSchedule::parse(expression).is_ok(),
+                "{expression} should be valid"
+            );
+        }
+
+        assert_eq!(
+            CronSchedule::parse("  0  12 * *   *").unwrap().as_str(),
+            "0 12 * * *"
+        );
+    }
+
+    #[test]
+    fn test_invalid_cron_expressions() {
+        assert_eq!(
+            CronSchedule::parse("* * * *"),
+            Err(CronError::FieldCount(4))
+        );
+        assert_eq!(
+            CronSchedule::parse("0 0 * * * 2024"),
+            Err(CronError::FieldCount(6))
+        );
+        assert_eq!(
+            CronSchedule::parse("60 * * * *"),
+            Err(CronError::InvalidField {
+                field: "minute",
+                value: "60".to_string()
+            })
+        );
+        assert_eq!(
+            CronSchedule::parse("0 24 * * *"),
+            Err(CronError::InvalidField {
+                field: "hour",
+                value: "24".to_string()
+            })
+        );
+        for (expression, field) in [
+            ("0 0 0 * *", "day of month"),
+            ("0 0 * 13 *", "month"),
+            ("0 0 * * FUNDAY", "day of week"),
+            ("*/0 * * * *", "minute"),
+            ("30-10 * * * *", "minute"),
+            ("0 0 32W * *", "day of month"),
+            ("0 0 * * 1#6", "day of week"),
+            ("0 0 * * L", "day of week"),
+        ] {
+            match CronSchedule::parse(expression) {
+                Err(CronError::InvalidField { field: f, .. }) => {
+                    assert_eq!(f, field, "{expression}")
+                }
+                other => panic!("{expression} should be invalid, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_update_worker_schedules() {
+        let update = UpdateWorkerSchedules {
+            account_id: "account",
+            script_name: "cron-worker",
+            schedules: vec![
+                CronSchedule::parse("*/5 * * * *").unwrap(),
+                CronSchedule::parse("0 0 * * SUN").unwrap(),
+            ],
+        };
+        assert_eq!(update.method(), Method::PUT);
+        assert_eq!(
+            update.path(),
+            "accounts/account/workers/scripts/cron-worker/schedules"
+        );
+        assert_eq!(
+            json_body(&update),
+            r#"[{"cron":"*/5 * * * *"},{"cron":"0 0 * * SUN"}]"#
+        );
+
+        let schedules: WorkerSchedules = serde_json::from_str(
+            r#"{"schedules": [{"cron": "*/5 * * * *", "created_on": "2024-01-01T00:00:00Z", "modified_on": "2024-01-01T00:00:00Z"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(schedules.schedules[0].cron, "*/5 * * * *");
+    }
+
+    #[test]
+    fn test_worker_domain_endpoints() {
+        let list = ListWorkerDomains {
+            account_id: "account",
+            params: ListWorkerDomainsParams {
+                service: Some("api-worker".to_string()),
+                ..Default::default()
+            },
+        };
+        assert_eq!(list.path(), "accounts/account/workers/domains");
+        assert_eq!(list.query(), Some("service=api-worker".to_string()));
+
+        let attach = AttachWorkerDomain {
+            account_id: "account",
+            params: AttachWorkerDomainParams {
+                hostname: "api.example.com".to_string(),
+                service: "api-worker".to_string(),
+                zone_id: "zone".to_string(),
+                environment: None,
+            },
+        };
+        assert_eq!(attach.method(), Method::PUT);
+        assert_eq!(
+            json_body(&attach),
+            r#"{"hostname":"api.example.com","service":"api-worker","zone_id":"zone"}"#
+        );
+
+        let detach = DetachWorkerDomain {
+            account_id: "account",
+            domain_id: "domain",
+        };
+        assert_eq!(detach.method(), Method::DELETE);
+        assert_eq!(detach.path(), "accounts/account/workers/domains/domain");
+
+        let domain: WorkerDomain = serde_json::from_str(
+            r#"{
+                "id": "dbe10b4bc17c295377eabd600e1787fd",
+                "environment": "production",
+                "hostname": "api.example.com",
+                "service": "api-worker",
+                "zone_id": "593c9c94de529bbbfaac7c53ced0447d",
+                "zone_name": "example.com",
+                "cert_id": "9b4c8a3e0a6c4fd9a4e6b1c7d0f2e3a1"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(domain.zone_name.as_deref(), Some("example.com"));
+    }
+
+    #[test]
+    fn test_subdomain_endpoints() {
+        let update_account = UpdateAccountSubdomain {
+            account_id: "account",
+            params: AccountSubdomain {
+                subdomain: "my-team".to_string(),
+            },
+        };
+        assert_eq!(update_account.method(), Method::PUT);
+        assert_eq!(update_account.path(), "accounts/account/workers/subdomain");
+        assert_eq!(json_body(&update_account), r#"{"subdomain":"my-team"}"#);
+
+        let get_script = GetScriptSubdomain {
+            account_id: "account",
+            script_name: "api-worker",
+        };
+        assert_eq!(
+            get_script.path(),
+            "accounts/account/workers/scripts/api-worker/subdomain"
+        );
+
+        let disable = UpdateScriptSubdomain {
+            account_id: "account",
+            script_name: "api-worker",
+            params: ScriptSubdomainParams {
+                enabled: false,
+                previews_enabled: Some(true),
+            },
+        };
+        assert_eq!(disable.method(), Method::POST);
+        assert_eq!(
+            json_body(&disable),
+            r#"{"enabled":false,"previews_enabled":true}"#
+        );
+    }
+}
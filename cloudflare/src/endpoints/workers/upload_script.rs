@@ -41,29 +41,38 @@ impl EndpointSpec for UploadWorkerScript<'_> {
 
 impl MultipartBody for UploadWorkerScript<'_> {
     fn parts(&self) -> Vec<(String, MultipartPart)> {
-        let mut parts = Vec::new();
-
-        // Add metadata part
         let metadata_json =
             serde_json::to_string(&self.metadata).unwrap_or_else(|_| "{}".to_string());
-        parts.push(("metadata".to_string(), MultipartPart::Text(metadata_json)));
+        script_parts(metadata_json, &self.script_content, &self.modules)
+    }
+}
 
-        // Add main script content
+/// Builds the multipart parts shared by script and version uploads: the metadata,
+/// the main script content, then any additional modules.
+pub(super) fn script_parts(
+    metadata_json: String,
+    script_content: &WorkerScriptContent,
+    modules: &[WorkerScriptContent],
+) -> Vec<(String, MultipartPart)> {
+    let mut parts = Vec::new();
+
+    // Add metadata part
+    parts.push(("metadata".to_string(), MultipartPart::Text(metadata_json)));
+
+    // Add main script content and additional modules, with their types so that the API does
+    // not have to guess them
+    for module in std::iter::once(script_content).chain(modules) {
         parts.push((
-            self.script_content.name.clone(),
-            MultipartPart::Bytes(self.script_content.content.clone()),
+            module.name.clone(),
+            MultipartPart::File {
+                content: module.content.clone(),
+                file_name: module.name.clone(),
+                content_type: module.content_type.clone(),
+            },
         ));
-
-        // Add additional modules
-        for module in &self.modules {
-            parts.push((
-                module.name.clone(),
-                MultipartPart::Bytes(module.content.clone()),
-            ));
-        }
-
-        parts
     }
+
+    parts
 }
 
 /// Upload a Workers script using the new 2025 JSON API (Beta)
@@ -0,0 +1,50 @@
+use crate::framework::endpoint::{EndpointSpec, Method, MultipartBody, MultipartPart, RequestBody};
+use crate::framework::response::ApiSuccess;
+
+use super::script_data_structures::WorkerScriptContent;
+use super::upload_script::script_parts;
+use super::version_data_structures::{WorkerVersion, WorkerVersionMetadata};
+
+/// Upload a new version of a Workers script without deploying it
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/scripts/subresources/versions/methods/create/>
+#[derive(Debug)]
+pub struct UploadWorkerVersion<'a> {
+    /// Account identifier
+    pub account_id: &'a str,
+    /// Script name
+    pub script_name: &'a str,
+    /// Version metadata
+    pub metadata: WorkerVersionMetadata,
+    /// Main script content
+    pub script_content: WorkerScriptContent,
+    /// Additional modules (WASM, data blobs, etc.)
+    pub modules: Vec<WorkerScriptContent>,
+}
+
+impl EndpointSpec for UploadWorkerVersion<'_> {
+    type JsonResponse = WorkerVersion;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/scripts/{}/versions",
+            self.account_id, self.script_name
+        )
+    }
+
+    fn body(&self) -> Option<RequestBody<'_>> {
+        Some(RequestBody::MultiPart(self))
+    }
+}
+
+impl MultipartBody for UploadWorkerVersion<'_> {
+    fn parts(&self) -> Vec<(String, MultipartPart)> {
+        let metadata_json =
+            serde_json::to_string(&self.metadata).unwrap_or_else(|_| "{}".to_string());
+        script_parts(metadata_json, &self.script_content, &self.modules)
+    }
+}
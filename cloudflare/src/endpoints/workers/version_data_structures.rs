@@ -0,0 +1,165 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::script_data_structures::{WorkerBinding, WorkerScriptMetadata, WorkerUsageModel};
+use crate::framework::response::ApiResult;
+
+/// Annotations attached to a Worker version or deployment
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorkerAnnotations {
+    /// Human-readable message about the version or deployment
+    #[serde(rename = "workers/message")]
+    pub message: Option<String>,
+    /// User-provided identifier for the version
+    #[serde(rename = "workers/tag")]
+    pub tag: Option<String>,
+    /// Operation that triggered the creation of the version or deployment
+    #[serde(rename = "workers/triggered_by")]
+    pub triggered_by: Option<String>,
+}
+
+/// Metadata part of a version upload: the script metadata plus version annotations
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone)]
+pub struct WorkerVersionMetadata {
+    /// Script metadata (main module, bindings, compatibility settings, ...)
+    #[serde(flatten)]
+    pub script: WorkerScriptMetadata,
+    /// Version annotations
+    pub annotations: Option<WorkerAnnotations>,
+}
+
+/// A Worker version
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/scripts/subresources/versions/>
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct WorkerVersion {
+    /// Version identifier
+    pub id: String,
+    /// Sequential version number
+    pub number: Option<u64>,
+    /// Authorship and timestamps
+    pub metadata: Option<WorkerVersionInfo>,
+    /// Version annotations
+    pub annotations: Option<WorkerAnnotations>,
+    /// Bindings and runtime settings. Only returned by the version detail endpoint.
+    pub resources: Option<WorkerVersionResources>,
+    /// Time the version took to start up, in milliseconds
+    pub startup_time_ms: Option<u64>,
+}
+
+/// Authorship and timestamps of a Worker version
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct WorkerVersionInfo {
+    /// Email of the version author
+    pub author_email: Option<String>,
+    /// Identifier of the version author
+    pub author_id: Option<String>,
+    /// Version creation date
+    pub created_on: Option<DateTime<Utc>>,
+    /// Version last modified date
+    pub modified_on: Option<DateTime<Utc>>,
+    /// Whether the version has a preview URL
+    pub has_preview: Option<bool>,
+    /// Where the version was uploaded from (e.g. `wrangler`, `api`, `dash`)
+    pub source: Option<String>,
+}
+
+/// Bindings and runtime settings of a Worker version
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct WorkerVersionResources {
+    /// Worker bindings
+    pub bindings: Option<Vec<WorkerBinding>>,
+    /// Script details
+    pub script: Option<WorkerVersionScript>,
+    /// Runtime settings
+    pub script_runtime: Option<WorkerVersionScriptRuntime>,
+}
+
+/// Script details of a Worker version
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct WorkerVersionScript {
+    /// Hashed script content
+    pub etag: Option<String>,
+    /// Event handlers exported by the script
+    pub handlers: Option<Vec<String>>,
+    /// Where the script was last deployed from
+    pub last_deployed_from: Option<String>,
+}
+
+/// Runtime settings of a Worker version
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct WorkerVersionScriptRuntime {
+    /// Compatibility date
+    pub compatibility_date: Option<String>,
+    /// Compatibility flags
+    pub compatibility_flags: Option<Vec<String>>,
+    /// Usage model
+    pub usage_model: Option<WorkerUsageModel>,
+    /// Resource limits (e.g. `cpu_ms`)
+    pub limits: Option<serde_json::Value>,
+}
+
+/// A page of Worker versions
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct WorkerVersionList {
+    /// Versions, newest first
+    pub items: Vec<WorkerVersion>,
+}
+
+/// Strategy used to split traffic between the versions of a deployment
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkerDeploymentStrategy {
+    /// Traffic is split by percentage between versions
+    Percentage,
+}
+
+/// A version taking part in a deployment and its share of traffic
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct WorkerDeploymentVersion {
+    /// Percentage of traffic routed to the version, between 0 and 100
+    pub percentage: f64,
+    /// Version identifier
+    pub version_id: String,
+}
+
+/// A Worker deployment
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/scripts/subresources/deployments/>
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct WorkerDeployment {
+    /// Deployment identifier
+    pub id: String,
+    /// Deployment creation date
+    pub created_on: DateTime<Utc>,
+    /// Where the deployment was created from (e.g. `wrangler`, `api`, `dash`)
+    pub source: Option<String>,
+    /// Traffic split strategy
+    pub strategy: WorkerDeploymentStrategy,
+    /// Versions taking part in the deployment
+    pub versions: Vec<WorkerDeploymentVersion>,
+    /// Deployment annotations
+    pub annotations: Option<WorkerAnnotations>,
+    /// Email of the deployment author
+    pub author_email: Option<String>,
+}
+
+/// Deployments of a Worker script
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct WorkerDeploymentList {
+    /// Deployments, newest first
+    pub deployments: Vec<WorkerDeployment>,
+}
+
+impl WorkerDeploymentList {
+    /// The deployment currently serving traffic, i.e. the most recent one
+    pub fn active(&self) -> Option<&WorkerDeployment> {
+        self.deployments.iter().max_by_key(|d| d.created_on)
+    }
+}
+
+impl ApiResult for WorkerVersion {}
+impl ApiResult for WorkerVersionList {}
+impl ApiResult for WorkerDeployment {}
+impl ApiResult for WorkerDeploymentList {}
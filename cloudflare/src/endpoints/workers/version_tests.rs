@@ -0,0 +1,294 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{
+        script_data_structures::{WorkerBinding, WorkerScriptContent, WorkerScriptMetadata},
+        version_data_structures::{
+            WorkerAnnotations, WorkerDeployment, WorkerDeploymentList, WorkerDeploymentStrategy,
+            WorkerVersion, WorkerVersionList, WorkerVersionMetadata,
+        },
+        CreateWorkerDeployment, CreateWorkerDeploymentParams, DeploymentSplitError,
+        GetWorkerDeployment, GetWorkerVersion, ListWorkerDeployments, ListWorkerVersions,
+        ListWorkerVersionsParams, UploadWorkerVersion,
+    };
+    use crate::framework::endpoint::{EndpointSpec, Method, MultipartBody, RequestBody};
+
+    #[test]
+    fn test_upload_worker_version_endpoint() {
+        let metadata = WorkerVersionMetadata {
+            script: WorkerScriptMetadata {
+                main_module: "worker.js".to_string(),
+                bindings: Some(vec![WorkerBinding::PlainText {
+                    name: "MESSAGE".to_string(),
+                    text: "Hello".to_string(),
+                }]),
+                compatibility_date: Some("2024-01-01".to_string()),
+                compatibility_flags: None,
+                usage_model: None,
+                placement: None,
+                tail_consumers: None,
+                keep_bindings_on_error: None,
+                observability: None,
+                assets: None,
+                tags: None,
+                migrations: None,
+            },
+            annotations: Some(WorkerAnnotations {
+                message: Some("Fix login redirect".to_string()),
+                tag: Some("v1.2.3".to_string()),
+                triggered_by: None,
+            }),
+        };
+
+        let upload_request = UploadWorkerVersion {
+            account_id: "test-account",
+            script_name: "my-worker",
+            metadata,
+            script_content: WorkerScriptContent {
+                content: b"export default { fetch() { return new Response('Hello'); } }".to_vec(),
+                content_type: "application/javascript+module".to_string(),
+                name: "worker.js".to_string(),
+            },
+            modules: vec![],
+        };
+
+        assert_eq!(upload_request.method(), Method::POST);
+        assert_eq!(
+            upload_request.path(),
+            "accounts/test-account/workers/scripts/my-worker/versions"
+        );
+
+        let json = serde_json::to_string(&upload_request.metadata).unwrap();
+        assert!(json.contains("\"main_module\":\"worker.js\""));
+        assert!(json.contains("\"annotations\":{\"workers/message\":\"Fix login redirect\",\"workers/tag\":\"v1.2.3\"}"));
+
+        let parts = upload_request.parts();
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].0, "metadata");
+        assert_eq!(parts[1].0, "worker.js");
+    }
+
+    #[test]
+    fn test_version_endpoints() {
+        let list_request = ListWorkerVersions {
+            account_id: "test-account",
+            script_name: "my-worker",
+            params: ListWorkerVersionsParams {
+                deployable: Some(true),
+                page: Some(2),
+                per_page: None,
+            },
+        };
+        assert_eq!(list_request.method(), Method::GET);
+        assert_eq!(
+            list_request.path(),
+            "accounts/test-account/workers/scripts/my-worker/versions"
+        );
+        assert_eq!(
+            list_request.query(),
+            Some("deployable=true&page=2".to_string())
+        );
+
+        let get_request = GetWorkerVersion {
+            account_id: "test-account",
+            script_name: "my-worker",
+            version_id: "version-1",
+        };
+        assert_eq!(
+            get_request.path(),
+            "accounts/test-account/workers/scripts/my-worker/versions/version-1"
+        );
+    }
+
+    #[test]
+    fn test_worker_version_deserialization() {
+        let json = r#"{
+            "id": "18f97339-c287-4872-9bdd-e2135c07ec12",
+            "number": 3,
+            "metadata": {
+                "author_email": "user@example.com",
+                "author_id": "408cbcdfd4dda4617efef40b04d168a1",
+                "created_on": "2024-03-18T15:23:42.123Z",
+                "has_preview": false,
+                "modified_on": "2024-03-18T15:23:42.123Z",
+                "source": "api"
+            },
+            "annotations": {
+                "workers/message": "Fix login redirect",
+                "workers/triggered_by": "upload"
+            },
+            "resources": {
+                "bindings": [
+                    {"type": "plain_text", "name": "MESSAGE", "text": "Hello"}
+                ],
+                "script": {
+                    "etag": "13a3240e8fb414561b0366813b0b8f42b3e6cfa0d9e70e99835dae83d0d8a794",
+                    "handlers": ["fetch"]
+                },
+                "script_runtime": {
+                    "compatibility_date": "2024-01-01",
+                    "usage_model": "standard",
+                    "limits": {"cpu_ms": 50}
+                }
+            }
+        }"#;
+
+        let version: WorkerVersion = serde_json::from_str(json).unwrap();
+        assert_eq!(version.number, Some(3));
+        let annotations = version.annotations.unwrap();
+        assert_eq!(annotations.message.as_deref(), Some("Fix login redirect"));
+        assert_eq!(annotations.triggered_by.as_deref(), Some("upload"));
+        let resources = version.resources.unwrap();
+        assert_eq!(
+            resources.bindings.unwrap(),
+            vec![WorkerBinding::PlainText {
+                name: "MESSAGE".to_string(),
+                text: "Hello".to_string(),
+            }]
+        );
+        assert_eq!(
+            resources.script.unwrap().handlers,
+            Some(vec!["fetch".to_string()])
+        );
+
+        let list: WorkerVersionList = serde_json::from_str(
+            r#"{"items": [{"id": "a", "number": 2}, {"id": "b", "number": 1}]}"#,
+        )
+        .unwrap();
+        assert_eq!(list.items.len(), 2);
+        assert!(list.items[0].resources.is_none());
+    }
+
+    #[test]
+    fn test_create_worker_deployment_endpoint() {
+        let create_request = CreateWorkerDeployment {
+            account_id: "test-account",
+            script_name: "my-worker",
+            params: CreateWorkerDeploymentParams::split(vec![
+                ("new-version".to_string(), 10.0),
+                ("old-version".to_string(), 90.0),
+            ])
+            .unwrap()
+            .with_message("Canary 10%"),
+            force: false,
+        };
+
+        assert_eq!(create_request.method(), Method::POST);
+        assert_eq!(
+            create_request.path(),
+            "accounts/test-account/workers/scripts/my-worker/deployments"
+        );
+        assert_eq!(create_request.query(), None);
+
+        match create_request.body() {
+            Some(RequestBody::Json(json)) => assert_eq!(
+                json,
+                r#"{"strategy":"percentage","versions":[{"percentage":10.0,"version_id":"new-version"},{"percentage":90.0,"version_id":"old-version"}],"annotations":{"workers/message":"Canary 10%"}}"#
+            ),
+            _ => panic!("Expected JSON body"),
+        }
+
+        let forced = CreateWorkerDeployment {
+            account_id: "test-account",
+            script_name: "my-worker",
+            params: CreateWorkerDeploymentParams::single("new-version"),
+            force: true,
+        };
+        assert_eq!(forced.query(), Some("force=true".to_string()));
+        assert_eq!(forced.params.versions[0].percentage, 100.0);
+    }
+
+    #[test]
+    fn test_deployment_split_validation() {
+        assert_eq!(
+            CreateWorkerDeploymentParams::split(vec![]).unwrap_err(),
+            DeploymentSplitError::NoVersions
+        );
+        assert_eq!(
+            CreateWorkerDeploymentParams::split(vec![
+                ("a".to_string(), 50.0),
+                ("b".to_string(), 40.0),
+            ])
+            .unwrap_err(),
+            DeploymentSplitError::TotalNot100 { total: 90.0 }
+        );
+        assert_eq!(
+            CreateWorkerDeploymentParams::split(vec![
+                ("a".to_string(), 120.0),
+                ("b".to_string(), -20.0),
+            ])
+            .unwrap_err(),
+            DeploymentSplitError::InvalidPercentage {
+                version_id: "a".to_string(),
+                percentage: 120.0
+            }
+        );
+        assert!(matches!(
+            CreateWorkerDeploymentParams::split(vec![
+                ("a".to_string(), 50.0),
+                ("b".to_string(), 25.0),
+                ("c".to_string(), 25.0),
+            ])
+            .unwrap_err(),
+            DeploymentSplitError::TooManyVersions { count: 3, max: 2 }
+        ));
+    }
+
+    #[test]
+    fn test_deployment_endpoints_and_active_deployment() {
+        let list_request = ListWorkerDeployments {
+            account_id: "test-account",
+            script_name: "my-worker",
+        };
+        assert_eq!(list_request.method(), Method::GET);
+        assert_eq!(
+            list_request.path(),
+            "accounts/test-account/workers/scripts/my-worker/deployments"
+        );
+
+        let get_request = GetWorkerDeployment {
+            account_id: "test-account",
+            script_name: "my-worker",
+            deployment_id: "deployment-1",
+        };
+        assert_eq!(
+            get_request.path(),
+            "accounts/test-account/workers/scripts/my-worker/deployments/deployment-1"
+        );
+
+        let json = r#"{
+            "deployments": [
+                {
+                    "id": "older",
+                    "created_on": "2024-03-01T00:00:00Z",
+                    "source": "api",
+                    "strategy": "percentage",
+                    "versions": [{"percentage": 100, "version_id": "v1"}]
+                },
+                {
+                    "id": "newer",
+                    "created_on": "2024-03-02T00:00:00Z",
+                    "source": "wrangler",
+                    "strategy": "percentage",
+                    "versions": [
+                        {"percentage": 10, "version_id": "v2"},
+                        {"percentage": 90, "version_id": "v1"}
+                    ],
+                    "annotations": {"workers/triggered_by": "deployment"},
+                    "author_email": "user@example.com"
+                }
+            ]
+        }"#;
+
+        let list: WorkerDeploymentList = serde_json::from_str(json).unwrap();
+        let active: &WorkerDeployment = list.active().unwrap();
+        assert_eq!(active.id, "newer");
+        assert_eq!(active.strategy, WorkerDeploymentStrategy::Percentage);
+        assert_eq!(active.versions.len(), 2);
+        assert_eq!(active.versions[0].percentage, 10.0);
+
+        let empty = WorkerDeploymentList {
+            deployments: vec![],
+        };
+        assert!(empty.active().is_none());
+    }
+}
@@ -0,0 +1,206 @@
+/*!
+Snapshots of Workers KV namespaces, e.g. before a risky migration.
+
+[`export`] writes every key of a namespace to a portable archive: newline-delimited JSON, one
+[`BackupRecord`] per line, with base64 values. [`restore`] replays an archive into a namespace
+through bulk writes, and [`copy`] moves keys from one namespace to another without an archive.
+
+Keys that expire within a minute are skipped when writing, since KV rejects such expirations.
+ */
+use std::io::{BufRead, Write};
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use chrono::Utc;
+use futures_util::stream::{self, StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none};
+
+use super::write_bulk::{KeyValuePair, MAX_BULK_BYTES, MAX_BULK_KEYS};
+use super::{Key, KvError, KvStore};
+
+/// Shortest time to expiration KV accepts on writes, in seconds
+const MIN_EXPIRATION_SECS: i64 = 60;
+
+#[derive(thiserror::Error, Debug)]
+/// Errors encountered while exporting, restoring or copying a namespace
+pub enum BackupError {
+    #[error(transparent)]
+    Kv(#[from] KvError),
+    #[error("Failed to read or write the archive: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid record on line {line} of the archive: {source}")]
+    InvalidRecord {
+        line: usize,
+        source: serde_json::Error,
+    },
+}
+
+/// One key of an archive
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct BackupRecord {
+    pub key: String,
+    /// The value, base64-encoded in the archive
+    #[serde_as(as = "serde_with::base64::Base64")]
+    pub value: Vec<u8>,
+    /// When the key expires, in seconds since the UNIX epoch
+    pub expiration: Option<i64>,
+    /// Arbitrary JSON that is associated with the key
+    pub metadata: Option<serde_json::Value>,
+}
+
+impl BackupRecord {
+    /// The bulk write pair for this record, or `None` if it expires too soon to be written
+    fn into_pair(self, now: i64) -> Option<KeyValuePair> {
+        if self
+            .expiration
+            .is_some_and(|expiration| expiration < now + MIN_EXPIRATION_SECS)
+        {
+            return None;
+        }
+        Some(KeyValuePair {
+            key: self.key,
+            value: STANDARD.encode(self.value),
+            expiration: self.expiration,
+            expiration_ttl: None,
+            base64: Some(true),
+            metadata: self.metadata,
+        })
+    }
+}
+
+/// Keys written by [`restore`], and keys skipped because they had expired
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RestoreSummary {
+    pub restored: usize,
+    pub expired: usize,
+}
+
+/// Writes every key starting with `prefix` to `archive`, and returns how many were written.
+/// Values are fetched [`KvStore::concurrency`] at a time.
+pub async fn export(
+    store: &KvStore<'_>,
+    prefix: Option<&str>,
+    mut archive: impl Write,
+) -> Result<usize, BackupError> {
+    let mut exported = 0;
+    let mut cursor = None;
+    loop {
+        let (keys, next) = store.list_page(prefix, cursor).await?;
+        for record in fetch_records(store, keys).await? {
+            serde_json::to_writer(&mut archive, &record).map_err(std::io::Error::from)?;
+            archive.write_all(b"\n")?;
+            exported += 1;
+        }
+        match next {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+    archive.flush()?;
+    Ok(exported)
+}
+
+/// Writes every record of `archive` to the namespace. Existing keys are overwritten.
+///
+/// Records are written in batches of up to [`MAX_BULK_KEYS`] keys per concurrent bulk write,
+/// and at most [`MAX_BULK_BYTES`] of archive lines, which bounds the memory used.
+pub async fn restore(
+    store: &KvStore<'_>,
+    archive: impl BufRead,
+) -> Result<RestoreSummary, BackupError> {
+    // Enough pairs to keep every concurrent bulk write busy
+    let batch_keys = MAX_BULK_KEYS * store.concurrency;
+    let mut summary = RestoreSummary::default();
+    let mut batch = Vec::new();
+    let mut batch_bytes = 0;
+    for (index, line) in archive.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record: BackupRecord =
+            serde_json::from_str(&line).map_err(|source| BackupError::InvalidRecord {
+                line: index + 1,
+                source,
+            })?;
+        // An archive line is about as long as the bulk write pair it becomes
+        if !batch.is_empty() && batch_bytes + line.len() > MAX_BULK_BYTES {
+            write_batch(store, std::mem::take(&mut batch), &mut summary).await?;
+            batch_bytes = 0;
+        }
+        batch_bytes += line.len();
+        batch.push(record);
+        if batch.len() == batch_keys {
+            write_batch(store, std::mem::take(&mut batch), &mut summary).await?;
+            batch_bytes = 0;
+        }
+    }
+    write_batch(store, batch, &mut summary).await?;
+    Ok(summary)
+}
+
+/// Writes records, skipping those that expire too soon as of now, so that a long restore does
+/// not write keys that expired while earlier batches were written
+async fn write_batch(
+    store: &KvStore<'_>,
+    records: Vec<BackupRecord>,
+    summary: &mut RestoreSummary,
+) -> Result<(), BackupError> {
+    let now = Utc::now().timestamp();
+    let count = records.len();
+    let pairs: Vec<_> = records
+        .into_iter()
+        .filter_map(|record| record.into_pair(now))
+        .collect();
+    summary.expired += count - pairs.len();
+    summary.restored += pairs.len();
+    store.put_many(pairs).await?;
+    Ok(())
+}
+
+/// Copies every key starting with `prefix` from `source` to `destination`, and returns how many
+/// were copied. Existing keys in `destination` are overwritten.
+pub async fn copy(
+    source: &KvStore<'_>,
+    destination: &KvStore<'_>,
+    prefix: Option<&str>,
+) -> Result<usize, BackupError> {
+    let mut copied = 0;
+    let mut cursor = None;
+    loop {
+        let (keys, next) = source.list_page(prefix, cursor).await?;
+        let now = Utc::now().timestamp();
+        let pairs: Vec<_> = fetch_records(source, keys)
+            .await?
+            .into_iter()
+            .filter_map(|record| record.into_pair(now))
+            .collect();
+        copied += pairs.len();
+        destination.put_many(pairs).await?;
+        match next {
+            Some(next) => cursor = Some(next),
+            None => return Ok(copied),
+        }
+    }
+}
+
+/// Fetches the values of listed keys, skipping keys deleted since they were listed
+async fn fetch_records(store: &KvStore<'_>, keys: Vec<Key>) -> Result<Vec<BackupRecord>, KvError> {
+    let records: Vec<Option<BackupRecord>> = stream::iter(keys)
+        .map(|key| async move {
+            let value = store.get(&key.name).await?;
+            Ok::<_, KvError>(value.map(|value| BackupRecord {
+                key: key.name,
+                value,
+                expiration: key.expiration.map(|expiration| expiration.timestamp()),
+                metadata: key.metadata,
+            }))
+        })
+        .buffered(store.concurrency)
+        .try_collect()
+        .await?;
+    Ok(records.into_iter().flatten().collect())
+}
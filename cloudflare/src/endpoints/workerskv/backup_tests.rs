@@ -0,0 +1,211 @@
+#[cfg(test)]
+mod tests {
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    use super::super::backup::{copy, export, restore, BackupError, BackupRecord, RestoreSummary};
+    use super::super::KvStore;
+    use crate::framework::auth::Credentials;
+    use crate::framework::client::async_api::Client;
+    use crate::framework::client::ClientConfig;
+    use crate::framework::Environment;
+
+    fn client(server: &Server) -> Client {
+        Client::new(
+            Credentials::UserAuthToken {
+                token: "api-token".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap()
+    }
+
+    fn success(result: serde_json::Value) -> String {
+        json!({"result": result, "success": true, "errors": [], "messages": []}).to_string()
+    }
+
+    /// Lists `config:a` (with expiration and metadata) and `config:b` on two pages
+    fn mock_source(server: &mut Server) -> Vec<mockito::Mock> {
+        let keys = "/accounts/account/storage/kv/namespaces/source/keys";
+        let values = "/accounts/account/storage/kv/namespaces/source/values";
+        vec![
+            server
+                .mock("GET", keys)
+                .match_query(Matcher::Regex("^limit=1000&prefix=config%3A$".to_string()))
+                .with_body(
+                    json!({
+                        "result": [{
+                            "name": "config:a",
+                            "expiration": 4102444800u64,
+                            "metadata": {"owner": "ops"}
+                        }],
+                        "result_info": {"count": 1, "cursor": "page-2"},
+                        "success": true, "errors": [], "messages": []
+                    })
+                    .to_string(),
+                )
+                .create(),
+            server
+                .mock("GET", keys)
+                .match_query(Matcher::UrlEncoded("cursor".into(), "page-2".into()))
+                .with_body(
+                    json!({
+                        "result": [{"name": "config:b"}],
+                        "result_info": {"count": 1, "cursor": ""},
+                        "success": true, "errors": [], "messages": []
+                    })
+                    .to_string(),
+                )
+                .create(),
+            server
+                .mock("GET", format!("{values}/config%3Aa").as_str())
+                .with_body("hello")
+                .create(),
+            server
+                .mock("GET", format!("{values}/config%3Ab").as_str())
+                .with_body([0xff, 0x00])
+                .create(),
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_export() {
+        let mut server = Server::new_async().await;
+        let mocks = mock_source(&mut server);
+
+        let client = client(&server);
+        let mut archive = Vec::new();
+        let exported = export(
+            &KvStore::new(&client, "account", "source"),
+            Some("config:"),
+            &mut archive,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(exported, 2);
+        assert_eq!(
+            String::from_utf8(archive).unwrap(),
+            concat!(
+                r#"{"key":"config:a","value":"aGVsbG8=","expiration":4102444800,"metadata":{"owner":"ops"}}"#,
+                "\n",
+                r#"{"key":"config:b","value":"/wA="}"#,
+                "\n",
+            )
+        );
+        for mock in mocks {
+            mock.assert();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_restore() {
+        let mut server = Server::new_async().await;
+        let write = server
+            .mock("PUT", "/accounts/account/storage/kv/namespaces/target/bulk")
+            .match_body(Matcher::Json(json!([
+                {"key": "kept", "value": "aGVsbG8=", "base64": true, "metadata": {"v": 1}},
+                {"key": "later", "value": "eA==", "expiration": 4102444800u64, "base64": true}
+            ])))
+            .with_body(success(
+                json!({"successful_key_count": 2, "unsuccessful_keys": []}),
+            ))
+            .create();
+
+        let archive = concat!(
+            r#"{"key":"kept","value":"aGVsbG8=","metadata":{"v":1}}"#,
+            "\n\n",
+            r#"{"key":"gone","value":"eA==","expiration":1000}"#,
+            "\n",
+            r#"{"key":"later","value":"eA==","expiration":4102444800}"#,
+            "\n",
+        );
+        let client = client(&server);
+        let summary = restore(
+            &KvStore::new(&client, "account", "target"),
+            archive.as_bytes(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            summary,
+            RestoreSummary {
+                restored: 2,
+                expired: 1
+            }
+        );
+        write.assert();
+    }
+
+    #[tokio::test]
+    async fn test_restore_invalid_record() {
+        let server = Server::new_async().await;
+        let client = client(&server);
+        let archive = "{\"key\":\"a\",\"value\":\"YQ==\"}\nnot json\n";
+        let result = restore(
+            &KvStore::new(&client, "account", "target"),
+            archive.as_bytes(),
+        )
+        .await;
+        assert!(matches!(
+            result,
+            Err(BackupError::InvalidRecord { line: 2, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_copy() {
+        let mut server = Server::new_async().await;
+        let mocks = mock_source(&mut server);
+        let bulk = "/accounts/account/storage/kv/namespaces/target/bulk";
+        let first_page = server
+            .mock("PUT", bulk)
+            .match_body(Matcher::Json(json!([{
+                "key": "config:a",
+                "value": "aGVsbG8=",
+                "expiration": 4102444800u64,
+                "base64": true,
+                "metadata": {"owner": "ops"}
+            }])))
+            .with_body(success(json!({"successful_key_count": 1})))
+            .create();
+        let second_page = server
+            .mock("PUT", bulk)
+            .match_body(Matcher::Json(json!([
+                {"key": "config:b", "value": "/wA=", "base64": true}
+            ])))
+            .with_body(success(json!({"successful_key_count": 1})))
+            .create();
+
+        let client = client(&server);
+        let copied = copy(
+            &KvStore::new(&client, "account", "source"),
+            &KvStore::new(&client, "account", "target"),
+            Some("config:"),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(copied, 2);
+        first_page.assert();
+        second_page.assert();
+        for mock in mocks {
+            mock.assert();
+        }
+    }
+
+    #[test]
+    fn test_backup_record_round_trip() {
+        let record = BackupRecord {
+            key: "k".to_string(),
+            value: vec![1, 2, 3],
+            expiration: None,
+            metadata: None,
+        };
+        let line = serde_json::to_string(&record).unwrap();
+        assert_eq!(line, r#"{"key":"k","value":"AQID"}"#);
+        assert_eq!(serde_json::from_str::<BackupRecord>(&line).unwrap(), record);
+    }
+}
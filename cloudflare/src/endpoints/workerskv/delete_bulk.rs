@@ -1,3 +1,4 @@
+use crate::endpoints::workerskv::write_bulk::MAX_BULK_KEYS;
 use crate::endpoints::workerskv::WorkersKvBulkResult;
 use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
 use crate::framework::response::ApiSuccess;
@@ -30,7 +31,7 @@ impl EndpointSpec for DeleteBulk<'_> {
     }
     #[inline]
     fn body(&self) -> Option<RequestBody<'_>> {
-        if self.bulk_keys.len() > 10_000 {
+        if self.bulk_keys.len() > MAX_BULK_KEYS {
             panic!("Bulk delete request can only contain up to 10,000 keys.");
         }
         let body = serde_json::to_string(&self.bulk_keys).unwrap();
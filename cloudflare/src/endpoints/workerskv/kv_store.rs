@@ -0,0 +1,386 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use futures_util::future;
+use futures_util::stream::{self, StreamExt, TryStreamExt};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use super::delete_bulk::DeleteBulk;
+use super::delete_key::DeleteKey;
+use super::list_namespace_keys::{ListNamespaceKeys, ListNamespaceKeysParams};
+use super::read_bulk::{
+    BulkValueType, BulkValueWithMetadata, ReadBulk, ReadBulkParams, MAX_BULK_GET_KEYS,
+};
+use super::read_key::{KvValue, ReadKey, ReadKeyWithExpiration};
+use super::read_key_metadata::ReadKeyMetadata;
+use super::write_bulk::{KeyValuePair, WriteBulk, MAX_BULK_BYTES, MAX_BULK_KEYS};
+use super::write_key::{WriteKey, WriteKeyBody, WriteKeyParams};
+use super::{Key, WorkersKvBulkResult};
+use crate::framework::client::async_api::Client;
+use crate::framework::endpoint::EndpointSpec;
+use crate::framework::response::{ApiFailure, ApiSuccess};
+
+#[derive(thiserror::Error, Debug)]
+/// Errors encountered by a [`KvStore`]
+pub enum KvError {
+    #[error("Cloudflare API request failed: {0}")]
+    Api(#[from] ApiFailure),
+    #[error("{} keys were still not written or deleted after retrying", .0.len())]
+    UnsuccessfulKeys(Vec<String>),
+    #[error("Value or metadata is not the expected JSON: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// A value read together with its expiration and metadata
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KvEntry<T, M = serde_json::Value> {
+    pub value: T,
+    /// When the key expires, `None` if it never does
+    pub expiration: Option<DateTime<Utc>>,
+    pub metadata: Option<M>,
+}
+
+/// A Workers KV namespace. Bulk operations are split into request-sized chunks that run
+/// concurrently, and keys the API reports as unsuccessful are retried.
+pub struct KvStore<'a> {
+    client: &'a Client,
+    account_id: &'a str,
+    namespace_id: &'a str,
+    pub(super) concurrency: usize,
+    retries: usize,
+}
+
+impl<'a> KvStore<'a> {
+    /// Chunks sent at the same time unless changed with [`KvStore::concurrency`]
+    pub const DEFAULT_CONCURRENCY: usize = 4;
+    /// Retry passes for unsuccessful keys unless changed with [`KvStore::retries`]
+    pub const DEFAULT_RETRIES: usize = 3;
+    /// Keys fetched per page when listing
+    const LIST_PAGE_SIZE: u16 = 1000;
+
+    pub fn new(client: &'a Client, account_id: &'a str, namespace_id: &'a str) -> Self {
+        KvStore {
+            client,
+            account_id,
+            namespace_id,
+            concurrency: Self::DEFAULT_CONCURRENCY,
+            retries: Self::DEFAULT_RETRIES,
+        }
+    }
+
+    /// Sets how many bulk chunks are sent at the same time
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Sets how many times unsuccessful keys of a bulk operation are retried
+    pub fn retries(mut self, retries: usize) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Reads a value, or `None` if the key does not exist
+    pub async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, KvError> {
+        let read = ReadKey {
+            account_identifier: self.account_id,
+            namespace_identifier: self.namespace_id,
+            key,
+        };
+        optional(self.client.request(&read).await)
+    }
+
+    /// Reads a value with its expiration and metadata, or `None` if the key does not exist
+    pub async fn get_with_metadata(&self, key: &str) -> Result<Option<KvEntry<Vec<u8>>>, KvError> {
+        let read = ReadKeyWithExpiration {
+            account_identifier: self.account_id,
+            namespace_identifier: self.namespace_id,
+            key,
+        };
+        let metadata = ReadKeyMetadata {
+            account_identifier: self.account_id,
+            namespace_identifier: self.namespace_id,
+            key,
+        };
+        let (value, metadata) =
+            future::join(self.client.request(&read), self.client.request(&metadata)).await;
+        // The key may be deleted between the two reads
+        let (Some(KvValue { value, expiration }), Some(metadata)) =
+            (optional(value)?, optional(metadata)?)
+        else {
+            return Ok(None);
+        };
+        Ok(Some(KvEntry {
+            value,
+            expiration,
+            metadata: metadata.result,
+        }))
+    }
+
+    /// Reads a value stored as JSON, or `None` if the key does not exist
+    pub async fn get_json<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>, KvError> {
+        match self.get(key).await? {
+            Some(value) => Ok(Some(serde_json::from_slice(&value)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Reads a value stored as JSON with its expiration and typed metadata, or `None` if the key
+    /// does not exist
+    pub async fn get_json_with_metadata<T: DeserializeOwned, M: DeserializeOwned>(
+        &self,
+        key: &str,
+    ) -> Result<Option<KvEntry<T, M>>, KvError> {
+        let Some(entry) = self.get_with_metadata(key).await? else {
+            return Ok(None);
+        };
+        Ok(Some(KvEntry {
+            value: serde_json::from_slice(&entry.value)?,
+            expiration: entry.expiration,
+            metadata: entry.metadata.map(serde_json::from_value).transpose()?,
+        }))
+    }
+
+    /// Reads many keys, splitting them into bulk reads that run concurrently. Every key is in
+    /// the returned map, with `None` if it does not exist. A stored JSON `null` is
+    /// `Some(Value::Null)`, since values are read with their metadata to tell the two apart.
+    pub async fn get_many(
+        &self,
+        keys: Vec<String>,
+        value_type: BulkValueType,
+    ) -> Result<HashMap<String, Option<serde_json::Value>>, KvError> {
+        let mut values: HashMap<_, _> = keys.iter().map(|key| (key.clone(), None)).collect();
+        let results: Vec<_> = stream::iter(keys.chunks(MAX_BULK_GET_KEYS))
+            .map(|chunk| async move {
+                let read = ReadBulk {
+                    account_identifier: self.account_id,
+                    namespace_identifier: self.namespace_id,
+                    params: ReadBulkParams {
+                        keys: chunk.to_vec(),
+                        value_type: Some(value_type),
+                        // Missing keys are `null`, while existing keys are objects
+                        with_metadata: Some(true),
+                    },
+                };
+                Ok::<_, KvError>(self.client.request(&read).await?.result.values)
+            })
+            .buffer_unordered(self.concurrency)
+            .try_collect()
+            .await?;
+        for (key, value) in results.into_iter().flatten() {
+            if !value.is_null() {
+                let entry: BulkValueWithMetadata = serde_json::from_value(value)?;
+                values.insert(key, Some(entry.value));
+            }
+        }
+        Ok(values)
+    }
+
+    /// Writes a value that never expires
+    pub async fn put(&self, key: &str, value: impl Into<Vec<u8>>) -> Result<(), KvError> {
+        let body = WriteKeyBody::Value(value.into());
+        self.write(key, WriteKeyParams::default(), body).await
+    }
+
+    /// Writes any serializable value as JSON
+    pub async fn put_json<T: Serialize>(
+        &self,
+        key: &str,
+        value: &T,
+        params: WriteKeyParams,
+    ) -> Result<(), KvError> {
+        self.write(key, params, WriteKeyBody::json(value)?).await
+    }
+
+    /// Writes any serializable value as JSON, with metadata
+    pub async fn put_json_with_metadata<T: Serialize, M: Serialize>(
+        &self,
+        key: &str,
+        value: &T,
+        metadata: &M,
+        params: WriteKeyParams,
+    ) -> Result<(), KvError> {
+        let body = WriteKeyBody::json_with_metadata(value, metadata)?;
+        self.write(key, params, body).await
+    }
+
+    async fn write(
+        &self,
+        key: &str,
+        params: WriteKeyParams,
+        body: WriteKeyBody,
+    ) -> Result<(), KvError> {
+        self.client
+            .request(&WriteKey {
+                account_identifier: self.account_id,
+                namespace_identifier: self.namespace_id,
+                key,
+                params,
+                body,
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Deletes a key. Deleting a missing key succeeds.
+    pub async fn delete(&self, key: &str) -> Result<(), KvError> {
+        self.client
+            .request(&DeleteKey {
+                account_identifier: self.account_id,
+                namespace_identifier: self.namespace_id,
+                key,
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Lists every key starting with `prefix`, following pagination cursors
+    pub async fn list(&self, prefix: Option<&str>) -> Result<Vec<Key>, KvError> {
+        let mut keys = Vec::new();
+        let mut cursor = None;
+        loop {
+            let (page, next) = self.list_page(prefix, cursor).await?;
+            keys.extend(page);
+            match next {
+                Some(next) => cursor = Some(next),
+                None => return Ok(keys),
+            }
+        }
+    }
+
+    /// Lists one page of keys, along with the cursor of the next page if there is one
+    pub(super) async fn list_page(
+        &self,
+        prefix: Option<&str>,
+        cursor: Option<String>,
+    ) -> Result<(Vec<Key>, Option<String>), KvError> {
+        let response = self
+            .client
+            .request(&ListNamespaceKeys {
+                account_identifier: self.account_id,
+                namespace_identifier: self.namespace_id,
+                params: ListNamespaceKeysParams {
+                    limit: Some(Self::LIST_PAGE_SIZE),
+                    cursor,
+                    prefix: prefix.map(str::to_string),
+                },
+            })
+            .await?;
+        // The last page has an empty cursor
+        let next = response
+            .result_info
+            .as_ref()
+            .and_then(|info| info.get("cursor")?.as_str())
+            .filter(|cursor| !cursor.is_empty())
+            .map(str::to_string);
+        Ok((response.result, next))
+    }
+
+    /// Writes many pairs, splitting them to fit the bulk write limits
+    pub async fn put_many(&self, pairs: Vec<KeyValuePair>) -> Result<(), KvError> {
+        let mut pending = pairs;
+        for _ in 0..=self.retries {
+            let chunks = chunk_pairs(&pending, MAX_BULK_KEYS, MAX_BULK_BYTES);
+            let unsuccessful = self
+                .run_chunks(chunks, |chunk| WriteBulk {
+                    account_identifier: self.account_id,
+                    namespace_identifier: self.namespace_id,
+                    bulk_key_value_pairs: chunk,
+                })
+                .await?;
+            pending.retain(|pair| unsuccessful.contains(&pair.key));
+            if pending.is_empty() {
+                return Ok(());
+            }
+        }
+        Err(KvError::UnsuccessfulKeys(
+            pending.into_iter().map(|pair| pair.key).collect(),
+        ))
+    }
+
+    /// Deletes many keys, splitting them to fit the bulk delete limits
+    pub async fn delete_many(&self, keys: Vec<String>) -> Result<(), KvError> {
+        let mut pending = keys;
+        for _ in 0..=self.retries {
+            let chunks = pending
+                .chunks(MAX_BULK_KEYS)
+                .map(<[String]>::to_vec)
+                .collect();
+            let unsuccessful = self
+                .run_chunks(chunks, |chunk| DeleteBulk {
+                    account_identifier: self.account_id,
+                    namespace_identifier: self.namespace_id,
+                    bulk_keys: chunk,
+                })
+                .await?;
+            pending.retain(|key| unsuccessful.contains(key));
+            if pending.is_empty() {
+                return Ok(());
+            }
+        }
+        Err(KvError::UnsuccessfulKeys(pending))
+    }
+
+    /// Sends every chunk and returns the keys the API reported as unsuccessful
+    async fn run_chunks<T, E>(
+        &self,
+        chunks: Vec<Vec<T>>,
+        endpoint: impl Fn(Vec<T>) -> E,
+    ) -> Result<HashSet<String>, KvError>
+    where
+        E: EndpointSpec<
+                JsonResponse = WorkersKvBulkResult,
+                ResponseType = ApiSuccess<WorkersKvBulkResult>,
+            > + Send
+            + Sync,
+    {
+        let results: Vec<Vec<String>> = stream::iter(chunks)
+            .map(|chunk| {
+                let endpoint = endpoint(chunk);
+                async move {
+                    let result = self.client.request(&endpoint).await?.result;
+                    Ok::<_, KvError>(result.unsuccessful_keys.unwrap_or_default())
+                }
+            })
+            .buffer_unordered(self.concurrency)
+            .try_collect()
+            .await?;
+        Ok(results.into_iter().flatten().collect())
+    }
+}
+
+/// Maps a missing key to `None`
+fn optional<T>(response: Result<T, ApiFailure>) -> Result<Option<T>, KvError> {
+    match response {
+        Ok(value) => Ok(Some(value)),
+        Err(ApiFailure::Error(status, _)) if status == reqwest::StatusCode::NOT_FOUND => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Splits pairs into chunks of at most `max_keys` pairs and `max_bytes` of JSON
+pub(super) fn chunk_pairs(
+    pairs: &[KeyValuePair],
+    max_keys: usize,
+    max_bytes: usize,
+) -> Vec<Vec<KeyValuePair>> {
+    let mut chunks = Vec::new();
+    let mut chunk = Vec::new();
+    // Opening and closing brackets of the JSON array
+    let mut chunk_bytes = 2;
+    for pair in pairs {
+        // Serializing a pair of strings cannot fail. One byte for the separating comma.
+        let pair_bytes = serde_json::to_vec(pair).unwrap().len() + 1;
+        if !chunk.is_empty() && (chunk.len() == max_keys || chunk_bytes + pair_bytes > max_bytes) {
+            chunks.push(std::mem::take(&mut chunk));
+            chunk_bytes = 2;
+        }
+        chunk.push(pair.clone());
+        chunk_bytes += pair_bytes;
+    }
+    if !chunk.is_empty() {
+        chunks.push(chunk);
+    }
+    chunks
+}
@@ -0,0 +1,327 @@
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::{TimeZone, Utc};
+    use mockito::{Matcher, Server};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use super::super::kv_store::chunk_pairs;
+    use super::super::read_bulk::{BulkValueType, ReadBulk, ReadBulkParams};
+    use super::super::write_bulk::{KeyValuePair, MAX_BULK_BYTES, MAX_BULK_KEYS};
+    use super::super::write_key::WriteKeyParams;
+    use super::super::{KvEntry, KvError, KvStore};
+    use crate::framework::auth::Credentials;
+    use crate::framework::client::async_api::Client;
+    use crate::framework::client::ClientConfig;
+    use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+    use crate::framework::Environment;
+
+    fn pair(key: impl Into<String>, value: impl Into<String>) -> KeyValuePair {
+        KeyValuePair {
+            key: key.into(),
+            value: value.into(),
+            expiration: None,
+            expiration_ttl: None,
+            base64: None,
+            metadata: None,
+        }
+    }
+
+    fn client(server: &Server) -> Client {
+        Client::new(
+            Credentials::UserAuthToken {
+                token: "api-token".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap()
+    }
+
+    fn success(result: serde_json::Value) -> String {
+        json!({"result": result, "success": true, "errors": [], "messages": []}).to_string()
+    }
+
+    #[test]
+    fn test_chunk_pairs_by_count() {
+        let pairs: Vec<_> = (0..MAX_BULK_KEYS * 2 + 1)
+            .map(|i| pair(format!("key-{i}"), "v"))
+            .collect();
+        let chunks = chunk_pairs(&pairs, MAX_BULK_KEYS, MAX_BULK_BYTES);
+        let sizes: Vec<_> = chunks.iter().map(Vec::len).collect();
+        assert_eq!(sizes, vec![MAX_BULK_KEYS, MAX_BULK_KEYS, 1]);
+        assert_eq!(chunks[2][0].key, format!("key-{}", MAX_BULK_KEYS * 2));
+    }
+
+    #[test]
+    fn test_chunk_pairs_by_size() {
+        let pairs: Vec<_> = (0..5)
+            .map(|i| pair(format!("big-{i}"), "x".repeat(10)))
+            .collect();
+        // Room for exactly three pairs
+        let max_bytes = serde_json::to_vec(&pairs[..3]).unwrap().len() + 1;
+        let chunks = chunk_pairs(&pairs, MAX_BULK_KEYS, max_bytes);
+        let sizes: Vec<_> = chunks.iter().map(Vec::len).collect();
+        assert_eq!(sizes, vec![3, 2]);
+        for chunk in chunks {
+            assert!(serde_json::to_vec(&chunk).unwrap().len() <= max_bytes);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_put_many_retries_unsuccessful_keys() {
+        let mut server = Server::new_async().await;
+        let path = "/accounts/account/storage/kv/namespaces/ns/bulk";
+        let first = server
+            .mock("PUT", path)
+            .match_body(Matcher::Regex(r#""key":"a".*"key":"b""#.to_string()))
+            .with_body(success(json!({
+                "successful_key_count": 1,
+                "unsuccessful_keys": ["b"]
+            })))
+            .create();
+        let retry = server
+            .mock("PUT", path)
+            .match_body(Matcher::Json(json!([{"key": "b", "value": "2"}])))
+            .with_body(success(json!({
+                "successful_key_count": 1,
+                "unsuccessful_keys": []
+            })))
+            .create();
+
+        let client = client(&server);
+        KvStore::new(&client, "account", "ns")
+            .put_many(vec![pair("a", "1"), pair("b", "2")])
+            .await
+            .unwrap();
+        first.assert();
+        retry.assert();
+    }
+
+    #[tokio::test]
+    async fn test_delete_many_gives_up_after_retries() {
+        let mut server = Server::new_async().await;
+        let delete = server
+            .mock("DELETE", "/accounts/account/storage/kv/namespaces/ns/bulk")
+            .with_body(success(json!({
+                "successful_key_count": 0,
+                "unsuccessful_keys": ["stuck"]
+            })))
+            .expect(3)
+            .create();
+
+        let client = client(&server);
+        let result = KvStore::new(&client, "account", "ns")
+            .retries(2)
+            .delete_many(vec!["stuck".to_string()])
+            .await;
+        delete.assert();
+        assert!(matches!(result, Err(KvError::UnsuccessfulKeys(keys)) if keys == ["stuck"]));
+    }
+
+    #[tokio::test]
+    async fn test_get_missing_key_and_list_pages() {
+        let mut server = Server::new_async().await;
+        let missing = server
+            .mock(
+                "GET",
+                "/accounts/account/storage/kv/namespaces/ns/values/missing",
+            )
+            .with_status(404)
+            .with_body(
+                json!({
+                    "result": null, "success": false, "messages": [],
+                    "errors": [{"code": 10009, "message": "get: 'key not found'"}]
+                })
+                .to_string(),
+            )
+            .create();
+        let page_one = server
+            .mock("GET", "/accounts/account/storage/kv/namespaces/ns/keys")
+            .match_query(Matcher::Regex("^limit=1000&prefix=user%3A$".to_string()))
+            .with_body(
+                json!({
+                    "result": [{"name": "user:1"}],
+                    "result_info": {"count": 1, "cursor": "next-page"},
+                    "success": true, "errors": [], "messages": []
+                })
+                .to_string(),
+            )
+            .create();
+        let page_two = server
+            .mock("GET", "/accounts/account/storage/kv/namespaces/ns/keys")
+            .match_query(Matcher::UrlEncoded("cursor".into(), "next-page".into()))
+            .with_body(
+                json!({
+                    "result": [{"name": "user:2"}],
+                    "result_info": {"count": 1, "cursor": ""},
+                    "success": true, "errors": [], "messages": []
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = client(&server);
+        let store = KvStore::new(&client, "account", "ns");
+        assert_eq!(store.get("missing").await.unwrap(), None);
+
+        let names: Vec<_> = store
+            .list(Some("user:"))
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|key| key.name)
+            .collect();
+        assert_eq!(names, vec!["user:1", "user:2"]);
+        missing.assert();
+        page_one.assert();
+        page_two.assert();
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Session {
+        user: String,
+        visits: u32,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct SessionMetadata {
+        created_by: String,
+    }
+
+    #[tokio::test]
+    async fn test_json_values_with_metadata() {
+        let mut server = Server::new_async().await;
+        let path = "/accounts/account/storage/kv/namespaces/ns/values/session";
+        let write = server
+            .mock("PUT", path)
+            .match_query(Matcher::UrlEncoded("expiration_ttl".into(), "3600".into()))
+            .match_body(Matcher::AllOf(vec![
+                Matcher::Regex(r#"\{"created_by":"login"\}"#.to_string()),
+                Matcher::Regex(r#"\{"user":"ada","visits":3\}"#.to_string()),
+            ]))
+            .with_body(success(json!(null)))
+            .create();
+        let read = server
+            .mock("GET", path)
+            .with_header("expiration", "1700000000")
+            .with_body(r#"{"user":"ada","visits":3}"#)
+            .create();
+        let metadata = server
+            .mock(
+                "GET",
+                "/accounts/account/storage/kv/namespaces/ns/metadata/session",
+            )
+            .with_body(success(json!({"created_by": "login"})))
+            .create();
+
+        let client = client(&server);
+        let store = KvStore::new(&client, "account", "ns");
+        let session = Session {
+            user: "ada".to_string(),
+            visits: 3,
+        };
+        let created_by = SessionMetadata {
+            created_by: "login".to_string(),
+        };
+        store
+            .put_json_with_metadata(
+                "session",
+                &session,
+                &created_by,
+                WriteKeyParams::expire_after(Duration::from_secs(3600)),
+            )
+            .await
+            .unwrap();
+
+        let entry = store
+            .get_json_with_metadata::<Session, SessionMetadata>("session")
+            .await
+            .unwrap();
+        assert_eq!(
+            entry,
+            Some(KvEntry {
+                value: session,
+                expiration: Utc.timestamp_opt(1_700_000_000, 0).single(),
+                metadata: Some(created_by),
+            })
+        );
+        write.assert();
+        read.assert();
+        metadata.assert();
+
+        // TTLs beyond what the API can represent saturate instead of wrapping negative
+        assert_eq!(
+            WriteKeyParams::expire_after(Duration::MAX).expiration_ttl,
+            Some(i64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_read_bulk() {
+        let endpoint = ReadBulk {
+            account_identifier: "account",
+            namespace_identifier: "ns",
+            params: ReadBulkParams {
+                keys: vec!["a".to_string(), "b".to_string()],
+                value_type: Some(BulkValueType::Json),
+                with_metadata: Some(true),
+            },
+        };
+        assert_eq!(endpoint.method(), Method::POST);
+        assert_eq!(
+            endpoint.path(),
+            "accounts/account/storage/kv/namespaces/ns/bulk/get"
+        );
+        let Some(RequestBody::Json(body)) = endpoint.body() else {
+            panic!("Expected JSON body");
+        };
+        assert_eq!(
+            body,
+            r#"{"keys":["a","b"],"type":"json","withMetadata":true}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_many_chunks_and_merges() {
+        let mut server = Server::new_async().await;
+        let path = "/accounts/account/storage/kv/namespaces/ns/bulk/get";
+        let first = server
+            .mock("POST", path)
+            .match_body(Matcher::Regex(
+                r#""key-0".*"key-99"\],"type":"json","withMetadata":true"#.to_string(),
+            ))
+            .with_body(success(json!({"values": {
+                "key-0": {"value": {"n": 0}, "metadata": null},
+                "key-1": null,
+                "key-2": {"value": null, "metadata": {"stored": "null"}}
+            }})))
+            .create();
+        let second = server
+            .mock("POST", path)
+            .match_body(Matcher::Regex(r#"\["key-100".*"key-149"\]"#.to_string()))
+            .with_body(success(json!({"values": {
+                "key-149": {"value": [149], "metadata": null, "expiration": 1700000000}
+            }})))
+            .create();
+
+        let client = client(&server);
+        let keys = (0..150).map(|i| format!("key-{i}")).collect();
+        let values = KvStore::new(&client, "account", "ns")
+            .get_many(keys, BulkValueType::Json)
+            .await
+            .unwrap();
+
+        assert_eq!(values.len(), 150);
+        assert_eq!(values["key-0"], Some(json!({"n": 0})));
+        assert_eq!(values["key-1"], None);
+        // A stored `null` is not a missing key
+        assert_eq!(values["key-2"], Some(serde_json::Value::Null));
+        assert_eq!(values["key-50"], None);
+        assert_eq!(values["key-149"], Some(json!([149])));
+        first.assert();
+        second.assert();
+    }
+}
@@ -3,12 +3,17 @@ use chrono::DateTime;
 use chrono::{TimeZone, Utc};
 use serde::{Deserialize, Deserializer, Serialize};
 
+pub mod backup;
+mod backup_tests;
 pub mod create_namespace;
 pub mod delete_bulk;
 pub mod delete_key;
 pub mod get_namespace;
+mod kv_store;
+mod kv_store_tests;
 pub mod list_namespace_keys;
 pub mod list_namespaces;
+pub mod read_bulk;
 pub mod read_key;
 pub mod read_key_metadata;
 pub mod remove_namespace;
@@ -16,6 +21,8 @@ pub mod rename_namespace;
 pub mod write_bulk;
 pub mod write_key;
 
+pub use kv_store::{KvEntry, KvError, KvStore};
+
 /// Workers KV Namespace
 ///
 /// A Namespace is a collection of key-value pairs stored in Workers KV.
@@ -81,7 +88,7 @@ fn url_encode_key(key: &str) -> String {
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct WorkersKvBulkResult {
     /// Number of keys successfully updated.
-    pub successful_key_count: Option<i8>,
+    pub successful_key_count: Option<u32>,
 
     /// Name of the keys that failed to be fully updated. They should be retried.
     // TODO: Ambiguity with the official docs; it does not seem to be optional. It's an empty array if no keys failed.
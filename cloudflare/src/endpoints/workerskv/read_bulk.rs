@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::{ApiResult, ApiSuccess};
+use serde::{Deserialize, Serialize};
+
+/// Most keys in a single bulk read
+pub const MAX_BULK_GET_KEYS: usize = 100;
+
+/// Get multiple KV pairs from the namespace.
+///
+/// Body should contain up to [`MAX_BULK_GET_KEYS`] keys to be read. The limit is not checked
+/// before sending, and the API rejects larger requests; [`super::KvStore::get_many`] splits
+/// larger reads into several requests. Keys that do not exist are returned as `null`.
+///
+/// <https://developers.cloudflare.com/api/resources/kv/subresources/namespaces/methods/bulk_get/>
+#[derive(Debug)]
+pub struct ReadBulk<'a> {
+    pub account_identifier: &'a str,
+    pub namespace_identifier: &'a str,
+    pub params: ReadBulkParams,
+}
+
+impl EndpointSpec for ReadBulk<'_> {
+    type JsonResponse = WorkersKvBulkGetResult;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/storage/kv/namespaces/{}/bulk/get",
+            self.account_identifier, self.namespace_identifier
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody<'_>> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+    // default content-type is already application/json
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct ReadBulkParams {
+    /// Keys to read, at most 100.
+    pub keys: Vec<String>,
+    /// How values are returned. Defaults to text.
+    #[serde(rename = "type")]
+    pub value_type: Option<BulkValueType>,
+    /// Whether to return the metadata and expiration of each key along with its value.
+    #[serde(rename = "withMetadata")]
+    pub with_metadata: Option<bool>,
+}
+
+/// How values of a bulk read are returned
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BulkValueType {
+    /// Values are returned as strings
+    Text,
+    /// Values are parsed as JSON. The request fails if any value is not valid JSON.
+    Json,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct WorkersKvBulkGetResult {
+    /// Values keyed by name, `null` for missing keys. With `with_metadata`, each value is a
+    /// [`BulkValueWithMetadata`] object.
+    pub values: HashMap<String, serde_json::Value>,
+}
+
+impl ApiResult for WorkersKvBulkGetResult {}
+
+/// A value of a bulk read made with `with_metadata`
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct BulkValueWithMetadata {
+    pub value: serde_json::Value,
+    /// Arbitrary JSON that is associated with a key.
+    pub metadata: Option<serde_json::Value>,
+    /// The time, measured in number of seconds since the UNIX epoch, at which the key expires.
+    pub expiration: Option<i64>,
+}
@@ -1,12 +1,14 @@
 use crate::framework::endpoint::EndpointSpec;
 use crate::framework::endpoint::Method;
-use crate::framework::response::ApiResult;
+use crate::framework::response::{ApiResult, ApiSuccess, ResponseConverter};
+use chrono::{DateTime, TimeZone, Utc};
 
 /// Returns the value associated with the given key in the given namespace.
 ///
 /// Use URL-encoding to use special characters (for example, `:`, `!`, `%`) in the key name.
 /// If the KV-pair is set to expire at some point, the expiration time as measured in seconds since
-/// the UNIX epoch will be returned in the expiration response header.
+/// the UNIX epoch will be returned in the expiration response header. Use
+/// [`ReadKeyWithExpiration`] to read it.
 ///
 /// <https://developers.cloudflare.com/api/resources/kv/subresources/namespaces/subresources/values/methods/get/>
 #[derive(Debug)]
@@ -36,3 +38,61 @@ impl EndpointSpec for ReadKey<'_> {
         )
     }
 }
+
+/// Returns the value associated with the given key along with its expiration, which
+/// [`ReadKey`] drops.
+///
+/// <https://developers.cloudflare.com/api/resources/kv/subresources/namespaces/subresources/values/methods/get/>
+#[derive(Debug)]
+pub struct ReadKeyWithExpiration<'a> {
+    pub account_identifier: &'a str,
+    pub namespace_identifier: &'a str,
+    pub key: &'a str,
+}
+
+/// A value read from Workers KV
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KvValue {
+    pub value: Vec<u8>,
+    /// When the key expires, `None` if it never does
+    pub expiration: Option<DateTime<Utc>>,
+}
+
+impl ResponseConverter<()> for KvValue {
+    fn from_raw(value: Vec<u8>) -> Self {
+        KvValue {
+            value,
+            expiration: None,
+        }
+    }
+    fn from_raw_with_headers(value: Vec<u8>, headers: &http::HeaderMap) -> Self {
+        // Seconds since the UNIX epoch
+        let expiration = headers
+            .get("expiration")
+            .and_then(|header| header.to_str().ok()?.parse().ok())
+            .and_then(|seconds| Utc.timestamp_opt(seconds, 0).single());
+        KvValue { value, expiration }
+    }
+    fn from_json(_api: ApiSuccess<()>) -> Self {
+        panic!("This endpoint does not return JSON")
+    }
+}
+
+impl EndpointSpec for ReadKeyWithExpiration<'_> {
+    const IS_RAW_BODY: bool = true;
+
+    type JsonResponse = ();
+    type ResponseType = KvValue;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        ReadKey {
+            account_identifier: self.account_identifier,
+            namespace_identifier: self.namespace_identifier,
+            key: self.key,
+        }
+        .path()
+    }
+}
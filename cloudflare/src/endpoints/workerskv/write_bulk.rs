@@ -4,16 +4,23 @@ use crate::endpoints::workerskv::WorkersKvBulkResult;
 use crate::framework::response::ApiSuccess;
 use serde::{Deserialize, Serialize};
 
+/// Most key-value pairs in a single bulk write
+pub const MAX_BULK_KEYS: usize = 10_000;
+/// Largest bulk write request body, in bytes
+pub const MAX_BULK_BYTES: usize = 100 * 1024 * 1024;
+
 /// Write multiple keys and values at once.
 ///
-/// Body should be an array of up to 10,000 key-value pairs to be stored, along with optional expiration information.
+/// Body should be an array of up to [`MAX_BULK_KEYS`] key-value pairs to be stored, along with
+/// optional expiration information, and the entire request must be [`MAX_BULK_BYTES`] or less.
+/// These limits are not checked before sending, and the API rejects larger requests; callers
+/// must chunk, or use [`super::KvStore::put_many`], which splits larger writes into several
+/// requests.
 ///
 /// Existing values and expirations will be overwritten.
 /// If neither expiration nor expiration_ttl is specified, the key-value pair will never expire.
 /// If both are set, expiration_ttl is used and expiration is ignored.
 ///
-/// The entire request size must be 100 megabytes or less.
-///
 /// A `404` is returned if a write action is for a namespace ID the account doesn't have.
 ///
 /// <https://developers.cloudflare.com/api/resources/kv/subresources/namespaces/methods/bulk_update/>
@@ -39,10 +46,6 @@ impl EndpointSpec for WriteBulk<'_> {
     }
     #[inline]
     fn body(&self) -> Option<RequestBody<'_>> {
-        if self.bulk_key_value_pairs.len() > 10_000 {
-            panic!("Bulk write request must have 10,000 key-value pairs or less.");
-        }
-        // TODO: The entire request size must be 100 megabytes or less.
         let body = serde_json::to_string(&self.bulk_key_value_pairs).unwrap();
         Some(RequestBody::Json(body))
     }
@@ -58,4 +61,6 @@ pub struct KeyValuePair {
     pub expiration: Option<i64>,
     pub expiration_ttl: Option<i64>,
     pub base64: Option<bool>,
+    /// Arbitrary JSON that is associated with a key.
+    pub metadata: Option<serde_json::Value>,
 }
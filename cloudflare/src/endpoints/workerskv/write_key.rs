@@ -1,8 +1,10 @@
 use crate::framework::endpoint::{serialize_query, EndpointSpec, MultipartBody, MultipartPart};
 use crate::framework::endpoint::{Method, RequestBody};
 use crate::framework::response::ApiSuccess;
+use chrono::{DateTime, Utc};
 use serde::Serialize;
 use std::borrow::Cow;
+use std::time::Duration;
 
 /// Write a value identified by a key.
 ///
@@ -76,6 +78,24 @@ pub struct WriteKeyParams {
     pub expiration_ttl: Option<i64>,
 }
 
+impl WriteKeyParams {
+    /// Expires the key `ttl` after it is written. KV rejects TTLs under 60 seconds.
+    pub fn expire_after(ttl: Duration) -> Self {
+        WriteKeyParams {
+            expiration: None,
+            expiration_ttl: Some(i64::try_from(ttl.as_secs()).unwrap_or(i64::MAX)),
+        }
+    }
+
+    /// Expires the key at the given time
+    pub fn expire_at(expiration: DateTime<Utc>) -> Self {
+        WriteKeyParams {
+            expiration: Some(expiration.timestamp()),
+            expiration_ttl: None,
+        }
+    }
+}
+
 #[derive(Serialize, Clone, Debug)]
 pub struct WriteKeyBodyMetadata {
     /// The value to store.
@@ -84,6 +104,19 @@ pub struct WriteKeyBodyMetadata {
     pub metadata: serde_json::Value,
 }
 
+impl WriteKeyBodyMetadata {
+    /// Pairs a value with any serializable metadata
+    pub fn new<M: Serialize>(
+        value: impl Into<Vec<u8>>,
+        metadata: &M,
+    ) -> Result<Self, serde_json::Error> {
+        Ok(WriteKeyBodyMetadata {
+            value: value.into(),
+            metadata: serde_json::to_value(metadata)?,
+        })
+    }
+}
+
 impl MultipartBody for WriteKeyBodyMetadata {
     fn parts(&self) -> Vec<(String, MultipartPart)> {
         vec![
@@ -108,3 +141,21 @@ pub enum WriteKeyBody {
     /// The value to store with metadata.
     Metadata(WriteKeyBodyMetadata),
 }
+
+impl WriteKeyBody {
+    /// Stores any serializable value as JSON
+    pub fn json<T: Serialize>(value: &T) -> Result<Self, serde_json::Error> {
+        Ok(WriteKeyBody::Value(serde_json::to_vec(value)?))
+    }
+
+    /// Stores any serializable value as JSON, with metadata
+    pub fn json_with_metadata<T: Serialize, M: Serialize>(
+        value: &T,
+        metadata: &M,
+    ) -> Result<Self, serde_json::Error> {
+        Ok(WriteKeyBody::Metadata(WriteKeyBodyMetadata::new(
+            serde_json::to_vec(value)?,
+            metadata,
+        )?))
+    }
+}
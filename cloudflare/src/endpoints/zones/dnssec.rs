@@ -0,0 +1,239 @@
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::{ApiResult, ApiSuccess};
+use chrono::offset::Utc;
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+
+/// DNSSEC Details
+/// Details about DNSSEC status and configuration for a zone
+/// <https://developers.cloudflare.com/api/resources/dns/subresources/dnssec/methods/get/>
+#[derive(Debug)]
+pub struct GetDnssec<'a> {
+    pub zone_identifier: &'a str,
+}
+
+impl EndpointSpec for GetDnssec<'_> {
+    type JsonResponse = DnssecDetails;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!("zones/{}/dnssec", self.zone_identifier)
+    }
+}
+
+/// Edit DNSSEC Status
+/// Enable or disable DNSSEC, or change its multi-signer and pre-signed options
+/// <https://developers.cloudflare.com/api/resources/dns/subresources/dnssec/methods/edit/>
+#[derive(Debug)]
+pub struct EditDnssec<'a> {
+    pub zone_identifier: &'a str,
+    pub params: EditDnssecParams,
+}
+
+impl EndpointSpec for EditDnssec<'_> {
+    type JsonResponse = DnssecDetails;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+    fn path(&self) -> String {
+        format!("zones/{}/dnssec", self.zone_identifier)
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody<'_>> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+}
+
+impl<'a> EditDnssec<'a> {
+    /// Enable DNSSEC for the zone. The zone stays `pending` until the DS record is published at
+    /// the registrar.
+    pub fn enable(zone_identifier: &'a str) -> Self {
+        Self {
+            zone_identifier,
+            params: EditDnssecParams {
+                status: Some(DnssecEditStatus::Active),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Disable DNSSEC for the zone. Remove the DS record at the registrar first.
+    pub fn disable(zone_identifier: &'a str) -> Self {
+        Self {
+            zone_identifier,
+            params: EditDnssecParams {
+                status: Some(DnssecEditStatus::Disabled),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct EditDnssecParams {
+    /// If true, multi-signer DNSSEC is enabled on the zone, allowing multiple providers to serve a
+    /// DNSSEC-signed zone at the same time.
+    pub dnssec_multi_signer: Option<bool>,
+    /// If true, allows Cloudflare to transfer in a DNSSEC-signed zone including signatures from an
+    /// external provider, without requiring Cloudflare to sign any records on the fly.
+    pub dnssec_presigned: Option<bool>,
+    /// Status of DNSSEC, based on user-desired state and presence of necessary records.
+    pub status: Option<DnssecEditStatus>,
+}
+
+/// The statuses that can be requested through [`EditDnssec`].
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DnssecEditStatus {
+    Active,
+    Disabled,
+}
+
+/// Delete DNSSEC Records
+/// Delete DNSSEC for the zone, removing all of its keys
+/// <https://developers.cloudflare.com/api/resources/dns/subresources/dnssec/methods/delete/>
+#[derive(Debug)]
+pub struct DeleteDnssec<'a> {
+    pub zone_identifier: &'a str,
+}
+
+impl EndpointSpec for DeleteDnssec<'_> {
+    type JsonResponse = serde_json::Value;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    fn path(&self) -> String {
+        format!("zones/{}/dnssec", self.zone_identifier)
+    }
+}
+
+/// Status of DNSSEC, based on user-desired state and presence of necessary records.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum DnssecStatus {
+    Active,
+    Pending,
+    Disabled,
+    PendingDisabled,
+    Error,
+}
+
+/// DNSSEC configuration of a zone
+/// <https://developers.cloudflare.com/api/resources/dns/subresources/dnssec/models/dnssec/>
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct DnssecDetails {
+    /// Algorithm key code.
+    pub algorithm: Option<String>,
+    /// Digest hash.
+    pub digest: Option<String>,
+    /// Type of digest algorithm.
+    pub digest_algorithm: Option<String>,
+    /// Coded type for digest algorithm.
+    pub digest_type: Option<String>,
+    /// If true, multi-signer DNSSEC is enabled on the zone.
+    #[serde(default)]
+    pub dnssec_multi_signer: bool,
+    /// If true, allows Cloudflare to transfer in a DNSSEC-signed zone including signatures from an
+    /// external provider.
+    #[serde(default)]
+    pub dnssec_presigned: bool,
+    /// Full DS record.
+    pub ds: Option<String>,
+    /// Flag for DNSSEC record.
+    pub flags: Option<u16>,
+    /// Code for key tag.
+    pub key_tag: Option<u16>,
+    /// Algorithm key type.
+    pub key_type: Option<String>,
+    /// When DNSSEC was last modified.
+    pub modified_on: Option<DateTime<Utc>>,
+    /// Public key for DS record.
+    pub public_key: Option<String>,
+    /// Status of DNSSEC, based on user-desired state and presence of necessary records.
+    pub status: DnssecStatus,
+}
+
+impl DnssecDetails {
+    /// Renders the DS record in the `<key tag> <algorithm> <digest type> <digest>` form that
+    /// registrars ask for when DNSSEC is enabled.
+    ///
+    /// Returns `None` until Cloudflare has generated the signing key for the zone.
+    pub fn registrar_ds_record(&self) -> Option<String> {
+        Some(format!(
+            "{} {} {} {}",
+            self.key_tag?,
+            self.algorithm.as_deref()?,
+            self.digest_type.as_deref()?,
+            self.digest.as_deref()?
+        ))
+    }
+}
+
+impl ApiResult for DnssecDetails {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dnssec_deserialization() {
+        let json = r#"
+        {
+            "algorithm": "13",
+            "digest": "48E939042E82C22542CB377B580DFDC52A361CEFDC72E7F9107E2B6BD9306A45",
+            "digest_algorithm": "SHA256",
+            "digest_type": "2",
+            "dnssec_multi_signer": false,
+            "dnssec_presigned": false,
+            "ds": "example.com. 3600 IN DS 16953 13 2 48E939042E82C22542CB377B580DFDC52A361CEFDC72E7F9107E2B6BD9306A45",
+            "flags": 257,
+            "key_tag": 42,
+            "key_type": "ECDSAP256SHA256",
+            "modified_on": "2014-01-01T05:20:00Z",
+            "public_key": "oXiGYrSTO+LSCJ3mohc8EP+CzF9KxBj8/ydXJ22pKuZP3VAC3/Md/k7xZfz470CoRyZJ6gV6vml07IC3d8xqhA==",
+            "status": "active"
+        }
+        "#;
+
+        let dnssec: DnssecDetails = serde_json::from_str(json).unwrap();
+        assert_eq!(dnssec.status, DnssecStatus::Active);
+        assert_eq!(dnssec.flags, Some(257));
+        assert_eq!(
+            dnssec.registrar_ds_record().unwrap(),
+            "42 13 2 48E939042E82C22542CB377B580DFDC52A361CEFDC72E7F9107E2B6BD9306A45"
+        );
+    }
+
+    #[test]
+    fn test_dnssec_disabled_has_no_ds_record() {
+        let dnssec: DnssecDetails = serde_json::from_str(r#"{"status": "disabled"}"#).unwrap();
+        assert_eq!(dnssec.status, DnssecStatus::Disabled);
+        assert_eq!(dnssec.registrar_ds_record(), None);
+    }
+
+    #[test]
+    fn test_edit_dnssec_params() {
+        let endpoint = EditDnssec::enable("zone-id");
+        assert_eq!(endpoint.method(), Method::PATCH);
+        assert_eq!(endpoint.path(), "zones/zone-id/dnssec");
+        assert_eq!(
+            serde_json::to_string(&endpoint.params).unwrap(),
+            r#"{"status":"active"}"#
+        );
+
+        let endpoint = EditDnssec::disable("zone-id");
+        assert_eq!(
+            serde_json::to_string(&endpoint.params).unwrap(),
+            r#"{"status":"disabled"}"#
+        );
+    }
+}
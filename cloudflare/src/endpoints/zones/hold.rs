@@ -0,0 +1,135 @@
+use crate::framework::endpoint::{serialize_query, EndpointSpec, Method};
+use crate::framework::response::{ApiResult, ApiSuccess};
+use serde::{Deserialize, Serialize};
+
+/// Get Zone Hold
+/// Retrieve whether the zone is subject to a zone hold, and metadata about the hold
+/// <https://developers.cloudflare.com/api/resources/zones/subresources/holds/methods/get/>
+#[derive(Debug)]
+pub struct ZoneHoldDetails<'a> {
+    pub zone_identifier: &'a str,
+}
+
+impl EndpointSpec for ZoneHoldDetails<'_> {
+    type JsonResponse = ZoneHold;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!("zones/{}/hold", self.zone_identifier)
+    }
+}
+
+/// Create Zone Hold
+/// Enforce a zone hold on the zone, blocking the creation and activation of zones with this
+/// zone's hostname
+/// <https://developers.cloudflare.com/api/resources/zones/subresources/holds/methods/create/>
+#[derive(Debug)]
+pub struct CreateZoneHold<'a> {
+    pub zone_identifier: &'a str,
+    pub params: CreateZoneHoldParams,
+}
+
+impl EndpointSpec for CreateZoneHold<'_> {
+    type JsonResponse = ZoneHold;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn path(&self) -> String {
+        format!("zones/{}/hold", self.zone_identifier)
+    }
+    #[inline]
+    fn query(&self) -> Option<String> {
+        serialize_query(&self.params)
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct CreateZoneHoldParams {
+    /// If provided, the zone hold will extend to block any subdomain of the given zone, as well
+    /// as SSL4SaaS Custom Hostnames. For example, a zone hold on a zone with the hostname
+    /// 'example.com' and include_subdomains=true will block 'example.com', 'staging.example.com',
+    /// 'api.staging.example.com', etc.
+    pub include_subdomains: Option<bool>,
+}
+
+/// Remove Zone Hold
+/// Stop enforcement of a zone hold on the zone, permanently or temporarily, allowing the creation
+/// and activation of zones with this zone's hostname
+/// <https://developers.cloudflare.com/api/resources/zones/subresources/holds/methods/delete/>
+#[derive(Debug)]
+pub struct RemoveZoneHold<'a> {
+    pub zone_identifier: &'a str,
+    pub params: RemoveZoneHoldParams,
+}
+
+impl EndpointSpec for RemoveZoneHold<'_> {
+    type JsonResponse = ZoneHold;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    fn path(&self) -> String {
+        format!("zones/{}/hold", self.zone_identifier)
+    }
+    #[inline]
+    fn query(&self) -> Option<String> {
+        serialize_query(&self.params)
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct RemoveZoneHoldParams {
+    /// If `hold_after` is provided, the hold will be temporarily disabled, then automatically
+    /// re-enabled by the system at the time specified in this RFC3339-formatted timestamp.
+    /// Otherwise, the hold will be disabled indefinitely.
+    pub hold_after: Option<String>,
+}
+
+/// Whether a zone is subject to a zone hold
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ZoneHold {
+    /// If true, the zone hold is in effect
+    pub hold: bool,
+    /// When the hold will be re-enabled, if it is temporarily disabled
+    pub hold_after: Option<String>,
+    /// If true, the hold also blocks subdomains of the zone
+    pub include_subdomains: Option<bool>,
+}
+
+impl ApiResult for ZoneHold {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zone_hold_queries() {
+        let create = CreateZoneHold {
+            zone_identifier: "zone-id",
+            params: CreateZoneHoldParams {
+                include_subdomains: Some(true),
+            },
+        };
+        assert_eq!(create.query(), Some("include_subdomains=true".to_string()));
+
+        let remove = RemoveZoneHold {
+            zone_identifier: "zone-id",
+            params: RemoveZoneHoldParams {
+                hold_after: Some("2023-01-31T15:56:36+00:00".to_string()),
+            },
+        };
+        assert_eq!(remove.method(), Method::DELETE);
+        assert_eq!(
+            remove.query(),
+            Some("hold_after=2023-01-31T15%3A56%3A36%2B00%3A00".to_string())
+        );
+    }
+}
@@ -1,2 +1,6 @@
+pub mod dnssec;
+pub mod hold;
 pub mod plan;
+pub mod settings;
+pub mod subscription;
 pub mod zone;
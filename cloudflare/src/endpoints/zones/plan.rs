@@ -1,6 +1,8 @@
-use serde::Deserialize;
+use crate::framework::endpoint::{EndpointSpec, Method};
+use crate::framework::response::{ApiResult, ApiSuccess};
+use serde::{Deserialize, Serialize};
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 /// Free plans won't have a Frequency, so most responses should accept Option instead.
 pub enum Frequency {
@@ -31,3 +33,100 @@ pub struct Plan {
     /// If the zone is allowed to subscribe to this plan
     pub can_subscribe: bool,
 }
+
+impl ApiResult for Plan {}
+impl ApiResult for Vec<Plan> {}
+
+/// List Available Plans
+/// Lists available plans the zone can subscribe to
+/// <https://developers.cloudflare.com/api/resources/zones/subresources/plans/methods/list/>
+#[derive(Debug)]
+pub struct ListAvailablePlans<'a> {
+    pub zone_identifier: &'a str,
+}
+
+impl EndpointSpec for ListAvailablePlans<'_> {
+    type JsonResponse = Vec<Plan>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!("zones/{}/available_plans", self.zone_identifier)
+    }
+}
+
+/// Available Plan Details
+/// Details of the available plan that the zone can subscribe to
+/// <https://developers.cloudflare.com/api/resources/zones/subresources/plans/methods/get/>
+#[derive(Debug)]
+pub struct AvailablePlanDetails<'a> {
+    pub zone_identifier: &'a str,
+    pub plan_identifier: &'a str,
+}
+
+impl EndpointSpec for AvailablePlanDetails<'_> {
+    type JsonResponse = Plan;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "zones/{}/available_plans/{}",
+            self.zone_identifier, self.plan_identifier
+        )
+    }
+}
+
+/// List Available Rate Plans
+/// Lists all rate plans the zone can subscribe to
+/// <https://developers.cloudflare.com/api/resources/zones/subresources/rate_plans/methods/get/>
+#[derive(Debug)]
+pub struct ListAvailableRatePlans<'a> {
+    pub zone_identifier: &'a str,
+}
+
+impl EndpointSpec for ListAvailableRatePlans<'_> {
+    type JsonResponse = Vec<RatePlan>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!("zones/{}/available_rate_plans", self.zone_identifier)
+    }
+}
+
+/// A rate plan, made up of the components that are billed for it
+#[derive(Deserialize, Debug)]
+pub struct RatePlan {
+    /// Plan identifier tag
+    pub id: String,
+    /// The plan name
+    pub name: Option<String>,
+    /// The monetary unit in which pricing information is displayed
+    pub currency: Option<String>,
+    /// The duration of the plan subscription
+    pub duration: Option<u32>,
+    /// The frequency at which you will be billed for this plan
+    pub frequency: Option<Frequency>,
+    /// Array of available components values for the plan
+    #[serde(default)]
+    pub components: Vec<RatePlanComponent>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct RatePlanComponent {
+    /// The default amount allocated
+    pub default: Option<f64>,
+    /// The unique component
+    pub name: Option<String>,
+    /// The unit price of the addon
+    pub unit_price: Option<f64>,
+}
+
+impl ApiResult for Vec<RatePlan> {}
@@ -0,0 +1,396 @@
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::{ApiResult, ApiSuccess};
+use chrono::offset::Utc;
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+
+/// Get All Zone Settings
+/// Available settings for your user in relation to a zone
+/// <https://developers.cloudflare.com/api/resources/zones/subresources/settings/>
+#[derive(Debug)]
+pub struct ListZoneSettings<'a> {
+    pub zone_identifier: &'a str,
+}
+
+impl EndpointSpec for ListZoneSettings<'_> {
+    type JsonResponse = Vec<ZoneSettingDetails>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!("zones/{}/settings", self.zone_identifier)
+    }
+}
+
+/// Get Zone Setting
+/// Fetch a single zone setting by its identifier, e.g. `always_use_https`
+/// <https://developers.cloudflare.com/api/resources/zones/subresources/settings/methods/get/>
+#[derive(Debug)]
+pub struct GetZoneSetting<'a> {
+    pub zone_identifier: &'a str,
+    /// Setting identifier, e.g. `min_tls_version`
+    pub setting_id: &'a str,
+}
+
+impl EndpointSpec for GetZoneSetting<'_> {
+    type JsonResponse = ZoneSettingDetails;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "zones/{}/settings/{}",
+            self.zone_identifier, self.setting_id
+        )
+    }
+}
+
+/// Edit Zone Setting
+/// Updates a single zone setting. The setting identifier is taken from the `ZoneSetting` variant.
+/// <https://developers.cloudflare.com/api/resources/zones/subresources/settings/methods/edit/>
+#[derive(Debug)]
+pub struct EditZoneSetting<'a> {
+    pub zone_identifier: &'a str,
+    pub setting: ZoneSetting,
+}
+
+impl EndpointSpec for EditZoneSetting<'_> {
+    type JsonResponse = ZoneSettingDetails;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+    fn path(&self) -> String {
+        format!(
+            "zones/{}/settings/{}",
+            self.zone_identifier,
+            self.setting.id()
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody<'_>> {
+        let RawZoneSetting { value, .. } = self.setting.clone().into();
+        let body = serde_json::json!({ "value": value }).to_string();
+        Some(RequestBody::Json(body))
+    }
+}
+
+/// Edit Multiple Zone Settings
+/// Edit settings for a zone in a single request
+/// <https://developers.cloudflare.com/api/resources/zones/subresources/settings/methods/bulk_edit/>
+#[derive(Debug)]
+pub struct EditZoneSettings<'a> {
+    pub zone_identifier: &'a str,
+    pub params: EditZoneSettingsParams,
+}
+
+impl EndpointSpec for EditZoneSettings<'_> {
+    type JsonResponse = Vec<ZoneSettingDetails>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+    fn path(&self) -> String {
+        format!("zones/{}/settings", self.zone_identifier)
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody<'_>> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct EditZoneSettingsParams {
+    /// One or more zone settings to update
+    pub items: Vec<ZoneSetting>,
+}
+
+/// A zone setting, keyed by its setting identifier.
+///
+/// Settings this crate does not model yet, and known settings with a value it does not know, are
+/// kept as `Other`, so that listing every setting of a zone does not fail on new settings or values.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[serde(from = "RawZoneSetting", into = "RawZoneSetting")]
+pub enum ZoneSetting {
+    /// Reply to all requests for URLs that use "http" with a 301 redirect to the "https" URL
+    AlwaysUseHttps(SettingToggle),
+    /// Compress responses with Brotli when the client supports it
+    Brotli(SettingToggle),
+    /// Browser Cache TTL (in seconds) for cached resources. `0` respects existing headers.
+    BrowserCacheTtl(u32),
+    /// How much of a request's query string is used for caching
+    CacheLevel(CacheLevel),
+    /// Temporarily bypass the cache to see changes to origin content in real time
+    DevelopmentMode(SettingToggle),
+    /// HTTP/3 (QUIC) support
+    Http3(SettingToggle),
+    /// IPv6 compatibility
+    Ipv6(SettingToggle),
+    /// Only accept HTTPS requests that use at least this TLS version
+    MinTlsVersion(MinTlsVersion),
+    /// How aggressively visitors are challenged based on their IP reputation
+    SecurityLevel(SecurityLevel),
+    /// TLS 1.3 support
+    Tls13(Tls13),
+    /// Any other zone setting, with its raw JSON value
+    Other {
+        id: String,
+        value: serde_json::Value,
+    },
+}
+
+impl ZoneSetting {
+    /// The setting identifier used by the API, e.g. `always_use_https`
+    pub fn id(&self) -> &str {
+        match self {
+            ZoneSetting::AlwaysUseHttps(_) => "always_use_https",
+            ZoneSetting::Brotli(_) => "brotli",
+            ZoneSetting::BrowserCacheTtl(_) => "browser_cache_ttl",
+            ZoneSetting::CacheLevel(_) => "cache_level",
+            ZoneSetting::DevelopmentMode(_) => "development_mode",
+            ZoneSetting::Http3(_) => "http3",
+            ZoneSetting::Ipv6(_) => "ipv6",
+            ZoneSetting::MinTlsVersion(_) => "min_tls_version",
+            ZoneSetting::SecurityLevel(_) => "security_level",
+            ZoneSetting::Tls13(_) => "tls_1_3",
+            ZoneSetting::Other { id, .. } => id,
+        }
+    }
+}
+
+/// The wire representation of a zone setting: `{"id": ..., "value": ...}`
+#[derive(Deserialize, Serialize, Clone, Debug)]
+struct RawZoneSetting {
+    id: String,
+    value: serde_json::Value,
+}
+
+impl From<RawZoneSetting> for ZoneSetting {
+    fn from(raw: RawZoneSetting) -> Self {
+        fn typed<T: serde::de::DeserializeOwned>(
+            value: &serde_json::Value,
+            variant: fn(T) -> ZoneSetting,
+        ) -> Option<ZoneSetting> {
+            T::deserialize(value).ok().map(variant)
+        }
+
+        let setting = match raw.id.as_str() {
+            "always_use_https" => typed(&raw.value, ZoneSetting::AlwaysUseHttps),
+            "brotli" => typed(&raw.value, ZoneSetting::Brotli),
+            "browser_cache_ttl" => typed(&raw.value, ZoneSetting::BrowserCacheTtl),
+            "cache_level" => typed(&raw.value, ZoneSetting::CacheLevel),
+            "development_mode" => typed(&raw.value, ZoneSetting::DevelopmentMode),
+            "http3" => typed(&raw.value, ZoneSetting::Http3),
+            "ipv6" => typed(&raw.value, ZoneSetting::Ipv6),
+            "min_tls_version" => typed(&raw.value, ZoneSetting::MinTlsVersion),
+            "security_level" => typed(&raw.value, ZoneSetting::SecurityLevel),
+            "tls_1_3" => typed(&raw.value, ZoneSetting::Tls13),
+            _ => None,
+        };
+        // Values added to the API after this crate are kept as they are
+        setting.unwrap_or(ZoneSetting::Other {
+            id: raw.id,
+            value: raw.value,
+        })
+    }
+}
+
+impl From<ZoneSetting> for RawZoneSetting {
+    fn from(setting: ZoneSetting) -> Self {
+        let id = setting.id().to_string();
+        // Serializing these plain enums and integers into a `Value` cannot fail.
+        let value = match setting {
+            ZoneSetting::AlwaysUseHttps(v)
+            | ZoneSetting::Brotli(v)
+            | ZoneSetting::DevelopmentMode(v)
+            | ZoneSetting::Http3(v)
+            | ZoneSetting::Ipv6(v) => serde_json::to_value(v),
+            ZoneSetting::BrowserCacheTtl(v) => serde_json::to_value(v),
+            ZoneSetting::CacheLevel(v) => serde_json::to_value(v),
+            ZoneSetting::MinTlsVersion(v) => serde_json::to_value(v),
+            ZoneSetting::SecurityLevel(v) => serde_json::to_value(v),
+            ZoneSetting::Tls13(v) => serde_json::to_value(v),
+            ZoneSetting::Other { value, .. } => Ok(value),
+        }
+        .unwrap();
+        RawZoneSetting { id, value }
+    }
+}
+
+/// A zone setting as returned by the API, along with its editability
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct ZoneSettingDetails {
+    /// The setting identifier and its value
+    #[serde(flatten)]
+    pub setting: ZoneSetting,
+    /// Whether or not this setting can be modified for this zone (based on your Cloudflare plan level)
+    pub editable: Option<bool>,
+    /// Last time this setting was modified
+    pub modified_on: Option<DateTime<Utc>>,
+    /// Seconds remaining until the setting reverts, e.g. for `development_mode`
+    pub time_remaining: Option<i64>,
+}
+
+impl ApiResult for ZoneSettingDetails {}
+impl ApiResult for Vec<ZoneSettingDetails> {}
+
+/// Value of a zone setting that is either on or off
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SettingToggle {
+    On,
+    Off,
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MinTlsVersion {
+    #[serde(rename = "1.0")]
+    Tls1_0,
+    #[serde(rename = "1.1")]
+    Tls1_1,
+    #[serde(rename = "1.2")]
+    Tls1_2,
+    #[serde(rename = "1.3")]
+    Tls1_3,
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Tls13 {
+    On,
+    Off,
+    /// TLS 1.3 with 0-RTT
+    Zrt,
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CacheLevel {
+    /// Cache every query string variation
+    Aggressive,
+    /// Ignore the query string
+    Basic,
+    /// Treat all query strings as the same
+    Simplified,
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SecurityLevel {
+    Off,
+    EssentiallyOff,
+    Low,
+    Medium,
+    High,
+    UnderAttack,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zone_settings_deserialization() {
+        let json = r#"
+        [
+            {"id": "always_use_https", "value": "on", "editable": true, "modified_on": "2014-01-01T05:20:00Z"},
+            {"id": "min_tls_version", "value": "1.2", "editable": true},
+            {"id": "tls_1_3", "value": "zrt"},
+            {"id": "browser_cache_ttl", "value": 14400},
+            {"id": "security_level", "value": "under_attack"},
+            {"id": "development_mode", "value": "off", "time_remaining": 0},
+            {"id": "ipv6", "value": "on"},
+            {"id": "rocket_loader", "value": "off"}
+        ]
+        "#;
+
+        let settings: Vec<ZoneSettingDetails> = serde_json::from_str(json).unwrap();
+        assert_eq!(settings.len(), 8);
+        assert_eq!(
+            settings[0].setting,
+            ZoneSetting::AlwaysUseHttps(SettingToggle::On)
+        );
+        assert_eq!(settings[0].editable, Some(true));
+        assert_eq!(
+            settings[1].setting,
+            ZoneSetting::MinTlsVersion(MinTlsVersion::Tls1_2)
+        );
+        assert_eq!(settings[2].setting, ZoneSetting::Tls13(Tls13::Zrt));
+        assert_eq!(settings[3].setting, ZoneSetting::BrowserCacheTtl(14400));
+        assert_eq!(
+            settings[4].setting,
+            ZoneSetting::SecurityLevel(SecurityLevel::UnderAttack)
+        );
+        assert_eq!(settings[5].time_remaining, Some(0));
+        assert_eq!(settings[6].setting, ZoneSetting::Ipv6(SettingToggle::On));
+        assert_eq!(
+            settings[7].setting,
+            ZoneSetting::Other {
+                id: "rocket_loader".to_string(),
+                value: serde_json::json!("off"),
+            }
+        );
+        assert_eq!(settings[7].setting.id(), "rocket_loader");
+    }
+
+    #[test]
+    fn test_zone_setting_unknown_value() {
+        let json = r#"[
+            {"id": "min_tls_version", "value": "2.0", "editable": true},
+            {"id": "http3", "value": "on"}
+        ]"#;
+        let settings: Vec<ZoneSettingDetails> = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            settings[0].setting,
+            ZoneSetting::Other {
+                id: "min_tls_version".to_string(),
+                value: serde_json::json!("2.0"),
+            }
+        );
+        assert_eq!(settings[0].setting.id(), "min_tls_version");
+        assert_eq!(settings[1].setting, ZoneSetting::Http3(SettingToggle::On));
+    }
+
+    #[test]
+    fn test_edit_zone_setting() {
+        let endpoint = EditZoneSetting {
+            zone_identifier: "zone-id",
+            setting: ZoneSetting::MinTlsVersion(MinTlsVersion::Tls1_2),
+        };
+        assert_eq!(endpoint.method(), Method::PATCH);
+        assert_eq!(endpoint.path(), "zones/zone-id/settings/min_tls_version");
+        match endpoint.body() {
+            Some(RequestBody::Json(body)) => assert_eq!(body, r#"{"value":"1.2"}"#),
+            _ => panic!("Expected JSON body"),
+        }
+    }
+
+    #[test]
+    fn test_edit_zone_settings() {
+        let endpoint = EditZoneSettings {
+            zone_identifier: "zone-id",
+            params: EditZoneSettingsParams {
+                items: vec![
+                    ZoneSetting::Http3(SettingToggle::On),
+                    ZoneSetting::CacheLevel(CacheLevel::Aggressive),
+                ],
+            },
+        };
+        assert_eq!(endpoint.path(), "zones/zone-id/settings");
+        match endpoint.body() {
+            Some(RequestBody::Json(body)) => assert_eq!(
+                body,
+                r#"{"items":[{"id":"http3","value":"on"},{"id":"cache_level","value":"aggressive"}]}"#
+            ),
+            _ => panic!("Expected JSON body"),
+        }
+    }
+}
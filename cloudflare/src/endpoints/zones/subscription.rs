@@ -0,0 +1,202 @@
+use crate::endpoints::zones::plan::Frequency;
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::{ApiResult, ApiSuccess};
+use chrono::offset::Utc;
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+
+/// Zone Subscription Details
+/// Lists zone subscription details
+/// <https://developers.cloudflare.com/api/resources/zones/subresources/subscriptions/methods/get/>
+#[derive(Debug)]
+pub struct ZoneSubscriptionDetails<'a> {
+    pub zone_identifier: &'a str,
+}
+
+impl EndpointSpec for ZoneSubscriptionDetails<'_> {
+    type JsonResponse = Subscription;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!("zones/{}/subscription", self.zone_identifier)
+    }
+}
+
+/// Create Zone Subscription
+/// Create a zone subscription, either plan or add-ons
+/// <https://developers.cloudflare.com/api/resources/zones/subresources/subscriptions/methods/create/>
+#[derive(Debug)]
+pub struct CreateZoneSubscription<'a> {
+    pub zone_identifier: &'a str,
+    pub params: ZoneSubscriptionParams,
+}
+
+impl EndpointSpec for CreateZoneSubscription<'_> {
+    type JsonResponse = Subscription;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn path(&self) -> String {
+        format!("zones/{}/subscription", self.zone_identifier)
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody<'_>> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+}
+
+/// Update Zone Subscription
+/// Updates zone subscriptions, either plan or add-ons
+/// <https://developers.cloudflare.com/api/resources/zones/subresources/subscriptions/methods/update/>
+#[derive(Debug)]
+pub struct UpdateZoneSubscription<'a> {
+    pub zone_identifier: &'a str,
+    pub params: ZoneSubscriptionParams,
+}
+
+impl EndpointSpec for UpdateZoneSubscription<'_> {
+    type JsonResponse = Subscription;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn path(&self) -> String {
+        format!("zones/{}/subscription", self.zone_identifier)
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody<'_>> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct ZoneSubscriptionParams {
+    /// How often the subscription is renewed automatically
+    pub frequency: Option<Frequency>,
+    /// The rate plan applied to the subscription
+    pub rate_plan: Option<SubscriptionRatePlanParams>,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct SubscriptionRatePlanParams {
+    /// The ID of the rate plan, e.g. `business`
+    pub id: String,
+}
+
+/// The state that the subscription is in
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum SubscriptionState {
+    Trial,
+    Provisioned,
+    Paid,
+    AwaitingPayment,
+    Cancelled,
+    Failed,
+    Expired,
+}
+
+/// A zone subscription
+/// <https://developers.cloudflare.com/api/resources/zones/subresources/subscriptions/models/subscription/>
+#[derive(Deserialize, Debug)]
+pub struct Subscription {
+    /// Subscription identifier tag
+    pub id: Option<String>,
+    /// The monetary unit in which pricing information is displayed
+    pub currency: Option<String>,
+    /// The end of the current period and also when the next billing is due
+    pub current_period_end: Option<DateTime<Utc>>,
+    /// When the current billing period started. May match `initial_period_start` if this is the
+    /// first period.
+    pub current_period_start: Option<DateTime<Utc>>,
+    /// How often the subscription is renewed automatically
+    pub frequency: Option<Frequency>,
+    /// The price of the subscription that will be billed, in US dollars
+    pub price: Option<f64>,
+    /// The rate plan applied to the subscription
+    pub rate_plan: Option<SubscriptionRatePlan>,
+    /// The state that the subscription is in
+    pub state: Option<SubscriptionState>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SubscriptionRatePlan {
+    /// The ID of the rate plan
+    pub id: String,
+    /// The currency applied to the rate plan subscription
+    pub currency: Option<String>,
+    /// Whether this rate plan is managed externally from Cloudflare
+    pub externally_managed: Option<bool>,
+    /// Whether a rate plan is enterprise-based (or newly adopted term contract)
+    pub is_contract: Option<bool>,
+    /// The full name of the rate plan
+    pub public_name: Option<String>,
+    /// The scope that this rate plan applies to
+    pub scope: Option<String>,
+    /// The list of sets this rate plan applies to
+    #[serde(default)]
+    pub sets: Vec<String>,
+}
+
+impl ApiResult for Subscription {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subscription_deserialization() {
+        let json = r#"
+        {
+            "id": "506e3185e9c882d175a2d0cb0093d9f2",
+            "currency": "USD",
+            "current_period_end": "2014-03-31T12:20:00Z",
+            "current_period_start": "2014-05-11T12:20:00Z",
+            "frequency": "monthly",
+            "price": 20,
+            "rate_plan": {
+                "id": "pro",
+                "currency": "USD",
+                "externally_managed": false,
+                "is_contract": false,
+                "public_name": "Pro Plan",
+                "scope": "zone",
+                "sets": []
+            },
+            "state": "Paid"
+        }
+        "#;
+
+        let subscription: Subscription = serde_json::from_str(json).unwrap();
+        assert_eq!(subscription.frequency, Some(Frequency::Monthly));
+        assert_eq!(subscription.state, Some(SubscriptionState::Paid));
+        assert_eq!(subscription.rate_plan.unwrap().id, "pro");
+    }
+
+    #[test]
+    fn test_update_zone_subscription_body() {
+        let endpoint = UpdateZoneSubscription {
+            zone_identifier: "zone-id",
+            params: ZoneSubscriptionParams {
+                frequency: Some(Frequency::Yearly),
+                rate_plan: Some(SubscriptionRatePlanParams {
+                    id: "business".to_string(),
+                }),
+            },
+        };
+        assert_eq!(endpoint.method(), Method::PUT);
+        assert_eq!(endpoint.path(), "zones/zone-id/subscription");
+        assert_eq!(
+            serde_json::to_string(&endpoint.params).unwrap(),
+            r#"{"frequency":"yearly","rate_plan":{"id":"business"}}"#
+        );
+    }
+}
@@ -7,6 +7,7 @@ use crate::framework::{OrderDirection, SearchMatch};
 use chrono::offset::Utc;
 use chrono::DateTime;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// List Zones
 /// List, search, sort, and filter your zones
@@ -56,7 +57,7 @@ pub struct CreateZone<'a> {
     pub params: CreateZoneParams<'a>,
 }
 impl EndpointSpec for CreateZone<'_> {
-    type JsonResponse = ();
+    type JsonResponse = Zone;
     type ResponseType = ApiSuccess<Self::JsonResponse>;
 
     fn method(&self) -> Method {
@@ -74,6 +75,288 @@ impl EndpointSpec for CreateZone<'_> {
     }
 }
 
+/// Edit Zone
+/// Edits a zone. Only one zone property can be changed at a time.
+/// <https://developers.cloudflare.com/api/resources/zones/methods/edit/>
+#[derive(Debug)]
+pub struct EditZone<'a> {
+    pub identifier: &'a str,
+    pub params: EditZoneParams,
+}
+impl EndpointSpec for EditZone<'_> {
+    type JsonResponse = Zone;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+    fn path(&self) -> String {
+        format!("zones/{}", self.identifier)
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody<'_>> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct EditZoneParams {
+    /// Indicates whether the zone is only using Cloudflare DNS services. A true value means the
+    /// zone will not receive security or performance benefits.
+    pub paused: Option<bool>,
+    /// The plan to move the zone to
+    pub plan: Option<EditZonePlan>,
+    /// A full zone implies that DNS is hosted with Cloudflare. A partial zone is typically a
+    /// partner-hosted zone or a CNAME setup. This parameter is only available to Enterprise
+    /// customers or if it has been explicitly enabled on a zone.
+    #[serde(rename = "type")]
+    pub zone_type: Option<Type>,
+    /// An array of domains used for custom name servers. This is only available for Business and
+    /// Enterprise plans.
+    pub vanity_name_servers: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct EditZonePlan {
+    /// Plan identifier tag
+    pub id: String,
+}
+
+/// Delete Zone
+/// Deletes an existing zone
+/// <https://developers.cloudflare.com/api/resources/zones/methods/delete/>
+#[derive(Debug)]
+pub struct DeleteZone<'a> {
+    pub identifier: &'a str,
+}
+impl EndpointSpec for DeleteZone<'_> {
+    type JsonResponse = ZoneIdOnly;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    fn path(&self) -> String {
+        format!("zones/{}", self.identifier)
+    }
+}
+
+/// Rerun the Activation Check
+/// Triggers a new activation check for a PENDING Zone. This can be triggered every 5 min for
+/// paygo/ent customers, every hour for FREE Zones.
+/// <https://developers.cloudflare.com/api/resources/zones/subresources/activation_check/methods/trigger/>
+#[derive(Debug)]
+pub struct ZoneActivationCheck<'a> {
+    pub identifier: &'a str,
+}
+impl EndpointSpec for ZoneActivationCheck<'_> {
+    type JsonResponse = ZoneIdOnly;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn path(&self) -> String {
+        format!("zones/{}/activation_check", self.identifier)
+    }
+}
+
+/// Purge Cached Content
+/// Removes cached content from Cloudflare's edge, either everything or by files, tags, hosts or
+/// prefixes.
+///
+/// How many items one request may purge depends on the plan. [`PurgeCache::new`] enforces the
+/// limit of Free, Pro and Business plans, and [`PurgeCache::with_limit`] that of Enterprise
+/// zones; use [`PurgeCacheParams::split`] to purge more.
+/// <https://developers.cloudflare.com/api/resources/cache/methods/purge/>
+#[derive(Debug)]
+pub struct PurgeCache<'a> {
+    pub zone_identifier: &'a str,
+    /// What to purge, within the per-request limit
+    params: PurgeCacheParams,
+}
+
+impl<'a> PurgeCache<'a> {
+    /// Fails if `params` lists no files, tags, hosts or prefixes, or more than
+    /// [`PurgeCacheParams::DEFAULT_MAX_ITEMS_PER_REQUEST`] of them
+    pub fn new(
+        zone_identifier: &'a str,
+        params: PurgeCacheParams,
+    ) -> Result<Self, PurgeCacheError> {
+        Self::with_limit(
+            zone_identifier,
+            params,
+            PurgeCacheParams::DEFAULT_MAX_ITEMS_PER_REQUEST,
+        )
+    }
+
+    /// Like [`PurgeCache::new`], for zones whose plan allows `max_items` items per request
+    pub fn with_limit(
+        zone_identifier: &'a str,
+        params: PurgeCacheParams,
+        max_items: usize,
+    ) -> Result<Self, PurgeCacheError> {
+        params.validate(max_items)?;
+        Ok(PurgeCache {
+            zone_identifier,
+            params,
+        })
+    }
+
+    /// What to purge
+    pub fn params(&self) -> &PurgeCacheParams {
+        &self.params
+    }
+}
+
+impl EndpointSpec for PurgeCache<'_> {
+    type JsonResponse = ZoneIdOnly;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn path(&self) -> String {
+        format!("zones/{}/purge_cache", self.zone_identifier)
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody<'_>> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+}
+
+/// What to purge from the cache. A single request can only purge one kind of item.
+#[derive(Serialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum PurgeCacheParams {
+    /// Remove ALL files from Cloudflare's cache
+    #[serde(serialize_with = "serialize_purge_everything")]
+    Everything,
+    /// Purge specific files by URL, optionally with the headers that make up their cache key
+    Files { files: Vec<PurgeFile> },
+    /// Purge all assets carrying one of the given Cache-Tag header values
+    Tags { tags: Vec<String> },
+    /// Purge all assets served from the given hostnames
+    Hosts { hosts: Vec<String> },
+    /// Purge all assets under the given URL prefixes, e.g. `www.example.com/foo`
+    Prefixes { prefixes: Vec<String> },
+}
+
+fn serialize_purge_everything<S: serde::Serializer>(serializer: S) -> Result<S::Ok, S::Error> {
+    use serde::ser::SerializeMap;
+    let mut map = serializer.serialize_map(Some(1))?;
+    map.serialize_entry("purge_everything", &true)?;
+    map.end()
+}
+
+impl PurgeCacheParams {
+    /// Items per request allowed on Free, Pro and Business plans. Enterprise zones allow more.
+    pub const DEFAULT_MAX_ITEMS_PER_REQUEST: usize = 30;
+
+    pub fn everything() -> Self {
+        PurgeCacheParams::Everything
+    }
+
+    /// Checks the request against a per-request item limit, e.g.
+    /// [`Self::DEFAULT_MAX_ITEMS_PER_REQUEST`] or the limit of the zone's plan.
+    pub fn validate(&self, max_items: usize) -> Result<(), PurgeCacheError> {
+        if let Some(kind) = self.empty_kind() {
+            return Err(PurgeCacheError::Empty { kind });
+        }
+        let (kind, count) = match self {
+            PurgeCacheParams::Everything => return Ok(()),
+            PurgeCacheParams::Files { files } => ("files", files.len()),
+            PurgeCacheParams::Tags { tags } => ("tags", tags.len()),
+            PurgeCacheParams::Hosts { hosts } => ("hosts", hosts.len()),
+            PurgeCacheParams::Prefixes { prefixes } => ("prefixes", prefixes.len()),
+        };
+        if count > max_items {
+            return Err(PurgeCacheError::TooManyItems {
+                kind,
+                count,
+                max: max_items,
+            });
+        }
+        Ok(())
+    }
+
+    /// Splits the request into requests of at most `max_items` items each, in order
+    pub fn split(self, max_items: usize) -> Vec<PurgeCacheParams> {
+        fn chunks<T: Clone>(items: Vec<T>, max_items: usize) -> Vec<Vec<T>> {
+            items.chunks(max_items.max(1)).map(<[T]>::to_vec).collect()
+        }
+        match self {
+            PurgeCacheParams::Everything => vec![PurgeCacheParams::Everything],
+            PurgeCacheParams::Files { files } => chunks(files, max_items)
+                .into_iter()
+                .map(|files| PurgeCacheParams::Files { files })
+                .collect(),
+            PurgeCacheParams::Tags { tags } => chunks(tags, max_items)
+                .into_iter()
+                .map(|tags| PurgeCacheParams::Tags { tags })
+                .collect(),
+            PurgeCacheParams::Hosts { hosts } => chunks(hosts, max_items)
+                .into_iter()
+                .map(|hosts| PurgeCacheParams::Hosts { hosts })
+                .collect(),
+            PurgeCacheParams::Prefixes { prefixes } => chunks(prefixes, max_items)
+                .into_iter()
+                .map(|prefixes| PurgeCacheParams::Prefixes { prefixes })
+                .collect(),
+        }
+    }
+
+    /// The kind of item the request lists none of
+    fn empty_kind(&self) -> Option<&'static str> {
+        match self {
+            PurgeCacheParams::Everything => None,
+            PurgeCacheParams::Files { files } => files.is_empty().then_some("files"),
+            PurgeCacheParams::Tags { tags } => tags.is_empty().then_some("tags"),
+            PurgeCacheParams::Hosts { hosts } => hosts.is_empty().then_some("hosts"),
+            PurgeCacheParams::Prefixes { prefixes } => prefixes.is_empty().then_some("prefixes"),
+        }
+    }
+}
+
+/// A file to purge: either a plain URL, or a URL with the request headers used in its cache key.
+#[derive(Serialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum PurgeFile {
+    Url(String),
+    UrlWithHeaders {
+        url: String,
+        headers: HashMap<String, String>,
+    },
+}
+
+impl From<&str> for PurgeFile {
+    fn from(url: &str) -> Self {
+        PurgeFile::Url(url.to_string())
+    }
+}
+
+impl From<String> for PurgeFile {
+    fn from(url: String) -> Self {
+        PurgeFile::Url(url)
+    }
+}
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+/// A purge request that the API would reject
+pub enum PurgeCacheError {
+    #[error("Purge request must contain at least one of {kind}")]
+    Empty { kind: &'static str },
+    #[error("Purge request contains {count} {kind}, but at most {max} are allowed per request")]
+    TooManyItems {
+        kind: &'static str,
+        count: usize,
+        max: usize,
+    },
+}
+
 #[derive(Serialize, Clone, Debug, Default)]
 pub struct CreateZoneParams<'a> {
     pub name: &'a str,
@@ -162,8 +445,9 @@ pub struct Zone {
     pub name: String,
     /// Information about the account the zone belongs to
     pub account: AccountDetails,
-    /// The last time proof of ownership was detected and the zone was made active
-    pub activated_on: DateTime<Utc>,
+    /// The last time proof of ownership was detected and the zone was made active.
+    /// Empty for zones that have never been activated.
+    pub activated_on: Option<DateTime<Utc>>,
     /// A list of beta features in which the zone is participating
     pub betas: Option<Vec<String>>,
     /// When the zone was created
@@ -212,6 +496,167 @@ pub struct Zone {
     pub zone_type: Type,
 }
 
+/// The identifier of a zone, returned by endpoints that act on a zone without returning it
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ZoneIdOnly {
+    /// Zone identifier tag
+    pub id: String,
+}
+
 // TODO: This should probably be a derive macro
 impl ApiResult for Zone {}
 impl ApiResult for Vec<Zone> {}
+impl ApiResult for ZoneIdOnly {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_purge_cache_params_serialization() {
+        assert_eq!(
+            serde_json::to_string(&PurgeCacheParams::everything()).unwrap(),
+            r#"{"purge_everything":true}"#
+        );
+
+        let files = PurgeCacheParams::Files {
+            files: vec![
+                "https://example.com/a.css".into(),
+                PurgeFile::UrlWithHeaders {
+                    url: "https://example.com/b.css".to_string(),
+                    headers: HashMap::from([(
+                        "Origin".to_string(),
+                        "https://example.org".to_string(),
+                    )]),
+                },
+            ],
+        };
+        assert_eq!(
+            serde_json::to_string(&files).unwrap(),
+            r#"{"files":["https://example.com/a.css",{"url":"https://example.com/b.css","headers":{"Origin":"https://example.org"}}]}"#
+        );
+
+        let tags = PurgeCacheParams::Tags {
+            tags: vec!["a-cache-tag".to_string()],
+        };
+        assert_eq!(
+            serde_json::to_string(&tags).unwrap(),
+            r#"{"tags":["a-cache-tag"]}"#
+        );
+    }
+
+    #[test]
+    fn test_purge_cache_limits() {
+        let hosts = PurgeCacheParams::Hosts {
+            hosts: (0..30).map(|i| format!("{i}.example.com")).collect(),
+        };
+        assert_eq!(
+            hosts.validate(PurgeCacheParams::DEFAULT_MAX_ITEMS_PER_REQUEST),
+            Ok(())
+        );
+
+        let prefixes = PurgeCacheParams::Prefixes {
+            prefixes: (0..31).map(|i| format!("example.com/{i}")).collect(),
+        };
+        assert_eq!(
+            prefixes.validate(PurgeCacheParams::DEFAULT_MAX_ITEMS_PER_REQUEST),
+            Err(PurgeCacheError::TooManyItems {
+                kind: "prefixes",
+                count: 31,
+                max: 30
+            })
+        );
+        // Enterprise zones allow more per request
+        assert_eq!(prefixes.validate(500), Ok(()));
+
+        let empty = PurgeCacheParams::Tags { tags: vec![] };
+        assert_eq!(
+            empty.validate(PurgeCacheParams::DEFAULT_MAX_ITEMS_PER_REQUEST),
+            Err(PurgeCacheError::Empty { kind: "tags" })
+        );
+        assert_eq!(
+            PurgeCache::new("zone-id", empty).unwrap_err(),
+            PurgeCacheError::Empty { kind: "tags" }
+        );
+    }
+
+    #[test]
+    fn test_purge_cache_split() {
+        let files = PurgeCacheParams::Files {
+            files: (0..65)
+                .map(|i| format!("https://example.com/{i}").into())
+                .collect(),
+        };
+        let requests = files.split(PurgeCacheParams::DEFAULT_MAX_ITEMS_PER_REQUEST);
+        let sizes: Vec<usize> = requests
+            .iter()
+            .map(|params| match params {
+                PurgeCacheParams::Files { files } => files.len(),
+                _ => panic!("expected files"),
+            })
+            .collect();
+        assert_eq!(sizes, [30, 30, 5]);
+
+        let tags = PurgeCacheParams::Tags {
+            tags: (0..31).map(|i| format!("tag-{i}")).collect(),
+        };
+        assert_eq!(
+            PurgeCache::new("zone-id", tags.clone()).unwrap_err(),
+            PurgeCacheError::TooManyItems {
+                kind: "tags",
+                count: 31,
+                max: 30
+            }
+        );
+        let endpoint = PurgeCache::with_limit("zone-id", tags, 500).unwrap();
+        assert!(endpoint.body().is_some());
+        assert_eq!(PurgeCacheParams::everything().split(30).len(), 1);
+    }
+
+    #[test]
+    fn test_created_zone_deserialization() {
+        let json = r##"
+        {
+            "id": "023e105f4ecef8ad9ca31a8372d0c353",
+            "account": {"id": "01a7362d577a6c3019a474fd6f485823", "name": "Demo Account"},
+            "activated_on": null,
+            "created_on": "2014-01-01T05:20:00.12345Z",
+            "development_mode": 0,
+            "meta": {"custom_certificate_quota": 1, "page_rule_quota": 100, "phishing_detected": false},
+            "modified_on": "2014-01-01T05:20:00.12345Z",
+            "name": "example.com",
+            "name_servers": ["bob.ns.cloudflare.com", "lola.ns.cloudflare.com"],
+            "original_dnshost": null,
+            "original_name_servers": null,
+            "original_registrar": null,
+            "owner": {"id": null, "type": "user", "email": null},
+            "paused": false,
+            "permissions": ["#zone:read"],
+            "status": "pending",
+            "type": "full"
+        }
+        "##;
+
+        let zone: Zone = serde_json::from_str(json).unwrap();
+        assert_eq!(zone.name, "example.com");
+        assert!(zone.activated_on.is_none());
+        assert!(matches!(zone.status, Status::Pending));
+    }
+
+    #[test]
+    fn test_edit_zone_params() {
+        let endpoint = EditZone {
+            identifier: "zone-id",
+            params: EditZoneParams {
+                vanity_name_servers: Some(vec!["ns1.example.com".to_string()]),
+                ..Default::default()
+            },
+        };
+        assert_eq!(endpoint.method(), Method::PATCH);
+        assert_eq!(endpoint.path(), "zones/zone-id");
+        assert_eq!(
+            serde_json::to_string(&endpoint.params).unwrap(),
+            r#"{"vanity_name_servers":["ns1.example.com"]}"#
+        );
+    }
+}
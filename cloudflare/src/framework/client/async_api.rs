@@ -66,6 +66,21 @@ impl Client {
         &self,
         endpoint: &Endpoint,
     ) -> ApiResponse<Endpoint::ResponseType>
+    where
+        Endpoint: EndpointSpec + Send + Sync,
+        Endpoint::ResponseType: ResponseConverter<Endpoint::JsonResponse>,
+    {
+        self.request_with_credentials(endpoint, &self.credentials)
+            .await
+    }
+
+    /// Issue an API request authenticated with other credentials than the client's own, e.g. a
+    /// short-lived token returned by a previous request.
+    pub(crate) async fn request_with_credentials<Endpoint>(
+        &self,
+        endpoint: &Endpoint,
+        credentials: &Credentials,
+    ) -> ApiResponse<Endpoint::ResponseType>
     where
         Endpoint: EndpointSpec + Send + Sync,
         Endpoint::ResponseType: ResponseConverter<Endpoint::JsonResponse>,
@@ -93,6 +108,17 @@ impl Client {
                             MultipartPart::Bytes(bytes) => {
                                 form = form.part(name, reqwest::multipart::Part::bytes(bytes));
                             }
+                            MultipartPart::File {
+                                content,
+                                file_name,
+                                content_type,
+                            } => {
+                                let part = reqwest::multipart::Part::bytes(content)
+                                    .file_name(file_name)
+                                    .mime_str(&content_type)
+                                    .map_err(ApiFailure::Invalid)?;
+                                form = form.part(name, part);
+                            }
                         }
                     }
                     request = request.multipart(form);
@@ -107,7 +133,7 @@ impl Client {
             }
         }
 
-        request = request.auth(&self.credentials);
+        request = request.auth(credentials);
         let response = request.send().await?;
 
         // The condition is necessary, even if a warning is present.
@@ -132,8 +158,11 @@ where
 {
     let status = resp.status();
     if status.is_success() {
+        let headers = resp.headers().clone();
         let bytes = resp.bytes().await.map_err(ApiFailure::Invalid)?.to_vec();
-        Ok(Endpoint::ResponseType::from_raw(bytes))
+        Ok(Endpoint::ResponseType::from_raw_with_headers(
+            bytes, &headers,
+        ))
     } else {
         let parsed: Result<ApiErrors, reqwest::Error> = resp.json().await;
         let errors = parsed.unwrap_or_default();
@@ -84,6 +84,17 @@ impl HttpApiClient {
                                 form = form
                                     .part(name, reqwest::blocking::multipart::Part::bytes(bytes));
                             }
+                            MultipartPart::File {
+                                content,
+                                file_name,
+                                content_type,
+                            } => {
+                                let part = reqwest::blocking::multipart::Part::bytes(content)
+                                    .file_name(file_name)
+                                    .mime_str(&content_type)
+                                    .map_err(ApiFailure::Invalid)?;
+                                form = form.part(name, part);
+                            }
                         }
                     }
                     request = request.multipart(form);
@@ -139,8 +150,11 @@ where
 {
     let status = resp.status();
     if status.is_success() {
+        let headers = resp.headers().clone();
         let bytes = resp.bytes().map_err(ApiFailure::Invalid)?.to_vec();
-        Ok(Endpoint::ResponseType::from_raw(bytes))
+        Ok(Endpoint::ResponseType::from_raw_with_headers(
+            bytes, &headers,
+        ))
     } else {
         let parsed: Result<ApiErrors, reqwest::Error> = resp.json();
         let errors = parsed.unwrap_or_default();
@@ -17,6 +17,12 @@ pub enum RequestBody<'a> {
 pub enum MultipartPart {
     Text(String),
     Bytes(Vec<u8>),
+    /// A file part with its own file name and content type, e.g. `text/html`
+    File {
+        content: Vec<u8>,
+        file_name: String,
+        content_type: String,
+    },
 }
 
 /// Helper trait for endpoints that require a multipart body.
@@ -28,9 +28,16 @@ impl<T> ApiResult for ApiSuccess<T> where T: ApiResult {}
 /// Some endpoints return nothing. That's OK.
 impl ApiResult for () {}
 
+/// Some endpoints return a plain list of names, e.g. script tags.
+impl ApiResult for Vec<String> {}
+
 /// A helper trait to avoid trait bounds issues in the clients.
 pub trait ResponseConverter<JsonResponse>: Sized {
     fn from_raw(bytes: Vec<u8>) -> Self;
+    /// Raw endpoints whose response headers carry data override this.
+    fn from_raw_with_headers(bytes: Vec<u8>, _headers: &http::HeaderMap) -> Self {
+        Self::from_raw(bytes)
+    }
     fn from_json(api: ApiSuccess<JsonResponse>) -> Self;
 }
 // JSON endpoints